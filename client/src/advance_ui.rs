@@ -1,5 +1,6 @@
 use crate::city_ui::add_building_description;
 use crate::client_state::{ActiveDialog, NO_UPDATE, RenderResult, StateUpdate};
+use crate::event_ui::event_help;
 use crate::layout_ui::{button_pressed, top_centered_text};
 use crate::multiline::MultilineText;
 use crate::payment_ui::{Payment, payment_dialog};
@@ -11,6 +12,7 @@ use macroquad::math::vec2;
 use macroquad::prelude::{BLACK, BLUE, GRAY, GREEN, Rect, WHITE, YELLOW};
 use server::action::Action;
 use server::advance::{Advance, AdvanceAction, AdvanceInfo, Bonus, find_special_advance};
+use server::events::EventOrigin;
 use server::game::GameState;
 use server::player::{CostTrigger, Player};
 use server::playing_actions::PlayingAction;
@@ -138,8 +140,9 @@ fn border_color(a: &AdvanceInfo) -> Color {
 
 fn description(rc: &RenderContext, a: &AdvanceInfo) -> MultilineText {
     let mut parts = MultilineText::default();
-    parts.add(rc, &a.name);
-    parts.add(rc, &a.description);
+    for label in event_help(rc, &EventOrigin::Advance(a.advance)) {
+        parts.add(rc, &label);
+    }
     parts.add(
         rc,
         &format!(
@@ -0,0 +1,90 @@
+use crate::layout_ui::UI_BACKGROUND;
+use crate::log_ui::draw_log_action;
+use crate::render_context::RenderContext;
+use crate::richtext::RichTextDrawer;
+use itertools::Itertools;
+use macroquad::math::{Rect, vec2};
+use server::ai::{CandidateTrace, DecisionTrace};
+use server::ai_suggestion::Suggestion;
+
+const MAX_CANDIDATES: usize = 8;
+const WIDTH: f32 = 500.;
+const LINE_HEIGHT: f32 = 22.;
+
+pub(crate) fn show_ai_trace(rc: &RenderContext, trace: &DecisionTrace) {
+    let mut lines = vec![
+        format!(
+            "AI decision of player {} ({})",
+            trace.player_index, trace.profile
+        ),
+        format!("Chosen: {:?}", trace.chosen),
+    ];
+    if trace.opening_book {
+        lines.push("Taken from the opening book".to_string());
+    }
+    lines.extend(
+        trace
+            .candidates
+            .iter()
+            .sorted_by(|a, b| b.mean_score.total_cmp(&a.mean_score))
+            .take(MAX_CANDIDATES)
+            .map(candidate_line),
+    );
+    lines.extend(trace.missions.iter().map(|m| {
+        format!(
+            "Mission {} to {}: units {:?}",
+            m.mission_type, m.target, m.units
+        )
+    }));
+
+    draw_panel(rc, &lines);
+}
+
+pub(crate) fn show_suggestions(rc: &RenderContext, suggestions: &[Suggestion]) {
+    // the actions are drawn like in the log - the lines are left empty for them
+    let mut lines = vec!["Suggested moves".to_string()];
+    for s in suggestions {
+        lines.push(String::new());
+        lines.push(format!("    {}", s.reason));
+    }
+    let (x, y) = draw_panel(rc, &lines);
+    for (i, s) in suggestions.iter().enumerate() {
+        let mut drawer =
+            RichTextDrawer::new(rc, vec2(x + 10., y + (2. * i as f32 + 2.) * LINE_HEIGHT));
+        drawer.text(&format!("{}.", i + 1));
+        draw_log_action(&mut drawer, &s.log);
+    }
+}
+
+// returns the top left corner of the panel
+fn draw_panel(rc: &RenderContext, lines: &[String]) -> (f32, f32) {
+    let x = rc.state.screen_size.x - WIDTH - 20.;
+    let y = 80.;
+    rc.draw_rectangle(
+        Rect::new(x, y, WIDTH, (lines.len() as f32 + 0.5) * LINE_HEIGHT),
+        UI_BACKGROUND,
+    );
+    for (i, line) in lines.iter().enumerate() {
+        rc.draw_limited_text(
+            line,
+            x + 10.,
+            y + (i as f32 + 1.) * LINE_HEIGHT,
+            WIDTH - 20.,
+        );
+    }
+    (x, y)
+}
+
+fn candidate_line(c: &CandidateTrace) -> String {
+    let eliminated = c
+        .eliminated_in_round
+        .map(|r| format!(" (pruned in round {r})"))
+        .unwrap_or_default();
+    format!(
+        "{:.2} ±{:.2} in {} rollouts{eliminated}: {:?}",
+        c.mean_score,
+        c.score_variance.sqrt(),
+        c.rollouts,
+        c.action
+    )
+}
@@ -166,8 +166,22 @@ impl Assets {
                 )),
             ),
         ]
-        .iter()
-        .cloned()
+        .into_iter()
+        .chain(
+            // wonders of content packs
+            [
+                Wonder::Custom1,
+                Wonder::Custom2,
+                Wonder::Custom3,
+                Wonder::Custom4,
+            ]
+            .map(|w| {
+                (
+                    w,
+                    load_png(include_bytes!("../assets/obelisk-svgrepo-com.png")),
+                )
+            }),
+        )
         .collect()
     }
 
@@ -342,7 +356,26 @@ impl Assets {
                     "../assets/statue-of-david-1-svgrepo-com.png"
                 )),
             ),
-            //Rome
+            // Team play
+            (
+                CustomActionType::TeamTrade,
+                load_png(include_bytes!("../assets/market-place-svgrepo-com.png")),
+            ),
+            // Trading
+            (
+                CustomActionType::Trade,
+                load_png(include_bytes!("../assets/wagon-svgrepo-com.png")),
+            ),
+        ]
+        .iter()
+        .cloned()
+        .chain(Self::civilization_custom_actions())
+        .collect()
+    }
+
+    fn civilization_custom_actions() -> Vec<(CustomActionType, Texture2D)> {
+        vec![
+            // Rome
             (
                 CustomActionType::Aqueduct,
                 load_png(include_bytes!("../assets/aqueduct-svgrepo-com.png")),
@@ -389,10 +422,17 @@ impl Assets {
                 CustomActionType::NewColonies,
                 load_png(include_bytes!("../assets/viking-helmet-svgrepo-com.png")),
             ),
+            // Egypt
+            (
+                CustomActionType::ExpeditionToPunt,
+                load_png(include_bytes!("../assets/graduate-cap-svgrepo-com.png")),
+            ),
+            // Persia
+            (
+                CustomActionType::Administrator,
+                load_png(include_bytes!("../assets/horse-head-svgrepo-com.png")),
+            ),
         ]
-        .iter()
-        .cloned()
-        .collect()
     }
 
     async fn terrain(features: &Features) -> HashMap<Terrain, Texture2D> {
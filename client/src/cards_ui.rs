@@ -2,6 +2,7 @@ use crate::client_state::{ActiveDialog, NO_UPDATE, RenderResult, StateUpdate};
 use crate::custom_phase_ui;
 use crate::custom_phase_ui::MultiSelection;
 use crate::dialog_ui::ok_button;
+use crate::event_ui::event_help;
 use crate::layout_ui::{bottom_centered_text, button_pressed, rect_from};
 use crate::multiline::MultilineText;
 use crate::player_ui::get_combat;
@@ -15,7 +16,7 @@ use macroquad::prelude::{BEIGE, Color, GREEN, RED, YELLOW};
 use server::action::Action;
 use server::card::{HandCard, HandCardType, hand_cards, validate_card_selection};
 use server::content::persistent_events::EventResponse;
-use server::events::check_event_origin;
+use server::events::{EventOrigin, check_event_origin};
 use server::objective_card::ObjectiveType;
 use server::playing_actions::{PlayingAction, PlayingActionType};
 use server::wonder::{Wonder, WonderInfo};
@@ -212,8 +213,9 @@ fn get_card_object(
 
 pub(crate) fn wonder_description(rc: &RenderContext, w: &WonderInfo) -> MultilineText {
     let mut description = MultilineText::default();
-    description.add(rc, &w.name());
-    description.add(rc, w.description.as_str());
+    for label in event_help(rc, &EventOrigin::Wonder(w.wonder)) {
+        description.add(rc, &label);
+    }
     description.add(rc, &format!("Cost: {}", w.cost));
     description.add(
         rc,
@@ -342,7 +344,7 @@ pub(crate) fn select_cards_dialog(
         rc,
         format!(
             "{}: {} cards selected",
-            s.request.description,
+            rc.translate_text(&s.request.description),
             s.selected.len()
         )
         .as_str(),
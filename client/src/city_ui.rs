@@ -179,7 +179,7 @@ fn building_icons<'a>(rc: &'a RenderContext, city: &'a City) -> IconActionVec<'a
                         " (without city activation)"
                     }
                 ),
-                Err(e) => format!(" ({e})"),
+                Err(e) => format!(" ({})", rc.translate_text(e)),
             };
             let tooltip = MultilineText::from(
                 rc,
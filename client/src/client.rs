@@ -4,6 +4,8 @@ use macroquad::prelude::*;
 use server::action::Action;
 use server::game::{Game, GameState};
 use server::position::Position;
+use server::unit::UnitType;
+use std::time::Duration;
 
 use crate::advance_ui::{pay_advance_dialog, show_paid_advance_menu};
 use crate::cards_ui::show_cards;
@@ -11,6 +13,7 @@ use crate::client_state::{
     ActiveDialog, CameraMode, DialogChooser, NO_UPDATE, RenderResult, State, StateUpdate,
 };
 use crate::collect_ui::collect_dialog;
+use crate::combat_report_ui::{CombatReportDialog, show_combat_report};
 use crate::construct_ui::pay_construction_dialog;
 use crate::dialog_ui::cancel_button_with_tooltip;
 use crate::event_ui::{custom_phase_event_origin, event_help_tooltip};
@@ -30,8 +33,8 @@ use crate::player_ui::{
 use crate::render_context::{RenderContext, RenderStage};
 use crate::unit_ui::unit_selection_click;
 use crate::{
-    cards_ui, custom_phase_ui, dialog_ui, map_ui, move_ui, recruit_unit_ui, status_phase_ui,
-    tooltip,
+    cards_ui, custom_phase_ui, dialog_ui, hot_seat_ui, map_ui, move_ui, recruit_unit_ui,
+    status_phase_ui, tooltip,
 };
 
 fn render_with_mutable_state(game: &Game, state: &mut State, features: &Features) -> RenderResult {
@@ -72,6 +75,10 @@ pub(crate) fn mouse_wheel_speed() -> f32 {
 }
 
 fn render(rc: &RenderContext, features: &Features) -> RenderResult {
+    if let Some(p) = rc.state.pass_to_player {
+        return hot_seat_ui::pass_to_player_dialog(rc, p);
+    }
+
     if rc.stage.is_main() {
         render_map(rc)?;
     }
@@ -126,6 +133,12 @@ fn render_ui(rc: &RenderContext, features: &Features) -> RenderResult {
     player_select(rc)?;
     show_global_controls(rc, features)?;
 
+    if !rc.state.suggestions.is_empty() {
+        crate::ai_trace_ui::show_suggestions(rc, &rc.state.suggestions);
+    } else if let Some(trace) = &rc.state.ai_trace {
+        crate::ai_trace_ui::show_ai_trace(rc, trace);
+    }
+
     let state = &rc.state;
     if top_right_texture(
         rc,
@@ -179,6 +192,17 @@ fn show_modal_dialog_toggles(rc: &RenderContext) -> RenderResult {
         }
         return StateUpdate::open_dialog(ActiveDialog::Info(dialog));
     }
+    if top_right_texture(
+        rc,
+        rc.assets().unit(UnitType::Infantry, rc.shown_player),
+        icon_pos(-5, 0),
+        "Show combat reports",
+    ) {
+        if let ActiveDialog::CombatReport(_) = state.active_dialog {
+            return StateUpdate::close_dialog();
+        }
+        return StateUpdate::open_dialog(ActiveDialog::CombatReport(CombatReportDialog::new(rc)));
+    }
     NO_UPDATE
 }
 
@@ -214,6 +238,7 @@ fn render_active_dialog(rc: &RenderContext) -> RenderResult {
         ActiveDialog::None | ActiveDialog::WaitingForUpdate => NO_UPDATE,
         ActiveDialog::DialogChooser(d) => dialog_chooser(rc, d),
         ActiveDialog::Log(d) => show_log(rc, d),
+        ActiveDialog::CombatReport(d) => show_combat_report(rc, d),
         ActiveDialog::Info(d) => show_info_dialog(rc, d),
         ActiveDialog::IncreaseHappiness(h) => increase_happiness_menu(rc, h),
         ActiveDialog::AdvanceMenu => show_paid_advance_menu(rc),
@@ -322,6 +347,9 @@ pub struct Features {
     pub import_export: bool,
     pub assets_url: String,
     pub ai: bool,
+    pub hot_seat: bool,
+    // thinking time of the AI to suggest moves to the human players - if available
+    pub suggestion_time: Option<Duration>,
 }
 
 impl Features {
@@ -335,6 +363,7 @@ pub enum GameSyncRequest {
     None,
     ExecuteAction(Action),
     StartAutoplay,
+    SuggestActions,
     Import,
     Export,
 }
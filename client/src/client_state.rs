@@ -1,6 +1,7 @@
 use crate::assets::Assets;
 use crate::client::{Features, GameSyncRequest};
 use crate::collect_ui::CollectResources;
+use crate::combat_report_ui::CombatReportDialog;
 use crate::construct_ui::ConstructionPayment;
 use crate::custom_phase_ui::{
     MultiSelection, SelectedStructureInfo, SelectedStructureStatus, UnitsSelection,
@@ -27,6 +28,7 @@ use server::content::persistent_events::{
     PlayerRequest, UnitTypeRequest,
 };
 use server::game::{Game, GameState};
+use server::localization::Catalog;
 use server::movement::{CurrentMove, MoveDestination};
 use server::playing_actions::PlayingActionType;
 use server::position::Position;
@@ -35,6 +37,7 @@ use server::position::Position;
 pub(crate) enum ActiveDialog {
     None,
     Log(LogDialog),
+    CombatReport(CombatReportDialog),
     Info(InfoDialog),
     WaitingForUpdate,
     DialogChooser(Box<DialogChooser>),
@@ -75,6 +78,7 @@ impl ActiveDialog {
         match self {
             ActiveDialog::None
             | ActiveDialog::Log(_)
+            | ActiveDialog::CombatReport(_)
             | ActiveDialog::Info(_)
             | ActiveDialog::DialogChooser(_)
             | ActiveDialog::AdvanceMenu => vec![],
@@ -167,7 +171,10 @@ impl ActiveDialog {
 
     #[must_use]
     pub(crate) fn is_modal(&self) -> bool {
-        matches!(self, ActiveDialog::Log(_) | ActiveDialog::Info(_)) || self.is_advance()
+        matches!(
+            self,
+            ActiveDialog::Log(_) | ActiveDialog::CombatReport(_) | ActiveDialog::Info(_)
+        ) || self.is_advance()
     }
 
     #[must_use]
@@ -211,6 +218,8 @@ pub(crate) enum StateUpdate {
     SetFocusedTile(Position),
     ToggleShowPermanentEffects,
     ToggleAiPlay,
+    SuggestActions,
+    TakeSeat(usize),
 }
 
 pub(crate) type RenderResult = Result<(), Box<StateUpdate>>;
@@ -344,14 +353,15 @@ pub enum ColorProfile {
 }
 
 use crate::info_ui::InfoDialog;
-#[cfg(not(target_arch = "wasm32"))]
-use server::ai::AI;
+use server::ai::{AI, DecisionTrace};
+use server::ai_suggestion::Suggestion;
 use server::events::EventOrigin;
 
 pub struct State {
     pub(crate) assets: Assets,
     pub control_player: Option<usize>,
     pub show_player: usize,
+    pub pass_to_player: Option<usize>, // hot seat: hide the game until the player takes the seat
     pub(crate) active_dialog: ActiveDialog,
     pub(crate) pending_update: Option<PendingUpdate>,
     pub(crate) world_camera: Camera2D,
@@ -366,9 +376,11 @@ pub struct State {
     pub(crate) show_permanent_effects: bool,
     pub ai_autoplay: bool,
     pub(crate) pan_map: bool,
-    #[cfg(not(target_arch = "wasm32"))]
     pub ai_players: Vec<AI>,
+    pub ai_trace: Option<DecisionTrace>,
+    pub suggestions: Vec<Suggestion>,
     pub color_profile: ColorProfile,
+    pub catalog: Catalog,
 }
 
 pub const ZOOM: f32 = 0.001;
@@ -384,6 +396,7 @@ impl State {
             assets: Assets::new(features).await,
             control_player: None,
             show_player: 0,
+            pass_to_player: None,
             world_camera: Camera2D {
                 offset: OFFSET,
                 ..Default::default()
@@ -399,9 +412,11 @@ impl State {
             pan_map: false,
             show_permanent_effects: false,
             ai_autoplay: false,
-            #[cfg(not(target_arch = "wasm32"))]
             ai_players: vec![],
+            ai_trace: None,
+            suggestions: vec![],
             color_profile: ColorProfile::Standard,
+            catalog: Catalog::default(),
         }
     }
 
@@ -491,9 +506,21 @@ impl State {
                     GameSyncRequest::None
                 }
             }
+            StateUpdate::SuggestActions => GameSyncRequest::SuggestActions,
+            StateUpdate::TakeSeat(p) => {
+                self.pass_to_player = None;
+                self.control_player = Some(p);
+                self.show_player = p;
+                GameSyncRequest::None
+            }
         }
     }
 
+    pub fn hand_over_to(&mut self, player_index: usize) {
+        self.clear();
+        self.pass_to_player = Some(player_index);
+    }
+
     pub(crate) fn set_dialog(&mut self, dialog: ActiveDialog) {
         self.active_dialog = dialog;
     }
@@ -578,7 +605,7 @@ impl State {
             GameState::ChooseCivilization => {
                 ActiveDialog::Info(InfoDialog::choose_civilization(game))
             }
-            GameState::Playing | GameState::Finished => ActiveDialog::None,
+            GameState::Playing | GameState::Draft | GameState::Finished => ActiveDialog::None,
             GameState::Movement(move_state) => ActiveDialog::MoveUnits(MoveSelection::new(
                 game.active_player(),
                 self.focused_tile,
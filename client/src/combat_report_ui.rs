@@ -0,0 +1,160 @@
+use crate::client_state::{ActiveDialog, NO_UPDATE, RenderResult, StateUpdate};
+use crate::layout_ui::bottom_center_texture;
+use crate::log_ui::draw_die_roll;
+use crate::render_context::RenderContext;
+use crate::richtext::RichTextDrawer;
+use macroquad::math::vec2;
+use server::combat_report::{CombatReport, CombatReportSide};
+use server::game_api::combat_report;
+use server::log::ActionLogBalance;
+use server::tactics_card::CombatRole;
+
+#[derive(Clone, Debug)]
+pub(crate) struct CombatReportDialog {
+    pub reports: Vec<CombatReport>,
+    // one page per combat round
+    pub pages: Vec<(usize, usize)>,
+    pub current_page: usize,
+}
+
+impl CombatReportDialog {
+    pub(crate) fn new(rc: &RenderContext) -> Self {
+        let reports = combat_report(rc.game);
+        let pages: Vec<(usize, usize)> = reports
+            .iter()
+            .enumerate()
+            .flat_map(|(i, r)| (0..r.rounds.len()).map(move |round| (i, round)))
+            .collect();
+        CombatReportDialog {
+            current_page: pages.len().saturating_sub(1),
+            reports,
+            pages,
+        }
+    }
+
+    fn with_page(&self, page: usize) -> RenderResult {
+        let mut d = self.clone();
+        d.current_page = page;
+        StateUpdate::open_dialog(ActiveDialog::CombatReport(d))
+    }
+}
+
+pub(crate) fn show_combat_report(rc: &RenderContext, d: &CombatReportDialog) -> RenderResult {
+    let Some((battle, round)) = d.pages.get(d.current_page).copied() else {
+        rc.draw_text("No battles yet", 20., 45.);
+        return NO_UPDATE;
+    };
+    let report = &d.reports[battle];
+    let r = &report.rounds[round];
+
+    let mut y = 1.5;
+    let mut drawer = next_line(rc, &mut y);
+    drawer.text(&format!("Battle {}, Round {}:", battle + 1, r.round));
+    drawer.player(report.attacker);
+    drawer.text("attacks");
+    drawer.player(report.defender);
+
+    for role in [CombatRole::Attacker, CombatRole::Defender] {
+        draw_side(rc, report.side(round, role), role, &mut y);
+    }
+    if let Some(retreat) = r.retreat {
+        let mut drawer = next_line(rc, &mut y);
+        drawer.player(report.attacker);
+        drawer.text(if retreat {
+            "retreats"
+        } else {
+            "does not retreat"
+        });
+    }
+    if round == report.rounds.len() - 1
+        && let Some(stats) = &report.stats
+    {
+        let mut drawer = next_line(rc, &mut y);
+        match stats.winner() {
+            Some(winner) => {
+                drawer.player(report.side(round, winner).player);
+                drawer.text("wins the battle");
+            }
+            None => drawer.text("The battle ends in a draw"),
+        }
+    }
+
+    navigation(rc, d)
+}
+
+fn next_line<'a>(rc: &'a RenderContext, y: &mut f32) -> RichTextDrawer<'a> {
+    let drawer = RichTextDrawer::new(rc, vec2(20., *y * 25. + 20.));
+    *y += 1.;
+    drawer
+}
+
+fn draw_side(rc: &RenderContext, side: &CombatReportSide, role: CombatRole, y: &mut f32) {
+    let mut drawer = next_line(rc, y);
+    drawer.text(&format!("{role}:"));
+    drawer.player(side.player);
+    drawer.text("with");
+    drawer.units(&side.units, None);
+
+    if let Some(card) = side.tactics_card {
+        let name = &rc.game.cache.get_tactics_card(card).name;
+        next_line(rc, y).text(&format!("plays tactics card {name}"));
+    }
+    for roll in &side.rolls {
+        let mut drawer = next_line(rc, y);
+        drawer.text("   ");
+        draw_die_roll(&mut drawer, roll);
+    }
+    for m in &side.modifiers {
+        next_line(rc, y).text(&format!("    {}", rc.translate(m)));
+    }
+    let mut drawer = next_line(rc, y);
+    drawer.text(&format!(
+        "Combat value {} - {} hits",
+        side.combat_value, side.hits
+    ));
+    if side.hit_cancels > 0 {
+        drawer.text(&format!("(cancels {} hits)", side.hit_cancels));
+    }
+    if !side.casualties.is_empty() {
+        let mut drawer = next_line(rc, y);
+        drawer.text("Casualties:");
+        drawer.units(&side.casualties, Some(&ActionLogBalance::Loss));
+    }
+    for n in &side.notes {
+        next_line(rc, y).text(&format!(
+            "    {}: {}",
+            n.origin.name(rc.game),
+            rc.translate(&n.message)
+        ));
+    }
+}
+
+fn navigation(rc: &RenderContext, d: &CombatReportDialog) -> RenderResult {
+    let last = d.pages.len() - 1;
+    if d.current_page > 0
+        && bottom_center_texture(rc, &rc.assets().start, vec2(-160., -30.), "First round")
+    {
+        return d.with_page(0);
+    }
+    if d.current_page > 0
+        && bottom_center_texture(rc, &rc.assets().undo, vec2(-90., -30.), "Previous round")
+    {
+        return d.with_page(d.current_page - 1);
+    }
+    if d.current_page < last
+        && bottom_center_texture(rc, &rc.assets().redo, vec2(42., -30.), "Next round")
+    {
+        return d.with_page(d.current_page + 1);
+    }
+    if d.current_page < last
+        && bottom_center_texture(rc, &rc.assets().end, vec2(112., -30.), "Last round")
+    {
+        return d.with_page(last);
+    }
+    rc.draw_text(
+        &format!("Page {} / {}", d.current_page + 1, d.pages.len()),
+        rc.state.screen_size.x / 2. - 40.,
+        rc.state.screen_size.y - 60.,
+    );
+    NO_UPDATE
+}
@@ -305,7 +305,7 @@ pub(crate) fn multi_select_tooltip<T: Clone + PartialEq + Ord>(
 }
 
 pub(crate) fn bool_request_dialog(rc: &RenderContext, description: &str) -> RenderResult {
-    bottom_centered_text(rc, description);
+    bottom_centered_text(rc, &rc.translate_text(description));
     if ok_button(rc, OkTooltip::Valid("OK".to_string())) {
         return bool_answer(true);
     }
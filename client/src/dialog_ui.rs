@@ -63,8 +63,11 @@ pub(crate) fn cancel_button_pos() -> Vec2 {
 pub(crate) fn ok_button(rc: &RenderContext, ok_tooltip: OkTooltip) -> bool {
     let pos = icon_pos(-8, -1);
     match ok_tooltip {
-        OkTooltip::Valid(tooltip) => bottom_right_texture(rc, &rc.assets().ok, pos, &tooltip),
+        OkTooltip::Valid(tooltip) => {
+            bottom_right_texture(rc, &rc.assets().ok, pos, &rc.translate_text(&tooltip))
+        }
         OkTooltip::Invalid(tooltip) => {
+            let tooltip = rc.translate_text(&tooltip);
             let _ = bottom_right_texture(rc, &rc.assets().ok_blocked, pos, &tooltip);
             false
         }
@@ -17,6 +17,9 @@ pub(crate) fn event_help_tooltip(rc: &RenderContext, origin: &EventOrigin) -> Mu
 #[must_use]
 pub(crate) fn event_help(rc: &RenderContext, origin: &EventOrigin) -> Vec<String> {
     effects::event_help(rc.game, origin)
+        .iter()
+        .map(|m| rc.translate(m))
+        .collect()
 }
 
 #[must_use]
@@ -0,0 +1,20 @@
+use crate::client_state::{NO_UPDATE, RenderResult, StateUpdate};
+use crate::dialog_ui::{OkTooltip, ok_button};
+use crate::layout_ui::{bottom_centered_text, top_centered_text};
+use crate::render_context::RenderContext;
+use macroquad::math::vec2;
+
+pub(crate) fn pass_to_player_dialog(rc: &RenderContext, player_index: usize) -> RenderResult {
+    let name = rc.game.player_name(player_index);
+    top_centered_text(
+        rc,
+        &format!("Pass the device to {name}"),
+        vec2(0., rc.state.screen_size.y / 2.),
+    );
+    bottom_centered_text(rc, &format!("{name}: click OK when you are ready"));
+
+    if ok_button(rc, OkTooltip::Valid(format!("Show the game for {name}"))) {
+        return StateUpdate::of(StateUpdate::TakeSeat(player_index));
+    }
+    NO_UPDATE
+}
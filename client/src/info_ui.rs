@@ -24,6 +24,7 @@ use server::leader::Leader;
 use server::objective_card::ObjectiveCard;
 use server::pirates::get_pirates_player;
 use server::resource::ResourceType;
+use server::scenario::Scenario;
 use server::unit::UnitType;
 use server::wonder::{Wonder, WonderInfo};
 use std::fmt::Display;
@@ -67,6 +68,7 @@ pub(crate) struct InfoDialog {
     pub objective_card: u8,
     pub unit: UnitType,
     pub building: Building,
+    pub scenario: String,
 }
 
 impl InfoDialog {
@@ -92,6 +94,7 @@ impl InfoDialog {
             objective_card: 1,
             unit: UnitType::Settler,
             building: Building::Academy,
+            scenario: String::new(),
         }
     }
 }
@@ -105,6 +108,7 @@ pub(crate) enum InfoCategory {
     ObjectiveCard,
     Buildings,
     Unit,
+    Scenario,
 }
 
 pub(crate) fn show_info_dialog(rc: &RenderContext, d: &InfoDialog) -> RenderResult {
@@ -122,6 +126,7 @@ pub(crate) fn show_info_dialog(rc: &RenderContext, d: &InfoDialog) -> RenderResu
     show_objective_cards(rc, d)?;
     show_buildings(rc, d)?;
     show_units(rc, d)?;
+    show_scenarios(rc, d)?;
 
     NO_UPDATE
 }
@@ -318,6 +323,34 @@ fn show_units(rc: &RenderContext, d: &InfoDialog) -> RenderResult {
     })
 }
 
+fn show_scenarios(rc: &RenderContext, d: &InfoDialog) -> RenderResult {
+    show_category(rc, d, 7, InfoCategory::Scenario, "Scenarios", |rc, d| {
+        show_category_items::<Scenario, String>(
+            rc,
+            d,
+            |g| g.cache.get_scenarios(),
+            |s| &s.name,
+            |s| s.name.clone(),
+            |_, _| None,
+            |s| {
+                let mut desc = MultilineText::of(rc, &s.name);
+                desc.add(rc, &s.description);
+                desc.add(rc, &format!("Players: {}", s.civilizations().join(", ")));
+                for c in &s.victory_conditions {
+                    desc.add(rc, &format!("Victory: {}", c.description()));
+                }
+                if rc.game.options.scenario.as_ref() == Some(&s.name) {
+                    desc.add(rc, "This game is played with this scenario");
+                }
+                desc
+            },
+            |d| &d.scenario,
+            |d, s| d.scenario = s,
+            |_| None,
+        )
+    })
+}
+
 fn show_category(
     rc: &RenderContext,
     d: &InfoDialog,
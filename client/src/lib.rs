@@ -11,18 +11,21 @@ extern crate core;
 
 mod action_buttons;
 mod advance_ui;
+mod ai_trace_ui;
 mod assets;
 mod cards_ui;
 mod city_ui;
 pub mod client;
 pub mod client_state;
 mod collect_ui;
+mod combat_report_ui;
 mod construct_ui;
 mod custom_phase_ui;
 mod dialog_ui;
 mod event_ui;
 mod happiness_ui;
 mod hex_ui;
+mod hot_seat_ui;
 mod influence_ui;
 mod info_ui;
 mod layout_ui;
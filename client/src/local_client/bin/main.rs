@@ -8,8 +8,10 @@ use macroquad::window::screen_height;
 use server::action::execute_action;
 use server::cache::Cache;
 use server::game::{CivSetupOption, Game, GameContext, GameOptions, PatchOption, UndoOption};
+use server::game_api::strip_secret;
 use server::game_data::GameData;
 use server::game_setup::{GameSetupBuilder, setup_game};
+use server::localization::{Catalog, Language};
 use server::profiling::start_profiling;
 use std::fs::File;
 use std::io::BufReader;
@@ -20,6 +22,8 @@ enum Mode {
     Local,
     ChooseCivilization,
     AI,
+    HotSeat,
+    Scenario,
 }
 
 #[macroquad::main("Clash")]
@@ -40,6 +44,8 @@ async fn main() {
         import_export: true,
         assets_url: "assets/".to_string(),
         ai: modes.contains(&Mode::AI),
+        hot_seat: modes.contains(&Mode::HotSeat),
+        suggestion_time: Some(std::time::Duration::from_secs(2)),
     };
 
     let seed = if args.len() > 2 {
@@ -47,20 +53,22 @@ async fn main() {
     } else {
         "a".repeat(32)
     };
-    let game = setup_game(
-        &GameSetupBuilder::new(players)
-            .seed(seed)
-            .options(GameOptions {
-                undo: UndoOption::SamePlayer,
-                civilization: if modes.contains(&Mode::ChooseCivilization) {
-                    CivSetupOption::ChooseCivilization
-                } else {
-                    CivSetupOption::Random
-                },
-                patch: PatchOption::Standard,
-            })
-            .build(),
-    );
+    let mut builder = GameSetupBuilder::new(players)
+        .seed(seed)
+        .options(GameOptions {
+            undo: UndoOption::SamePlayer,
+            civilization: if modes.contains(&Mode::ChooseCivilization) {
+                CivSetupOption::ChooseCivilization
+            } else {
+                CivSetupOption::Random
+            },
+            patch: PatchOption::Standard,
+            ..GameOptions::default()
+        });
+    if modes.contains(&Mode::Scenario) {
+        builder = builder.scenario(&scenario_name(&args));
+    }
+    let game = setup_game(&builder.build());
 
     run(game, &mut features).await;
 }
@@ -71,6 +79,8 @@ fn get_modes(args: &[String]) -> Vec<Mode> {
             "generate" => vec![Mode::Local],
             "choose" => vec![Mode::Local, Mode::ChooseCivilization],
             "ai" => vec![Mode::AI, Mode::Local],
+            "hotseat" => vec![Mode::Local, Mode::HotSeat],
+            "scenario" => vec![Mode::Local, Mode::Scenario],
             _ => {
                 panic!("Unknown argument: {arg}");
             }
@@ -79,37 +89,88 @@ fn get_modes(args: &[String]) -> Vec<Mode> {
     }
 }
 
+fn scenario_name(args: &[String]) -> String {
+    let cache = Cache::new(&GameOptions::default());
+    let scenarios = cache.get_scenarios();
+    match args.get(1) {
+        Some(name) if scenarios.iter().any(|s| &s.name == name) => name.clone(),
+        _ => {
+            let names = scenarios
+                .iter()
+                .map(|s| format!("{} ({} players)", s.name, s.players.len()))
+                .collect::<Vec<_>>()
+                .join(", ");
+            panic!("Please provide one of the scenarios as the second argument: {names}");
+        }
+    }
+}
+
 async fn run(mut game: Game, features: &mut Features) {
     let mut state = init(features).await;
+    if let Some(language) = env::var("CLASH_LANGUAGE")
+        .ok()
+        .and_then(|l| Language::from_code(&l))
+    {
+        state.catalog = Catalog::new(language);
+    }
     state.update_from_game(&game);
 
     start_ai(&mut game, features, &mut state);
 
     let mut sync_result = GameSyncResult::None;
     state.show_player = game.active_player();
+    let mut hot_seat_view: Option<HotSeatView> = None;
     loop {
-        state.control_player = Some(game.active_player());
+        let active_player = game.active_player();
+        if !features.hot_seat {
+            state.control_player = Some(active_player);
+        } else if state.control_player != Some(active_player)
+            && state.pass_to_player != Some(active_player)
+        {
+            state.hand_over_to(active_player);
+        }
         state.raw_screen_size = vec2(screen_width(), screen_height());
 
-        let message = render_and_update(&game, &mut state, &sync_result, features);
+        if features.hot_seat
+            && hot_seat_view
+                .as_ref()
+                .is_none_or(|v| v.seat != state.control_player)
+        {
+            hot_seat_view = Some(HotSeatView::new(&game, state.control_player));
+            sync_result = GameSyncResult::Update;
+        }
+        let shown_game = hot_seat_view.as_ref().map_or(&game, |v| &v.game);
+
+        let message = render_and_update(shown_game, &mut state, &sync_result, features);
         sync_result = GameSyncResult::None;
         match message {
             GameSyncRequest::None => {}
             GameSyncRequest::StartAutoplay => {
+                state.suggestions.clear();
                 game = ai_autoplay(game, features, &mut state);
                 state.show_player = game.active_player();
+                hot_seat_view = None;
                 sync_result = GameSyncResult::Update;
             }
+            GameSyncRequest::SuggestActions => {
+                suggest_actions(&game, features, &mut state);
+            }
             GameSyncRequest::ExecuteAction(a) => {
+                state.suggestions.clear();
                 let p = game.active_player();
                 game = execute_action(game, a, p);
                 game = ai_autoplay(game, features, &mut state);
-                state.show_player = game.active_player();
+                if !features.hot_seat {
+                    state.show_player = game.active_player();
+                }
+                hot_seat_view = None;
                 sync_result = GameSyncResult::Update;
             }
             GameSyncRequest::Import => {
+                state.suggestions.clear();
                 game = import();
                 state.show_player = game.active_player();
+                hot_seat_view = None;
                 sync_result = GameSyncResult::Update;
             }
             GameSyncRequest::Export => {
@@ -120,34 +181,72 @@ async fn run(mut game: Game, features: &mut Features) {
     }
 }
 
-#[cfg(target_arch = "wasm32")]
-fn start_ai(_: &mut Game, _: &mut Features, _: &mut State) {}
+// the game as seen by the player sitting in front of the screen
+struct HotSeatView {
+    seat: Option<usize>,
+    game: Game,
+}
+
+impl HotSeatView {
+    fn new(game: &Game, seat: Option<usize>) -> Self {
+        Self {
+            seat,
+            game: strip_secret(game.clone(), seat),
+        }
+    }
+}
 
-#[cfg(not(target_arch = "wasm32"))]
 fn start_ai(game: &mut Game, features: &mut Features, state: &mut State) {
     use server::ai::AI;
+    use server::ai_opening_book::OpeningBook;
+    use server::ai_profile::AiProfile;
 
     if features.ai {
+        // one profile per seat, e.g. CLASH_AI_PROFILES=militarist,expansionist
+        let profiles = env::var("CLASH_AI_PROFILES").unwrap_or_default();
+        let profiles = profiles
+            .split(',')
+            .filter(|p| !p.is_empty())
+            .map(|p| AiProfile::from_code(p).unwrap_or_else(|| panic!("Unknown AI profile: {p}")))
+            .collect::<Vec<_>>();
+        // created with "api_tool opening-book"
+        let book = env::var("CLASH_OPENING_BOOK").ok().map(|path| {
+            let book: OpeningBook = serde_json::from_str(
+                &std::fs::read_to_string(path).expect("Failed to read opening book"),
+            )
+            .expect("Failed to parse opening book");
+            std::sync::Arc::new(book)
+        });
         state.ai_players = game
             .human_player_ids()
             .into_iter()
-            .map(|p| AI::new(1., std::time::Duration::from_secs(5), false, game, p))
+            .enumerate()
+            .map(|(i, p)| {
+                let profile = profiles.get(i).copied().unwrap_or_default();
+                let mut ai = AI::new(
+                    1.,
+                    std::time::Duration::from_secs(5),
+                    false,
+                    game,
+                    p,
+                    profile,
+                );
+                if let Some(book) = &book {
+                    ai.set_opening_book(book.clone());
+                }
+                ai
+            })
             .collect()
     }
 }
 
-#[cfg(target_arch = "wasm32")]
-fn ai_autoplay(game: Game, _: &mut Features, _: &mut State) -> Game {
-    game
-}
-
-#[cfg(not(target_arch = "wasm32"))]
 fn ai_autoplay(mut game: Game, f: &mut Features, state: &mut State) -> Game {
     if f.ai {
         while state.ai_autoplay && game.state != server::game::GameState::Finished {
             let active_player = game.active_player();
             let ai = &mut state.ai_players[active_player];
-            let action = ai.next_action(&game);
+            let (action, trace) = ai.next_action_with_trace(&game);
+            state.ai_trace = Some(trace);
             let player_index = game.active_player();
             game = execute_action(game, action, player_index);
             export(&game)
@@ -156,6 +255,13 @@ fn ai_autoplay(mut game: Game, f: &mut Features, state: &mut State) -> Game {
     game
 }
 
+fn suggest_actions(game: &Game, f: &mut Features, state: &mut State) {
+    if let Some(thinking_time) = f.suggestion_time {
+        state.suggestions =
+            server::ai_suggestion::suggest_actions(game, game.active_player(), thinking_time, 3);
+    }
+}
+
 const EXPORT_FILE: &str = "game.json";
 
 fn import() -> Game {
@@ -7,6 +7,7 @@ use server::content::ability::combat_event_origin;
 use server::cultural_influence::InfluenceCultureAttemptInfo;
 use server::events::EventOrigin;
 use server::game_setup::setup_event_origin;
+use server::localization::Message;
 use server::log::{
     ActionLogAction, ActionLogAge, ActionLogBalance, ActionLogEntry, ActionLogItem, ActionLogRound,
     ActionLogTurn, SetupTurnType, TurnType,
@@ -127,16 +128,26 @@ pub(crate) fn collect_log_entries(rc: &RenderContext) -> Vec<LogEntry> {
     for age in &rc.game.log {
         c.age = age.age;
         if age.age == 0 {
-            c.add_message("Game Start", 0);
+            c.add_message(
+                &rc.translate(&Message::new("log.game_start", "Game Start")),
+                0,
+            );
         }
         for round in &age.rounds {
             c.round = round.round;
             if round.round > 0 {
-                c.add_message(&format!("Age {}, Round {}", age.age, round.round), 0);
+                c.add_message(
+                    &rc.translate(
+                        &Message::new("log.age_round", "Age {age}, Round {round}")
+                            .param("age", age.age)
+                            .param("round", round.round),
+                    ),
+                    0,
+                );
             }
             for turn in &round.turns {
                 c.active_origin = None;
-                let indent = add_title_items(&mut c, age, round, turn);
+                let indent = add_title_items(rc, &mut c, age, round, turn);
                 for action in &turn.actions {
                     collect_action(rc, &mut c, action, indent + action.active_events);
                 }
@@ -169,8 +180,8 @@ fn collect_action(
 
     for item in &items {
         match &item.entry {
-            ActionLogEntry::Text(m) => {
-                add_text_item(rc, c, indent, item, m);
+            ActionLogEntry::Message(m) => {
+                add_text_item(rc, c, indent, item, &rc.translate(m));
                 continue;
             }
             ActionLogEntry::HandCard {
@@ -185,7 +196,7 @@ fn collect_action(
 
         set_item_origin(c, item);
         c.add_entry(LogBody::Item(item.clone()), indent);
-        add_additional_items(c, item, indent);
+        add_additional_items(rc, c, item, indent);
     }
 }
 
@@ -242,6 +253,7 @@ fn set_item_origin(c: &mut LogCollector, item: &ActionLogItem) {
 }
 
 fn add_title_items(
+    rc: &RenderContext,
     c: &mut LogCollector,
     age: &ActionLogAge,
     round: &ActionLogRound,
@@ -264,16 +276,30 @@ fn add_title_items(
             c.add_entry(LogBody::PlayerSetup(t.clone()), 1);
             2
         }
-        TurnType::StatusPhase(t) => status_phase_title(age, *t, c),
+        TurnType::StatusPhase(t) => status_phase_title(rc, age, *t, c),
+        TurnType::Draft(r) => {
+            c.add_message(
+                &rc.translate(
+                    &Message::new("log.draft", "Card Draft (Round {round})").param("round", *r),
+                ),
+                2,
+            );
+            3 // response adds another indent
+        }
     }
 }
 
-fn add_additional_items(c: &mut LogCollector, item: &ActionLogItem, indent: usize) {
+fn add_additional_items(
+    rc: &RenderContext,
+    c: &mut LogCollector,
+    item: &ActionLogItem,
+    indent: usize,
+) {
     match &item.entry {
         ActionLogEntry::CombatRound(r) => {
             c.add_entry(
                 LogBody::CombatUnits {
-                    role: "Attacking:".to_string(),
+                    role: rc.translate(&Message::new("log.combat.attacking", "Attacking:")),
                     player: item.player,
                     units: r.attackers.clone(),
                 },
@@ -281,7 +307,7 @@ fn add_additional_items(c: &mut LogCollector, item: &ActionLogItem, indent: usiz
             );
             c.add_entry(
                 LogBody::CombatUnits {
-                    role: "Defending:".to_string(),
+                    role: rc.translate(&Message::new("log.combat.defending", "Defending:")),
                     player: r.defending_player,
                     units: r.defenders.clone(),
                 },
@@ -290,7 +316,7 @@ fn add_additional_items(c: &mut LogCollector, item: &ActionLogItem, indent: usiz
         }
         ActionLogEntry::CombatRoll(r) => {
             for m in &r.combat_modifiers {
-                c.add_message(m, indent + 1);
+                c.add_message(&rc.translate(m), indent + 1);
             }
             for r in &r.rolls {
                 c.add_entry(LogBody::DieRoll(r.clone()), indent + 1);
@@ -300,7 +326,7 @@ fn add_additional_items(c: &mut LogCollector, item: &ActionLogItem, indent: usiz
     }
 }
 
-fn inline_action_items(items: &mut Vec<ActionLogItem>, action: &mut ActionLogBody) {
+pub(crate) fn inline_action_items(items: &mut Vec<ActionLogItem>, action: &mut ActionLogBody) {
     if remove_element_by(items, |item| {
         matches!(
             item,
@@ -422,20 +448,35 @@ fn pull_action_arg(items: &mut Vec<ActionLogItem>, body: &ActionLogBody) -> Opti
     }
 }
 
-fn status_phase_title(age: &ActionLogAge, t: StatusPhaseStateType, c: &mut LogCollector) -> usize {
+fn status_phase_title(
+    rc: &RenderContext,
+    age: &ActionLogAge,
+    t: StatusPhaseStateType,
+    c: &mut LogCollector,
+) -> usize {
     if matches!(t, StatusPhaseStateType::CompleteObjectives) {
-        c.add_message(&format!("Status Phase (Age {})", age.age), 2);
+        c.add_message(
+            &rc.translate(
+                &Message::new("log.status_phase", "Status Phase (Age {age})").param("age", age.age),
+            ),
+            2,
+        );
     }
 
+    let (key, text) = match t {
+        StatusPhaseStateType::CompleteObjectives => ("complete_objectives", "Complete Objectives"),
+        StatusPhaseStateType::FreeAdvance => ("free_advance", "Free Advance"),
+        StatusPhaseStateType::DrawCards => ("draw_cards", "Draw Cards"),
+        StatusPhaseStateType::RazeSize1City => ("raze_size_1_city", "Raze Size 1 City"),
+        StatusPhaseStateType::ChangeGovernmentType => {
+            ("change_government_type", "Change Government Type")
+        }
+        StatusPhaseStateType::DetermineFirstPlayer => {
+            ("determine_first_player", "Determine First Player")
+        }
+    };
     c.add_message(
-        match t {
-            StatusPhaseStateType::CompleteObjectives => "Complete Objectives",
-            StatusPhaseStateType::FreeAdvance => "Free Advance",
-            StatusPhaseStateType::DrawCards => "Draw Cards",
-            StatusPhaseStateType::RazeSize1City => "Raze Size 1 City",
-            StatusPhaseStateType::ChangeGovernmentType => "Change Government Type",
-            StatusPhaseStateType::DetermineFirstPlayer => "Determine First Player",
-        },
+        &rc.translate(&Message::new(&format!("log.status_phase.{key}"), text)),
         3,
     );
     if matches!(t, StatusPhaseStateType::DrawCards) {
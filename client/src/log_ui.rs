@@ -1,6 +1,8 @@
 use crate::client_state::{ActiveDialog, NO_UPDATE, RenderResult, State, StateUpdate};
 use crate::layout_ui::bottom_center_texture;
-use crate::log_collector::{ActionLogBody, LogBody, LogEntry, collect_log_entries};
+use crate::log_collector::{
+    ActionLogBody, LogBody, LogEntry, collect_log_entries, inline_action_items,
+};
 use crate::render_context::RenderContext;
 use crate::richtext::RichTextDrawer;
 use macroquad::math::vec2;
@@ -10,8 +12,9 @@ use server::combat_roll::UnitCombatRoll;
 use server::content::custom_actions::SpecialAction;
 use server::content::persistent_events::EventResponse;
 use server::events::EventOrigin;
+use server::localization::Message;
 use server::log::{
-    ActionLogBalance, ActionLogEntry, ActionLogEntryAdvance, ActionLogEntryMove,
+    ActionLogAction, ActionLogBalance, ActionLogEntry, ActionLogEntryAdvance, ActionLogEntryMove,
     ActionLogIncidentToken, ActionLogItem,
 };
 use server::movement::MovementAction;
@@ -192,7 +195,7 @@ fn draw_item(entry: &LogEntry, drawer: &mut RichTextDrawer, item: &ActionLogItem
     });
 }
 
-fn draw_die_roll(drawer: &mut RichTextDrawer, r: &UnitCombatRoll) {
+pub(crate) fn draw_die_roll(drawer: &mut RichTextDrawer, r: &UnitCombatRoll) {
     let (bonus_str, add_value) = if r.bonus {
         match r.unit_type {
             UnitType::Settler | UnitType::Ship => panic!("unit does not have bonus"),
@@ -268,8 +271,21 @@ fn draw_entry(drawer: &mut RichTextDrawer, entry: &ActionLogEntry, player: usize
                 r.combat_value, r.hits,
             ));
         }
-        ActionLogEntry::Text(m) => {
-            drawer.text(m);
+        ActionLogEntry::CombatRetreat { retreat } => {
+            let text = if *retreat {
+                drawer
+                    .rc
+                    .translate(&Message::new("log.combat.retreat", "Retreat"))
+            } else {
+                drawer
+                    .rc
+                    .translate(&Message::new("log.combat.no_retreat", "Do not retreat"))
+            };
+            drawer.text(&text);
+        }
+        ActionLogEntry::Message(m) => {
+            let text = drawer.rc.translate(m);
+            drawer.text(&text);
         }
         ActionLogEntry::InfluenceCultureAttempt(i) => {
             drawer.structure(&i.structure, i.position, None, i.target_player);
@@ -345,6 +361,14 @@ pub(crate) fn multiline_label(
     }
 }
 
+///
+/// Draws an action that was not executed (yet) like in the log, e.g. a suggested move.
+pub(crate) fn draw_log_action(drawer: &mut RichTextDrawer, action: &ActionLogAction) {
+    let mut body = ActionLogBody::new(action.clone());
+    inline_action_items(&mut action.items.clone(), &mut body);
+    draw_action(drawer, &body);
+}
+
 fn draw_action(drawer: &mut RichTextDrawer, body: &ActionLogBody) {
     if body.action_cost {
         drawer.action_icon();
@@ -2,7 +2,8 @@ use crate::client_state::{ActiveDialog, NO_UPDATE, RenderResult, StateUpdate};
 use crate::dialog_ui::OkTooltip;
 use crate::event_ui::event_help;
 use crate::layout_ui::{
-    ICON_SIZE, bottom_centered_text_with_offset, draw_scaled_icon_with_tooltip,
+    ICON_SIZE, bottom_center_texture, bottom_centered_text_with_offset,
+    draw_scaled_icon_with_tooltip,
 };
 use crate::multiline::MultilineText;
 use crate::render_context::RenderContext;
@@ -46,6 +47,7 @@ pub(crate) struct Payment<T: Clone> {
     pub available: ResourcePile,
     pub optional: bool,
     pub current: Vec<ResourcePayment>,
+    pub alternatives: Vec<ResourcePile>,
 }
 
 impl<T> Payment<T>
@@ -67,6 +69,7 @@ where
             available: available.clone(),
             optional,
             current: resource_payment(cost, available),
+            alternatives: cost.minimal_payments(available),
         }
     }
 
@@ -135,9 +138,10 @@ pub(crate) fn multi_payment_dialog<T: Clone>(
     let mut exec = false;
     let mut added: Option<Payment<T>> = None;
     let mut removed: Option<Payment<T>> = None;
+    let mut alternative: Option<Payment<T>> = None;
 
     for (i, payment) in payments.iter().enumerate() {
-        let name = &payment.name;
+        let name = &rc.translate_text(&payment.name);
         let cost = payment.cost.clone();
         let types = cost.possible_resource_types();
         let offset = vec2(0., i as f32 * -100.);
@@ -200,12 +204,27 @@ pub(crate) fn multi_payment_dialog<T: Clone>(
             may_cancel,
         )?;
 
+        let shown = types.iter().unique().count();
+        if payment.alternatives.len() > 1
+            && bottom_center_texture(
+                rc,
+                &rc.assets().rotate_explore,
+                vec2(shown as f32 * ICON_SIZE, -85.) + offset,
+                "Next payment alternative",
+            )
+        {
+            alternative = Some(next_alternative(payment.clone()));
+        }
+
         if let Some(p) = added {
             return StateUpdate::open_dialog(to_dialog(replace_updated_payment(&p, payments)));
         }
         if let Some(p) = removed {
             return StateUpdate::open_dialog(to_dialog(replace_updated_payment(&p, payments)));
         }
+        if let Some(p) = alternative {
+            return StateUpdate::open_dialog(to_dialog(replace_updated_payment(&p, payments)));
+        }
 
         if exec {
             return execute_action(payments.iter().map(Payment::to_resource_pile).collect());
@@ -279,6 +298,21 @@ pub(crate) fn plus<T: Clone>(mut payment: Payment<T>, t: ResourceType) -> Paymen
     payment
 }
 
+// cycles through the payments that don't waste any resources
+pub(crate) fn next_alternative<T: Clone>(mut payment: Payment<T>) -> Payment<T> {
+    let current = payment.to_resource_pile();
+    let next = payment
+        .alternatives
+        .iter()
+        .position(|p| p == &current)
+        .map_or(0, |i| (i + 1) % payment.alternatives.len());
+    let pile = payment.alternatives[next].clone();
+    for r in &mut payment.current {
+        r.selectable.current = pile.get(&r.resource);
+    }
+    payment
+}
+
 pub(crate) fn minus<T: Clone>(mut payment: Payment<T>, t: ResourceType) -> Payment<T> {
     payment.get_mut(t).selectable.current -= 1;
     payment
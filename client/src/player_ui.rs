@@ -360,7 +360,7 @@ fn show_permanent_effects(painter: &mut ColumnLabelPainter, game: &Game, player:
     painter.label("Permanent effects:");
     for e in &game.permanent_effects {
         for m in e.description(painter.rc.game) {
-            painter.label(&m);
+            painter.label(&painter.rc.translate(&m));
         }
     }
 }
@@ -423,6 +423,14 @@ pub(crate) fn show_global_controls(rc: &RenderContext, features: &Features) -> R
         }
     }
 
+    if features.suggestion_time.is_some()
+        && can_control
+        && !rc.state.ai_autoplay
+        && bottom_right_texture(rc, &rc.assets().info, icon_pos(-4, -3), "Suggest moves")
+    {
+        return StateUpdate::of(StateUpdate::SuggestActions);
+    }
+
     NO_UPDATE
 }
 
@@ -433,7 +441,7 @@ fn can_end_move(game: &Game) -> Option<&str> {
     match game.state {
         GameState::Movement(_) => Some("End movement"),
         GameState::Playing => Some("End turn"),
-        GameState::Finished | GameState::ChooseCivilization => None,
+        GameState::Finished | GameState::ChooseCivilization | GameState::Draft => None,
     }
 }
 
@@ -470,7 +478,7 @@ pub(crate) fn choose_player_dialog(
     let h = -50.;
     bottom_centered_text_with_offset(
         rc,
-        &request.description,
+        &rc.translate_text(&request.description),
         vec2(0., choices.len() as f32 * h + 50.),
         &MultilineText::default(),
     );
@@ -177,7 +177,7 @@ pub(crate) fn select_dialog(rc: &RenderContext, a: &RecruitAmount) -> RenderResu
                 match &s.cost {
                     Ok(_) => HighlightType::None,
                     Err(e) => {
-                        if e.contains("Mising building:") {
+                        if e.contains("Missing building:") {
                             HighlightType::MissingAdvance
                         } else if e == NOT_ENOUGH_RESOURCES {
                             HighlightType::NotEnoughResources
@@ -195,7 +195,7 @@ pub(crate) fn select_dialog(rc: &RenderContext, a: &RecruitAmount) -> RenderResu
         |s, p| {
             let suffix = match &s.cost {
                 Ok(_) => format!(" ({} available with current resources)", s.selectable.max),
-                Err(e) => format!(" ({e})"),
+                Err(e) => format!(" ({})", rc.translate_text(e)),
             };
             let mut tooltip =
                 MultilineText::of(rc, &format!("Recruit {}{}", s.unit_type.name(game), suffix));
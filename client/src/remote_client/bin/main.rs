@@ -10,6 +10,7 @@ use serde::{Deserialize, Serialize};
 use server::action::Action;
 use server::cache::Cache;
 use server::game_data::GameData;
+use server::localization::{Catalog, Language};
 use std::panic;
 use std::str::FromStr;
 use wasm_bindgen::prelude::*;
@@ -77,6 +78,8 @@ struct Preferences {
     ui_scale: String,
     world_zoom_factor: String,
     color_profile: ColorProfile,
+    #[serde(default)]
+    language: String,
 }
 
 #[macroquad::main("Clash")]
@@ -98,6 +101,8 @@ impl RemoteClient {
             import_export: false,
             assets_url: control.assets_url(),
             ai: false,
+            hot_seat: false,
+            suggestion_time: None,
         };
         let state = init(&features).await;
 
@@ -161,6 +166,10 @@ impl RemoteClient {
         let profile = p.color_profile;
         log(&format!("set color profile to {profile:?}",));
         self.state.color_profile = profile;
+
+        let language = Language::from_code(&p.language).unwrap_or_default();
+        log(&format!("set language to {language:?}"));
+        self.state.catalog = Catalog::new(language);
     }
 
     fn update_state(&mut self) -> GameSyncResult {
@@ -7,6 +7,7 @@ use macroquad::input::mouse_position;
 use macroquad::math::{Vec2, bool};
 use macroquad::prelude::{BLACK, LIME, ORANGE, Rect, SKYBLUE, WHITE, set_camera};
 use server::game::Game;
+use server::localization::Message;
 use server::payment::PaymentOptions;
 use server::player::Player;
 use server::playing_actions::PlayingActionType;
@@ -51,6 +52,14 @@ impl RenderContext<'_> {
         &self.state.assets
     }
 
+    pub(crate) fn translate(&self, message: &Message) -> String {
+        self.state.catalog.translate(message)
+    }
+
+    pub(crate) fn translate_text(&self, text: &str) -> String {
+        self.state.catalog.translate_text(text)
+    }
+
     pub(crate) fn with_camera(
         &self,
         mode: CameraMode,
@@ -16,6 +16,7 @@ use crate::content::persistent_events::{
 };
 use crate::events::{Event, EventOrigin, EventPlayer};
 use crate::game::GameContext;
+use crate::localization::Message;
 use crate::player::Player;
 use crate::player_events::{PersistentEvent, PersistentEvents, TransientEvents};
 use crate::playing_actions::PlayingActionType;
@@ -34,7 +35,7 @@ pub(crate) type AbilityInitializer = Arc<dyn Fn(&mut Game, usize) + Sync + Send>
 
 pub(crate) type AbilityInitializerWithPrioDelta = Arc<dyn Fn(&mut Game, usize, i32) + Sync + Send>;
 
-pub(crate) struct SelectedChoice<A, C> {
+pub struct SelectedChoice<A, C> {
     pub player_index: usize,
     pub origin: EventOrigin,
     pub actively_selected: bool,
@@ -61,14 +62,14 @@ impl<A, C> SelectedChoice<A, C> {
         EventPlayer::new(player_index, self.origin.clone())
     }
 
-    pub fn log(&self, game: &mut Game, message: &str) {
-        game.log(self.player_index, &self.origin, message);
+    pub fn log_message(&self, game: &mut Game, message: Message) {
+        game.log_message(self.player_index, &self.origin, message);
     }
 }
 
-pub(crate) type SelectedMultiChoice<C> = SelectedChoice<C, C>;
-pub(crate) type SelectedSingleChoice<C> = SelectedChoice<Vec<C>, C>;
-pub(crate) type SelectedWithoutChoices<C> = SelectedChoice<(), C>;
+pub type SelectedMultiChoice<C> = SelectedChoice<C, C>;
+pub type SelectedSingleChoice<C> = SelectedChoice<Vec<C>, C>;
+pub type SelectedWithoutChoices<C> = SelectedChoice<(), C>;
 
 #[derive(Clone)]
 pub struct AbilityListeners {
@@ -102,7 +103,7 @@ impl AbilityListeners {
     }
 }
 
-pub(crate) struct AbilityInitializerBuilder {
+pub struct AbilityInitializerBuilder {
     initializers: Vec<AbilityInitializerWithPrioDelta>,
     deinitializers: Vec<AbilityInitializer>,
     once_initializers: Vec<AbilityInitializer>,
@@ -110,6 +111,7 @@ pub(crate) struct AbilityInitializerBuilder {
 }
 
 impl AbilityInitializerBuilder {
+    #[must_use]
     pub fn new() -> Self {
         Self {
             initializers: Vec::new(),
@@ -157,8 +159,14 @@ impl AbilityInitializerBuilder {
     }
 }
 
+impl Default for AbilityInitializerBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[must_use]
-pub(crate) trait AbilityInitializerSetup: Sized {
+pub trait AbilityInitializerSetup: Sized {
     fn builder(&mut self) -> &mut AbilityInitializerBuilder;
 
     fn get_key(&self) -> EventOrigin;
@@ -167,6 +175,7 @@ pub(crate) trait AbilityInitializerSetup: Sized {
 
     fn description(&self) -> String;
 
+    #[must_use]
     fn add_initializer<F>(mut self, initializer: F) -> Self
     where
         F: Fn(&mut Game, &EventPlayer, i32) + 'static + Sync + Send,
@@ -183,6 +192,7 @@ pub(crate) trait AbilityInitializerSetup: Sized {
         self
     }
 
+    #[must_use]
     fn add_deinitializer<F>(mut self, deinitializer: F) -> Self
     where
         F: Fn(&mut Game, &EventPlayer) + 'static + Sync + Send,
@@ -194,6 +204,7 @@ pub(crate) trait AbilityInitializerSetup: Sized {
         self
     }
 
+    #[must_use]
     fn add_once_initializer<F>(mut self, initializer: F) -> Self
     where
         F: Fn(&mut Game, &EventPlayer) + 'static + Sync + Send,
@@ -206,6 +217,7 @@ pub(crate) trait AbilityInitializerSetup: Sized {
         self
     }
 
+    #[must_use]
     fn add_once_deinitializer<F>(mut self, deinitializer: F) -> Self
     where
         F: Fn(&mut Game, &EventPlayer) + 'static + Sync + Send,
@@ -218,6 +230,7 @@ pub(crate) trait AbilityInitializerSetup: Sized {
         self
     }
 
+    #[must_use]
     fn add_transient_event_listener<T, U, V, E, F>(
         self,
         event: E,
@@ -237,6 +250,7 @@ pub(crate) trait AbilityInitializerSetup: Sized {
         )
     }
 
+    #[must_use]
     fn add_combat_strength_listener(
         self,
         priority: i32,
@@ -258,6 +272,7 @@ pub(crate) trait AbilityInitializerSetup: Sized {
         )
     }
 
+    #[must_use]
     fn add_persistent_event_listener<E, V>(
         self,
         event: E,
@@ -340,6 +355,7 @@ pub(crate) trait AbilityInitializerSetup: Sized {
         )
     }
 
+    #[must_use]
     fn add_simple_persistent_event_listener<V, E, F>(
         self,
         event: E,
@@ -363,6 +379,7 @@ pub(crate) trait AbilityInitializerSetup: Sized {
         )
     }
 
+    #[must_use]
     fn add_payment_request_listener<E, V>(
         self,
         event: E,
@@ -410,6 +427,7 @@ pub(crate) trait AbilityInitializerSetup: Sized {
         )
     }
 
+    #[must_use]
     fn add_resource_request<E, V>(
         self,
         event: E,
@@ -427,6 +445,7 @@ pub(crate) trait AbilityInitializerSetup: Sized {
         self.add_resource_request_with_response(event, priority, request, move |_game, _s, _| {})
     }
 
+    #[must_use]
     fn add_resource_request_with_response<E, V>(
         self,
         event: E,
@@ -487,6 +506,7 @@ pub(crate) trait AbilityInitializerSetup: Sized {
         )
     }
 
+    #[must_use]
     fn add_bool_request<E, V>(
         self,
         event: E,
@@ -528,6 +548,7 @@ pub(crate) trait AbilityInitializerSetup: Sized {
         )
     }
 
+    #[must_use]
     fn add_advance_request<E, V>(
         self,
         event: E,
@@ -565,6 +586,7 @@ pub(crate) trait AbilityInitializerSetup: Sized {
         )
     }
 
+    #[must_use]
     fn add_position_request<E, V>(
         self,
         event: E,
@@ -602,6 +624,7 @@ pub(crate) trait AbilityInitializerSetup: Sized {
         )
     }
 
+    #[must_use]
     fn add_player_request<E, V>(
         self,
         event: E,
@@ -639,6 +662,7 @@ pub(crate) trait AbilityInitializerSetup: Sized {
         )
     }
 
+    #[must_use]
     fn add_unit_type_request<E, V>(
         self,
         event: E,
@@ -676,6 +700,7 @@ pub(crate) trait AbilityInitializerSetup: Sized {
         )
     }
 
+    #[must_use]
     fn add_hand_card_request<E, V>(
         self,
         event: E,
@@ -717,6 +742,7 @@ pub(crate) trait AbilityInitializerSetup: Sized {
         )
     }
 
+    #[must_use]
     fn add_units_request<E, V>(
         self,
         event: E,
@@ -766,6 +792,7 @@ pub(crate) trait AbilityInitializerSetup: Sized {
         )
     }
 
+    #[must_use]
     fn add_structures_request<E, V>(
         self,
         event: E,
@@ -803,6 +830,7 @@ pub(crate) trait AbilityInitializerSetup: Sized {
         )
     }
 
+    #[must_use]
     fn add_choice_reward_request_listener<E, C, R, V>(
         self,
         event: E,
@@ -870,6 +898,7 @@ pub(crate) trait AbilityInitializerSetup: Sized {
         )
     }
 
+    #[must_use]
     fn add_multi_choice_reward_request_listener<E, C, R, V>(
         self,
         event: E,
@@ -949,6 +978,7 @@ pub(crate) trait AbilityInitializerSetup: Sized {
         )
     }
 
+    #[must_use]
     fn add_custom_action(
         self,
         action: CustomActionType,
@@ -969,6 +999,7 @@ pub(crate) trait AbilityInitializerSetup: Sized {
         )
     }
 
+    #[must_use]
     fn add_custom_action_with_city_checker(
         self,
         action: CustomActionType,
@@ -990,6 +1021,7 @@ pub(crate) trait AbilityInitializerSetup: Sized {
         )
     }
 
+    #[must_use]
     fn add_action_modifier(
         self,
         modifier: PlayingActionModifier,
@@ -1003,6 +1035,7 @@ pub(crate) trait AbilityInitializerSetup: Sized {
         )
     }
 
+    #[must_use]
     fn add_special_action_execution(
         self,
         action: SpecialAction,
@@ -9,12 +9,14 @@ use crate::construct::on_construct;
 use crate::content::custom_actions::on_custom_action;
 use crate::content::persistent_events::{EventResponse, PersistentEventType};
 use crate::cultural_influence::on_cultural_influence;
+use crate::draft::draft_response;
 use crate::events::{EventOrigin, EventPlayer};
 use crate::explore::ask_explore_resolution;
 use crate::game::GameState;
 use crate::game::{Game, GameContext};
 use crate::game_setup::execute_choose_civ;
 use crate::incident::{on_choose_incident, on_trigger_incident};
+use crate::localization::Message;
 use crate::log::{
     ActionLogBalance, ActionLogEntry, ActionLogItem, add_action_log_item, add_log_action,
     current_turn_log_mut,
@@ -25,10 +27,12 @@ use crate::playing_actions::{PlayingAction, PlayingActionType};
 use crate::position::Position;
 use crate::recruit::on_recruit;
 use crate::resource::check_for_waste;
+use crate::scenario::check_scenario_victory;
 use crate::status_phase::status_phase_response;
-use crate::undo::{clean_patch, redo, to_serde_value, undo};
+use crate::trade::trade_response;
+use crate::undo::{clean_patch, redo, undo, undo_snapshot};
 use crate::unit::units_killed;
-use crate::victory_points::add_dynamic_victory_points;
+use crate::victory_points::{add_dynamic_victory_points, check_victory_points_target};
 use crate::wonder::{on_draw_wonder_card, on_play_wonder_card};
 use serde::{Deserialize, Serialize};
 
@@ -43,7 +47,7 @@ pub enum Action {
     ChooseCivilization(String),
 }
 
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Serialize, Clone, Debug, PartialEq)]
 pub enum ActionType {
     Playing(PlayingActionType),
     Movement,
@@ -88,15 +92,15 @@ pub fn try_execute_action(
     }
 
     let add_undo = !matches!(&action, Action::Undo);
-    let old = to_serde_value(&game);
+    let old = undo_snapshot(&game);
     let old_player = game.active_player();
     execute_without_undo(&mut game, action, player_index)?;
-    let new = to_serde_value(&game);
     let new_player = game.active_player();
-    let patch = json_patch::diff(&new, &old);
     if old_player != new_player {
         game.player_changed();
     } else if add_undo && game.can_undo() {
+        // only create the patch if it's needed
+        let patch = json_patch::diff(&undo_snapshot(&game), &old);
         let i = game.log_index - 1;
         current_turn_log_mut(&mut game).actions[i].undo = clean_patch(patch.0);
     }
@@ -129,10 +133,13 @@ pub fn execute_without_undo(
         // ignore missing response in replay
         game.add_log_item(ActionLogItem::new(
             player_index,
-            ActionLogEntry::message(format!(
-                "interrupted {} events in replay due to a missing response",
-                game.events.len()
-            )),
+            ActionLogEntry::Message(
+                Message::new(
+                    "log.replay.interrupted_events",
+                    "interrupted {events} events in replay due to a missing response",
+                )
+                .param("events", game.events.len()),
+            ),
             EventOrigin::Ability("replay".to_string()),
             vec![],
         ));
@@ -207,6 +214,8 @@ pub(crate) fn after_action(game: &mut Game, player_index: usize) {
     for p in game.human_player_ids() {
         add_dynamic_victory_points(game, p);
     }
+    check_victory_points_target(game);
+    check_scenario_victory(game);
 }
 
 pub(crate) fn on_city_activation_mood_decreased(
@@ -266,6 +275,8 @@ pub(crate) fn execute_custom_phase_action(
             on_end_combat(game, s);
         }
         StatusPhase(s) => status_phase_response(game, s),
+        Draft(d) => draft_response(game, d),
+        Trade(t) => trade_response(game, t),
         TurnStart => game.on_start_turn(),
         PayAction(a) => {
             a.on_pay_action(game, player, game.current_event().origin_override.clone())?;
@@ -341,6 +352,12 @@ fn execute_regular_action(
             },
             player_index,
         ),
+        GameState::Draft => {
+            if game.context == GameContext::Replay {
+                return Ok(());
+            }
+            Err("cards must be selected in the card draft".to_string())
+        }
         GameState::Finished => {
             if game.context == GameContext::Replay {
                 return Ok(());
@@ -62,7 +62,7 @@ impl ActionCard {
     }
 
     #[must_use]
-    pub(crate) fn builder<F>(
+    pub fn builder<F>(
         id: u8,
         name: &str,
         description: &str,
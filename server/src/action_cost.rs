@@ -80,7 +80,7 @@ impl ActionCost {
     }
 }
 
-pub(crate) struct ActionCostOncePerTurnBuilder {
+pub struct ActionCostOncePerTurnBuilder {
     action: SpecialAction,
 }
 
@@ -111,7 +111,7 @@ impl ActionCostOncePerTurnBuilder {
     }
 }
 
-pub(crate) struct ActionCostBuilder {
+pub struct ActionCostBuilder {
     once_per_turn: Option<SpecialAction>,
 }
 
@@ -132,7 +132,7 @@ impl ActionCostBuilder {
     }
 }
 
-pub(crate) struct ActionResourceCostBuilder {
+pub struct ActionResourceCostBuilder {
     once_per_turn: Option<SpecialAction>,
     free: bool,
 }
@@ -6,11 +6,13 @@ use crate::content::persistent_events::PersistentEventType;
 use crate::events::{EventOrigin, EventPlayer};
 use crate::game::Game;
 use crate::incident::trigger_incident;
+use crate::localization::Message;
 use crate::log::{ActionLogBalance, ActionLogEntry, ActionLogIncidentToken, add_action_log_item};
 use crate::payment::PaymentOptions;
 use crate::player::Player;
 use crate::player_events::OnAdvanceInfo;
 use crate::resource::{ResourceType, gain_resources, lose_resources};
+use crate::solo::incident_tokens;
 use crate::special_advance::{SpecialAdvance, SpecialAdvanceRequirement};
 use crate::{ability_initializer::AbilityInitializerSetup, resource_pile::ResourcePile};
 use Bonus::*;
@@ -396,6 +398,7 @@ pub(crate) fn gain_advance_without_payment(
     payment: ResourcePile,
     take_incident_token: bool,
 ) {
+    let take_incident_token = take_incident_token && !game.options.incidents.no_incident_tokens;
     do_advance(game, advance, player, take_incident_token);
     on_advance(
         game,
@@ -419,10 +422,11 @@ pub(crate) fn on_advance(game: &mut Game, player_index: usize, info: OnAdvanceIn
     };
 
     if info.take_incident_token {
+        let tokens = incident_tokens(game);
         let player = game.player_mut(player_index);
         player.incident_tokens -= 1;
         if player.incident_tokens == 0 {
-            player.incident_tokens = 3;
+            player.incident_tokens = tokens;
             trigger_incident(game, player_index, EventOrigin::Advance(info.advance));
         }
     }
@@ -465,9 +469,10 @@ pub(crate) fn remove_advance(game: &mut Game, advance: Advance, player: &EventPl
 }
 
 fn unlock_special_advance(game: &mut Game, special_advance: SpecialAdvance, player: &EventPlayer) {
-    player.log(
+    player.log_message(
         game,
-        &format!("unlocks {}", special_advance.info(game).name),
+        Message::new("log.unlock_special_advance", "unlocks {special_advance}")
+            .param("special_advance", &special_advance.info(game).name),
     );
     special_advance
         .info(game)
@@ -1,28 +1,38 @@
 use core::panic;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::time::Duration;
 use std::vec;
 
 use itertools::Itertools;
-use num_cpus;
-use tokio::runtime::Runtime;
+use serde::Serialize;
+use web_time::Instant;
 
 use crate::ai_actions::AiActions;
-use crate::cache::Cache;
-use crate::game::GameContext;
-use crate::game_data::GameData;
+use crate::ai_evaluation::{
+    STANDARD_WEIGHTS, action_type_prior, leading_player, relative_evaluation,
+};
+use crate::ai_opening_book::OpeningBook;
+use crate::ai_profile::AiProfile;
+use crate::ai_runtime::{AiRuntime, parallelism, run_all};
 use crate::movement::MovementAction;
 use crate::{
     action::{self, Action, ActionType},
-    ai_missions::ActiveMissions,
+    ai_missions::{ActiveMissions, Mission, MissionEvaluation, MissionTrace},
     game::{Game, GameState},
     playing_actions::{PlayingAction, PlayingActionType},
     utils::{self, Rng},
 };
 
-pub const ACTION_SCORE_WEIGHTING: f64 = 0.0;
+// exponent of the action priors in the rollouts - 0.0 would make all priors uniform
+pub const ACTION_SCORE_WEIGHTING: f64 = 1.0;
+// number of actions after which a rollout is stopped and the position is evaluated
+const ROLLOUT_DEPTH: usize = 200;
 const ADAPTIVE_DIFFICULTY_SCORE_THRESHOLD: f64 = 10.0;
 const ALLOCATE_UNITS_EVALUATION_TIME: f64 = 0.1;
 const PRUNING_ITERATIONS: usize = 3;
+// added to the scores after shifting the worst one to 0 - so every candidate keeps a chance
+const SCORE_SHIFT: f64 = 1.0;
 
 pub struct AI {
     rng: Rng,
@@ -31,6 +41,109 @@ pub struct AI {
     pub adaptive_difficulty: bool,
     active_missions: ActiveMissions,
     ai_actions: AiActions,
+    runtime: AiRuntime,
+    cancel: CancelHandle,
+}
+
+///
+/// Stops an AI that is thinking - it returns the best action found so far.
+#[derive(Clone, Default)]
+pub struct CancelHandle {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelHandle {
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    fn reset(&self) {
+        self.cancelled.store(false, Ordering::Relaxed);
+    }
+}
+
+///
+/// The rollout scores of a candidate action.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ScoreStatistics {
+    pub rollouts: usize,
+    sum: f64,
+    sum_of_squares: f64,
+}
+
+impl ScoreStatistics {
+    pub(crate) fn add(&mut self, score: f64) {
+        self.rollouts += 1;
+        self.sum += score;
+        self.sum_of_squares += score * score;
+    }
+
+    pub(crate) fn merge(&mut self, other: &ScoreStatistics) {
+        self.rollouts += other.rollouts;
+        self.sum += other.sum;
+        self.sum_of_squares += other.sum_of_squares;
+    }
+
+    #[must_use]
+    pub fn mean(&self) -> f64 {
+        if self.rollouts == 0 {
+            return 0.0;
+        }
+        self.sum / self.rollouts as f64
+    }
+
+    #[must_use]
+    pub fn variance(&self) -> f64 {
+        if self.rollouts == 0 {
+            return 0.0;
+        }
+        let mean = self.mean();
+        (self.sum_of_squares / self.rollouts as f64 - mean * mean).max(0.0)
+    }
+}
+
+///
+/// An action that the AI considered.
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct CandidateTrace {
+    pub action: Action,
+    pub group: ActionType,
+    pub rollouts: usize,
+    pub mean_score: f64,
+    pub score_variance: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub eliminated_in_round: Option<usize>,
+}
+
+impl CandidateTrace {
+    fn new(group: &ActionType, action: &Action) -> Self {
+        Self {
+            action: action.clone(),
+            group: group.clone(),
+            rollouts: 0,
+            mean_score: 0.0,
+            score_variance: 0.0,
+            eliminated_in_round: None,
+        }
+    }
+}
+
+///
+/// Explains why the AI chose an action - for bug reports and tuning.
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct DecisionTrace {
+    pub player_index: usize,
+    pub profile: AiProfile,
+    pub candidates: Vec<CandidateTrace>,
+    pub chosen: Action,
+    // the action was taken from the opening book - without evaluating the candidates
+    pub opening_book: bool,
+    pub missions: Vec<MissionTrace>,
 }
 
 impl AI {
@@ -46,6 +159,7 @@ impl AI {
         adaptive_difficulty: bool,
         starting_game: &Game,
         player_index: usize,
+        profile: AiProfile,
     ) -> Self {
         assert!((0.0..=1.0).contains(&difficulty));
         let rng = Rng::new();
@@ -53,22 +167,30 @@ impl AI {
         missions_rng.seed = missions_rng.seed.wrapping_add(1);
         missions_rng.next_seed();
         let starting_units = starting_game.players[player_index].units.len();
+        let runtime = AiRuntime::new();
+        let cancel = CancelHandle::default();
+        let active_missions = ActiveMissions::new(
+            starting_game,
+            player_index,
+            &mut missions_rng,
+            Some(MissionEvaluation {
+                time: thinking_time.mul_f64(ALLOCATE_UNITS_EVALUATION_TIME) * starting_units as u32,
+                difficulty,
+                runtime: &runtime,
+                cancel: &cancel,
+            }),
+            profile,
+        );
 
         AI {
             rng,
             difficulty,
             thinking_time,
             adaptive_difficulty,
-            active_missions: ActiveMissions::new(
-                starting_game,
-                player_index,
-                &mut missions_rng,
-                Some((
-                    thinking_time.mul_f64(ALLOCATE_UNITS_EVALUATION_TIME) * starting_units as u32,
-                    difficulty,
-                )),
-            ),
+            active_missions,
             ai_actions: AiActions::new(),
+            runtime,
+            cancel,
         }
     }
 
@@ -83,76 +205,72 @@ impl AI {
     ///
     /// Panics if it's not the AI's turn
     pub fn next_action(&mut self, game: &Game) -> Action {
+        self.next_action_with_trace(game).0
+    }
+
+    /// Returns the next action for the AI to take and an explanation of the decision.
+    ///
+    /// # Panics
+    ///
+    /// Panics if it's not the AI's turn
+    pub fn next_action_with_trace(&mut self, game: &Game) -> (Action, DecisionTrace) {
+        let result = self.decide(game);
+        // a cancellation only applies to one decision
+        self.cancel.reset();
+        result
+    }
+
+    ///
+    /// Returns a handle to stop the thinking early - also from another thread.
+    #[must_use]
+    pub fn cancel_handle(&self) -> CancelHandle {
+        self.cancel.clone()
+    }
+
+    fn decide(&mut self, game: &Game) -> (Action, DecisionTrace) {
         assert_eq!(game.active_player(), self.active_missions.player_index);
-        let start_time = std::time::Instant::now();
+        let start_time = Instant::now();
 
         if can_move(game, self.active_missions.player_index) {
             let idle_units = self.active_missions.idle_units.len();
             self.active_missions.update(
                 game,
                 &mut self.rng,
-                Some((
-                    self.thinking_time.mul_f64(ALLOCATE_UNITS_EVALUATION_TIME) * idle_units as u32,
-                    self.difficulty,
-                )),
+                Some(MissionEvaluation {
+                    time: self.thinking_time.mul_f64(ALLOCATE_UNITS_EVALUATION_TIME)
+                        * idle_units as u32,
+                    difficulty: self.difficulty,
+                    runtime: &self.runtime,
+                    cancel: &self.cancel,
+                }),
             );
         }
 
-        let mut actions = get_actions(&mut self.ai_actions, game, &self.active_missions);
-        if actions.is_empty() {
-            return forced_action(game);
-        }
-        if actions.len() == 1 {
-            return actions
+        let actions = get_actions(&mut self.ai_actions, game, &self.active_missions);
+        let mut candidates = actions
+            .iter()
+            .map(|(group, action)| CandidateTrace::new(group, action))
+            .collect_vec();
+        if actions.len() <= 1 {
+            let action = actions
                 .into_iter()
                 .next()
-                .expect("there should be 1 available action")
-                .1;
+                .map_or_else(|| forced_action(game), |(_, a)| a);
+            let trace = self.trace(candidates, action.clone(), false);
+            return (action, trace);
         }
-
-        let runtime = Runtime::new().expect("failed to create runtime");
-
-        let players_active_missions = self
-            .active_missions
-            .get_players_active_missions(game, &mut self.rng);
-        let difficulty_factor = difficulty_factor(self.difficulty);
-
-        let mut evaluations = vec![1.0; actions.len()];
-        for i in 0..PRUNING_ITERATIONS {
-            if start_time.elapsed() >= self.thinking_time {
-                break;
-            }
-            let time_remaining = self.thinking_time - start_time.elapsed();
-            let thinking_time_per_action =
-                time_remaining / actions.len() as u32 / (PRUNING_ITERATIONS - i) as u32;
-            for (j, new_evaluation) in actions
-                .iter()
-                .map(|(action_group, action)| {
-                    runtime
-                        .block_on(evaluate_action(
-                            game,
-                            action,
-                            action_group,
-                            &mut self.rng,
-                            thinking_time_per_action,
-                            &players_active_missions,
-                        ))
-                        .powf(difficulty_factor)
-                })
-                .enumerate()
-            {
-                evaluations[j] = utils::new_average(evaluations[j], new_evaluation, i);
+        if let Some(book) = &self.active_missions.opening_book {
+            let all = actions.iter().map(|(_, a)| a.clone()).collect_vec();
+            if let Some(action) = book.shortcut(game, &all) {
+                let trace = self.trace(candidates, action.clone(), true);
+                return (action.clone(), trace);
             }
-            let median = utils::median(&evaluations);
-            let (new_actions, new_evaluations) = actions
-                .into_iter()
-                .zip(evaluations.into_iter())
-                .filter(|&(_, evaluation)| evaluation >= median)
-                .unzip::<(ActionType, Action), f64, Vec<(ActionType, Action)>, Vec<f64>>();
-            actions = new_actions;
-            evaluations = new_evaluations;
         }
 
+        let difficulty_factor = difficulty_factor(self.difficulty);
+        let (actions, evaluations) =
+            self.evaluate_candidates(game, start_time, actions, &mut candidates);
+
         let chosen_action = if self.difficulty >= 1.0 - f64::EPSILON {
             evaluations
                 .iter()
@@ -161,15 +279,106 @@ impl AI {
                 .expect("there are no possible actions")
                 .0
         } else {
-            utils::weighted_random_selection(&evaluations, &mut self.rng)
+            utils::weighted_random_selection(
+                &selection_weights(&evaluations, difficulty_factor),
+                &mut self.rng,
+            )
         };
 
-        if self.difficulty > 0.0 + f64::EPSILON {
-            let final_evaluation = evaluations
+        self.adapt_difficulty(&evaluations, chosen_action);
+
+        let action = actions
+            .into_iter()
+            .nth(chosen_action)
+            .expect("there are no possible actions")
+            .1;
+        let trace = self.trace(candidates, action.clone(), false);
+        (action, trace)
+    }
+
+    // evaluates the candidates with rollouts - and prunes the worst half in each round
+    fn evaluate_candidates(
+        &mut self,
+        game: &Game,
+        start_time: Instant,
+        mut actions: Vec<(ActionType, Action)>,
+        candidates: &mut [CandidateTrace],
+    ) -> (Vec<(ActionType, Action)>, Vec<f64>) {
+        let player_index = self.active_missions.player_index;
+        let players_active_missions = Arc::new(
+            self.active_missions
+                .get_players_active_missions(game, &mut self.rng),
+        );
+        let mut games = actions
+            .iter()
+            .map(|(_, action)| {
+                Arc::new(action::execute_action(
+                    game.ai_clone(),
+                    action.clone(),
+                    player_index,
+                ))
+            })
+            .collect_vec();
+        // the average relative score - negative if the AI is behind
+        let mut evaluations = vec![0.0; actions.len()];
+        // index of the remaining actions in the candidates
+        let mut candidate_indices = (0..actions.len()).collect_vec();
+        let mut statistics = vec![ScoreStatistics::default(); actions.len()];
+        for i in 0..PRUNING_ITERATIONS {
+            if start_time.elapsed() >= self.thinking_time || self.cancel.is_cancelled() {
+                break;
+            }
+            // all remaining candidates are evaluated at the same time
+            let deadline = start_time
+                + self
+                    .thinking_time
+                    .mul_f64((i + 1) as f64 / PRUNING_ITERATIONS as f64);
+            let round_games = Arc::new(games.clone());
+            let round_missions = players_active_missions.clone();
+            let round_statistics = self.runtime.rollouts(
+                games.len(),
+                deadline,
+                &self.cancel,
+                &mut self.rng,
+                move |candidate, rng| {
+                    monte_carlo_score(
+                        rng,
+                        player_index,
+                        round_games[candidate].ai_clone(),
+                        round_missions.to_vec(),
+                    )
+                },
+            );
+            for (j, new_statistics) in round_statistics.iter().enumerate() {
+                statistics[candidate_indices[j]].merge(new_statistics);
+                evaluations[j] = utils::new_average(evaluations[j], new_statistics.mean(), i);
+            }
+            let median = utils::median(&evaluations);
+            for (j, evaluation) in evaluations.iter().enumerate() {
+                if *evaluation < median {
+                    candidates[candidate_indices[j]].eliminated_in_round = Some(i + 1);
+                }
+            }
+            let (new_actions, new_evaluations): (Vec<_>, Vec<_>) = actions
                 .into_iter()
-                .nth(chosen_action)
-                .expect("there are no possible actions")
-                .powf(1.0 / difficulty_factor);
+                .zip(games)
+                .zip(evaluations.into_iter().zip(candidate_indices))
+                .filter(|&(_, (evaluation, _))| evaluation >= median)
+                .unzip();
+            (actions, games) = new_actions.into_iter().unzip();
+            (evaluations, candidate_indices) = new_evaluations.into_iter().unzip();
+        }
+        for (candidate, statistics) in candidates.iter_mut().zip(statistics) {
+            candidate.rollouts = statistics.rollouts;
+            candidate.mean_score = statistics.mean();
+            candidate.score_variance = statistics.variance();
+        }
+        (actions, evaluations)
+    }
+
+    fn adapt_difficulty(&mut self, evaluations: &[f64], chosen_action: usize) {
+        if self.difficulty > 0.0 + f64::EPSILON {
+            let final_evaluation = evaluations[chosen_action];
             println!("average final relative score: {final_evaluation}");
             if self.adaptive_difficulty {
                 if final_evaluation > ADAPTIVE_DIFFICULTY_SCORE_THRESHOLD {
@@ -183,12 +392,38 @@ impl AI {
         } else {
             println!("can't get action score or adapt difficulty because the AI's difficulty is 0");
         }
+    }
 
-        actions
-            .into_iter()
-            .nth(chosen_action)
-            .expect("there are no possible actions")
-            .1
+    fn trace(
+        &self,
+        candidates: Vec<CandidateTrace>,
+        chosen: Action,
+        opening_book: bool,
+    ) -> DecisionTrace {
+        DecisionTrace {
+            player_index: self.active_missions.player_index,
+            profile: self.active_missions.profile,
+            candidates,
+            chosen,
+            opening_book,
+            missions: self
+                .active_missions
+                .missions
+                .iter()
+                .map(Mission::trace)
+                .collect(),
+        }
+    }
+
+    #[must_use]
+    pub fn profile(&self) -> AiProfile {
+        self.active_missions.profile
+    }
+
+    ///
+    /// Uses the statistics of archived games for the early moves.
+    pub fn set_opening_book(&mut self, book: Arc<OpeningBook>) {
+        self.active_missions.opening_book = Some(book);
     }
 
     fn increase_difficulty(&mut self) {
@@ -212,34 +447,7 @@ impl AI {
     }
 }
 
-async fn evaluate_action(
-    game: &Game,
-    action: &Action,
-    action_group: &ActionType,
-    rng: &mut Rng,
-    thinking_time: Duration,
-    players_active_missions: &[ActiveMissions],
-) -> f64 {
-    let player_index = game.active_player();
-    let action_score = get_action_score(game, action, &players_active_missions[player_index]);
-    let action_group_score =
-        get_action_group_score(game, action_group, &players_active_missions[player_index]);
-    let game = action::execute_action(game.clone(), action.clone(), player_index);
-    let score = get_average_score(
-        game,
-        player_index,
-        rng,
-        thinking_time,
-        players_active_missions,
-    )
-    .await;
-    println!(
-        " -> Monte Carlo score: {score}, action score: {action_score}, action group score: {action_group_score}"
-    );
-    score * action_score * action_group_score
-}
-
-/// Simulates the current game multiple times to the end and returns the average score for the given player relative the best opponent.
+/// Simulates the current game multiple times and returns the average score for the given player relative the best opponent.
 ///
 /// # Panics
 ///
@@ -251,71 +459,69 @@ pub async fn get_average_score(
     evaluation_time: Duration,
     players_active_missions: &[ActiveMissions],
 ) -> f64 {
-    let mut iterations = 0;
-    let start_time = std::time::Instant::now();
-    let mut score = 0.0;
-    let num_cores = num_cpus::get();
+    let start_time = Instant::now();
+    let mut statistics = ScoreStatistics::default();
     loop {
-        let mut handles = Vec::new();
-        for _ in 0..num_cores {
+        let mut tasks = Vec::new();
+        for _ in 0..parallelism() {
             rng.seed = rng.seed.wrapping_add(1);
             rng.next_seed();
             let thread_rng = rng.clone();
-            let new_game = game.cloned_data();
+            let new_game = game.ai_clone();
             let new_active_missions = players_active_missions.to_vec();
-            let cache = game.cache.clone();
-            let handle = tokio::spawn(async move {
-                monte_carlo_score(
-                    thread_rng,
-                    player_index,
-                    new_game,
-                    new_active_missions,
-                    cache,
-                )
+            tasks.push(move || {
+                monte_carlo_score(thread_rng, player_index, new_game, new_active_missions)
             });
-            handles.push(handle);
         }
-        for handle in handles {
-            score += handle.await.expect("multi-threading error");
+        for score in run_all(tasks).await {
+            statistics.add(score);
         }
-        iterations += num_cores;
         if start_time.elapsed() >= evaluation_time {
             break;
         }
     }
-    println!("Monte Carlo iterations: {iterations}");
-    score / iterations as f64
+    println!("Monte Carlo iterations: {}", statistics.rollouts);
+    statistics.mean()
 }
 
-fn monte_carlo_score(
+pub(crate) fn monte_carlo_score(
     mut rng: Rng,
     player_index: usize,
-    game_data: GameData,
+    game: Game,
     players_active_missions: Vec<ActiveMissions>,
-    cache: Cache,
 ) -> f64 {
     let mut ai = AiActions::new();
-    let new_game = monte_carlo_run(
-        &mut ai,
-        game_from_data(game_data, cache),
-        &mut rng,
-        players_active_missions,
-    );
-    let ai_score = new_game.players[player_index].victory_points(&new_game) as f64;
-    let mut max_opponent_score = 0.0;
-    for (i, player) in new_game.players.iter().enumerate() {
-        if i != player_index {
-            let opponent_score = player.victory_points(&new_game) as f64;
-            if opponent_score > max_opponent_score {
-                max_opponent_score = opponent_score;
-            }
-        }
-    }
-    ai_score - max_opponent_score
+    let weights = players_active_missions[player_index].profile.weights();
+    let new_game = monte_carlo_run(&mut ai, game, &mut rng, players_active_missions);
+    relative_evaluation(&new_game, player_index, &weights)
 }
 
-fn game_from_data(game_data: GameData, cache: Cache) -> Game {
-    Game::from_data(game_data, cache, GameContext::AI)
+///
+/// Simulates random games from the given position for the given time (single-threaded)
+/// and returns the number of rollouts per second.
+///
+/// The game for each rollout is created by `clone` - to compare different ways of copying a game.
+#[must_use]
+pub fn benchmark_rollouts(game: &Game, duration: Duration, clone: impl Fn(&Game) -> Game) -> f64 {
+    let mut rng = Rng::from_seed(0);
+    let players_active_missions = game
+        .players
+        .iter()
+        .map(|player| ActiveMissions::new(game, player.index, &mut rng, None, AiProfile::default()))
+        .collect::<Vec<ActiveMissions>>();
+    let mut ai = AiActions::new();
+    let start_time = Instant::now();
+    let mut rollouts = 0;
+    while start_time.elapsed() < duration {
+        monte_carlo_run(
+            &mut ai,
+            clone(game),
+            &mut rng,
+            players_active_missions.clone(),
+        );
+        rollouts += 1;
+    }
+    f64::from(rollouts) / start_time.elapsed().as_secs_f64()
 }
 
 fn monte_carlo_run(
@@ -324,9 +530,9 @@ fn monte_carlo_run(
     rng: &mut Rng,
     mut players_active_missions: Vec<ActiveMissions>,
 ) -> Game {
-    loop {
+    for _ in 0..ROLLOUT_DEPTH {
         if matches!(game.state, GameState::Finished) {
-            return game;
+            break;
         }
         let current_player = game.active_player();
         if can_move(&game, current_player) {
@@ -336,6 +542,7 @@ fn monte_carlo_run(
             choose_monte_carlo_action(ai, &game, rng, &players_active_missions[current_player]);
         game = action::execute_action(game, action, current_player);
     }
+    game
 }
 
 fn choose_monte_carlo_action(
@@ -432,6 +639,17 @@ fn can_move(game: &Game, player_index: usize) -> bool {
         .is_ok()
 }
 
+///
+/// The relative scores can be negative, so they are shifted to a positive range
+/// before the difficulty is applied.
+fn selection_weights(evaluations: &[f64], difficulty_factor: f64) -> Vec<f64> {
+    let min = evaluations.iter().copied().fold(f64::INFINITY, f64::min);
+    evaluations
+        .iter()
+        .map(|e| (e - min + SCORE_SHIFT).powf(difficulty_factor))
+        .collect()
+}
+
 #[must_use]
 pub fn difficulty_factor(difficulty: f64) -> f64 {
     if difficulty >= 1.0 - f64::EPSILON {
@@ -443,7 +661,19 @@ pub fn difficulty_factor(difficulty: f64) -> f64 {
 
 fn get_action_score(game: &Game, action: &Action, active_missions: &ActiveMissions) -> f64 {
     match action {
-        Action::Playing(_action) => 1.0, //todo
+        Action::Playing(action) => {
+            let profile_prior = if let PlayingAction::Advance(a) = action {
+                active_missions.profile.advance_prior(game, a.advance)
+            } else {
+                // the action type is already rated by the action group
+                1.0
+            };
+            profile_prior
+                * active_missions
+                    .opening_book
+                    .as_ref()
+                    .map_or(1.0, |book| book.action_prior(game, action))
+        }
         Action::Movement(action) => {
             let mission = active_missions
                 .missions
@@ -469,7 +699,17 @@ fn get_action_group_score(
     active_missions: &ActiveMissions,
 ) -> f64 {
     match action_group {
-        ActionType::Playing(_action) => 1.0, //todo
+        ActionType::Playing(action) => {
+            action_type_prior(
+                game,
+                active_missions.player_index,
+                action,
+                &active_missions.profile.weights(),
+            ) * active_missions
+                .opening_book
+                .as_ref()
+                .map_or(1.0, |book| book.action_type_prior(game, action))
+        }
         ActionType::Movement => {
             let skip = if active_missions.missions.len() <= 3 {
                 0
@@ -496,40 +736,36 @@ fn get_action_group_score(
 
 /// Returns the win probability of each player in the game in the order listed in the game.
 ///
+/// Rollouts that don't reach the end of the game are won by the player with the best evaluation.
+///
 /// # Panics
 ///
 /// Panics if the game is in an invalid state.
 #[must_use]
 pub async fn evaluate_position(game: &Game, evaluation_time: Duration) -> Vec<f64> {
     let mut rng = Rng::new();
-    let start_time = std::time::Instant::now();
+    let start_time = Instant::now();
     let players_active_missions = game
         .players
         .iter()
-        .map(|player| ActiveMissions::new(game, player.index, &mut rng, None))
+        .map(|player| ActiveMissions::new(game, player.index, &mut rng, None, AiProfile::default()))
         .collect::<Vec<ActiveMissions>>();
     let mut wins = vec![0; game.players.len()];
-    let num_cores = num_cpus::get();
     let mut iterations = 0;
     loop {
-        let mut handles = Vec::new();
-        for _ in 0..num_cores {
+        let mut tasks = Vec::new();
+        for _ in 0..parallelism() {
             rng.seed = rng.seed.wrapping_add(1);
             rng.next_seed();
             let thread_rng = rng.clone();
-            let new_game = game.cloned_data();
+            let new_game = game.ai_clone();
             let new_active_missions = players_active_missions.clone();
-            let cache = game.cache.clone();
-            let handle = tokio::spawn(async move {
-                simulate_game(new_game, thread_rng, new_active_missions, cache)
-            });
-            handles.push(handle);
+            tasks.push(move || simulate_game(new_game, thread_rng, new_active_missions));
         }
-        for handle in handles {
-            let winner = handle.await.expect("multi-threading error");
+        for winner in run_all(tasks).await {
             wins[winner] += 1;
+            iterations += 1;
         }
-        iterations += num_cores;
         if start_time.elapsed() >= evaluation_time {
             return wins
                 .iter()
@@ -539,31 +775,14 @@ pub async fn evaluate_position(game: &Game, evaluation_time: Duration) -> Vec<f6
     }
 }
 
-fn simulate_game(
-    game: GameData,
-    mut rng: Rng,
-    players_active_missions: Vec<ActiveMissions>,
-    cache: Cache,
-) -> usize {
+fn simulate_game(game: Game, mut rng: Rng, players_active_missions: Vec<ActiveMissions>) -> usize {
     let new_game = monte_carlo_run(
         &mut AiActions::new(),
-        game_from_data(game, cache),
+        game,
         &mut rng,
         players_active_missions,
     );
-    let max_score = new_game
-        .players
-        .iter()
-        .map(|player| player.victory_points(&new_game) as f64)
-        .max_by(|a, b| a.partial_cmp(b).expect("floating point error"))
-        .expect("there are no players");
-    for (i, player) in new_game.players.iter().enumerate() {
-        let score = player.victory_points(&new_game) as f64;
-        if (score - max_score).abs() < f64::EPSILON {
-            return i;
-        }
-    }
-    unreachable!("there should be a winner");
+    leading_player(&new_game, &STANDARD_WEIGHTS)
 }
 
 /// Returns a score between 0 and 1 for the given action. with 0 being the worst possible action and 1 being the best.
@@ -582,17 +801,17 @@ pub fn rate_action(
     let players_active_missions = game
         .players
         .iter()
-        .map(|player| ActiveMissions::new(game, player.index, &mut rng, None))
+        .map(|player| ActiveMissions::new(game, player.index, &mut rng, None, AiProfile::default()))
         .collect::<Vec<ActiveMissions>>();
-    let runtime = Runtime::new().expect("failed to create runtime");
+    let runtime = AiRuntime::new();
     let initial_score = runtime.block_on(get_average_score(
-        game.clone(),
+        game.ai_clone(),
         player_index,
         &mut rng,
         evaluation_time / 2,
         &players_active_missions,
     ));
-    let new_game = action::execute_action(game.clone(), action.clone(), player_index);
+    let new_game = action::execute_action(game.ai_clone(), action.clone(), player_index);
     let new_score = runtime.block_on(get_average_score(
         new_game,
         player_index,
@@ -602,3 +821,23 @@ pub fn rate_action(
     ));
     new_score - initial_score
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{SCORE_SHIFT, difficulty_factor, selection_weights};
+
+    #[test]
+    fn test_selection_weights_for_negative_scores() {
+        // the AI is behind in all rollouts
+        let weights = selection_weights(&[-12.0, -4.0, -30.0], difficulty_factor(0.75));
+        assert!(weights.iter().all(|w| w.is_finite() && *w > 0.0));
+        assert!(weights[1] > weights[0] && weights[0] > weights[2]);
+        assert!((weights[2] - SCORE_SHIFT).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_selection_weights_for_mixed_scores() {
+        let weights = selection_weights(&[-5.0, 5.0], difficulty_factor(0.5));
+        assert_eq!(weights, vec![SCORE_SHIFT, 10.0 + SCORE_SHIFT]);
+    }
+}
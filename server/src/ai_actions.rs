@@ -286,7 +286,7 @@ pub fn try_payment(
         // here we don't need to clone the payment options
         return e
             .entry(max)
-            .or_insert_with_key(|available| o.first_valid_payment(available))
+            .or_insert_with_key(|available| cheapest_payment(o, available))
             .clone();
     }
 
@@ -296,10 +296,20 @@ pub fn try_payment(
         .entry(o.clone())
         .or_default()
         .entry(max)
-        .or_insert_with_key(|available| o.first_valid_payment(available))
+        .or_insert_with_key(|available| cheapest_payment(o, available))
         .clone()
 }
 
+// gold and tokens are more flexible than the other resources, so they are kept if possible
+fn cheapest_payment(o: &PaymentOptions, available: &ResourcePile) -> Option<ResourcePile> {
+    o.minimal_payments(available).into_iter().min_by_key(|p| {
+        [p.amount(), p.gold, p.mood_tokens, p.culture_tokens]
+            .into_iter()
+            .map(u32::from)
+            .sum::<u32>()
+    })
+}
+
 fn advances(ai_actions: &mut AiActions, p: &Player, game: &Game) -> Vec<Action> {
     game.cache
         .get_advances()
@@ -0,0 +1,261 @@
+use crate::city::MoodState;
+use crate::collect::{base_collect_event_origin, possible_resource_collections};
+use crate::game::Game;
+use crate::objective_card::status_phase_completable;
+use crate::player::{CostTrigger, Player};
+use crate::playing_actions::PlayingActionType;
+
+///
+/// Weights of the heuristic evaluation of a position.
+///
+/// All values are measured in victory points, e.g. a city size of 0.25 means that 4 city pieces
+/// are worth as much as 1 victory point.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EvaluationWeights {
+    pub victory_points: f64,
+    pub advance: f64,
+    pub objective_card: f64,
+    // an objective card that could be completed in the next status phase
+    pub objective_progress: f64,
+    pub city_size: f64,
+    pub happy_city: f64,
+    pub angry_city: f64,
+    pub army_unit: f64,
+    pub ship: f64,
+    pub settler: f64,
+    pub resource: f64,
+    // a resource that the cities can collect in one collect action
+    pub income: f64,
+    pub action_card: f64,
+    pub wonder_card: f64,
+    // a wonder card with the required advance or with the resources to pay for it
+    pub wonder_progress: f64,
+    pub wonder: f64,
+    pub influence: f64,
+}
+
+pub const STANDARD_WEIGHTS: EvaluationWeights = EvaluationWeights {
+    victory_points: 1.0,
+    advance: 0.15,
+    objective_card: 0.5,
+    objective_progress: 0.5,
+    city_size: 0.25,
+    happy_city: 0.2,
+    angry_city: -0.3,
+    army_unit: 0.2,
+    ship: 0.1,
+    settler: 0.3,
+    resource: 0.05,
+    income: 0.1,
+    action_card: 0.2,
+    wonder_card: 0.4,
+    wonder_progress: 0.3,
+    wonder: 0.0,
+    influence: 0.0,
+};
+
+impl Default for EvaluationWeights {
+    fn default() -> Self {
+        STANDARD_WEIGHTS
+    }
+}
+
+///
+/// Returns the heuristic value of the position for the given player.
+///
+/// Unlike [`Player::victory_points`], this also rates progress that will only pay off later,
+/// so it can be used for positions that are not finished.
+#[must_use]
+pub fn evaluate(game: &Game, player_index: usize, weights: &EvaluationWeights) -> f64 {
+    evaluation_terms(game, player_index, weights)
+        .iter()
+        .map(|(_, value)| value)
+        .sum()
+}
+
+///
+/// Returns the parts of [`evaluate`] with a short description each.
+#[must_use]
+pub fn evaluation_terms(
+    game: &Game,
+    player_index: usize,
+    weights: &EvaluationWeights,
+) -> [(&'static str, f64); 13] {
+    let player = game.player(player_index);
+    [
+        (
+            "victory points",
+            f64::from(player.victory_points(game)) * weights.victory_points,
+        ),
+        ("advances", player.advances.len() as f64 * weights.advance),
+        (
+            "objective cards",
+            player.objective_cards.len() as f64 * weights.objective_card,
+        ),
+        (
+            "objective progress",
+            objective_progress(game, player) as f64 * weights.objective_progress,
+        ),
+        ("cities", cities_value(player, weights)),
+        ("units", units_value(player, weights)),
+        (
+            "resources",
+            f64::from(player.resources.amount()) * weights.resource,
+        ),
+        ("income", f64::from(income(game, player)) * weights.income),
+        (
+            "action cards",
+            player.action_cards.len() as f64 * weights.action_card,
+        ),
+        (
+            "wonder cards",
+            player.wonder_cards.len() as f64 * weights.wonder_card,
+        ),
+        (
+            "wonder progress",
+            wonder_progress(game, player) as f64 * weights.wonder_progress,
+        ),
+        (
+            "wonders",
+            player.wonders_owned.len() as f64 * weights.wonder,
+        ),
+        (
+            "culture tokens",
+            f64::from(player.resources.culture_tokens) * weights.influence,
+        ),
+    ]
+}
+
+///
+/// Returns the value of the position for the given player relative to the best opponent.
+///
+/// Barbarians and pirates are not considered opponents.
+#[must_use]
+pub fn relative_evaluation(game: &Game, player_index: usize, weights: &EvaluationWeights) -> f64 {
+    let best_opponent = game
+        .human_player_ids()
+        .into_iter()
+        .filter(|&p| p != player_index)
+        .map(|p| evaluate(game, p, weights))
+        .fold(0.0, f64::max);
+    evaluate(game, player_index, weights) - best_opponent
+}
+
+///
+/// Returns the player with the best position - the winner if the game is finished.
+///
+/// # Panics
+///
+/// Panics if there are no human players.
+#[must_use]
+pub fn leading_player(game: &Game, weights: &EvaluationWeights) -> usize {
+    game.human_player_ids()
+        .into_iter()
+        .map(|p| (p, evaluate(game, p, weights)))
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .expect("there are no players")
+        .0
+}
+
+///
+/// Returns the prior probability weight of an action type for the random playouts.
+///
+/// The weight is always positive, so every action can still be chosen.
+#[must_use]
+pub fn action_type_prior(
+    game: &Game,
+    player_index: usize,
+    action_type: &PlayingActionType,
+    weights: &EvaluationWeights,
+) -> f64 {
+    let player = game.player(player_index);
+    let value = match action_type {
+        PlayingActionType::Advance => weights.advance,
+        PlayingActionType::FoundCity => weights.city_size + weights.victory_points,
+        PlayingActionType::Construct => weights.city_size * 2.0,
+        PlayingActionType::Collect => weights.resource * player.cities.len() as f64,
+        PlayingActionType::Recruit => weights.army_unit,
+        PlayingActionType::IncreaseHappiness => weights.happy_city,
+        PlayingActionType::InfluenceCultureAttempt => {
+            weights.victory_points / 2.0 + weights.influence
+        }
+        PlayingActionType::ActionCard(_) => weights.action_card,
+        PlayingActionType::WonderCard(_) => weights.victory_points * 4.0 + weights.wonder,
+        PlayingActionType::MoveUnits | PlayingActionType::Special(_) => weights.army_unit / 2.0,
+        PlayingActionType::EndTurn => 0.0,
+    };
+    // keep a base weight, so that the playouts stay diverse
+    1.0 + value.max(0.0)
+}
+
+fn cities_value(player: &Player, weights: &EvaluationWeights) -> f64 {
+    player
+        .cities
+        .iter()
+        .map(|city| {
+            city.size() as f64 * weights.city_size
+                + match city.mood_state {
+                    MoodState::Happy => weights.happy_city,
+                    MoodState::Neutral => 0.0,
+                    MoodState::Angry => weights.angry_city,
+                }
+        })
+        .sum()
+}
+
+fn units_value(player: &Player, weights: &EvaluationWeights) -> f64 {
+    player
+        .units
+        .iter()
+        .map(|unit| {
+            if unit.is_army_unit() {
+                weights.army_unit
+            } else if unit.is_ship() {
+                weights.ship
+            } else if unit.is_settler() {
+                weights.settler
+            } else {
+                0.0
+            }
+        })
+        .sum()
+}
+
+fn objective_progress(game: &Game, player: &Player) -> usize {
+    player
+        .objective_cards
+        .iter()
+        .filter(|id| !status_phase_completable(game, player, **id).is_empty())
+        .count()
+}
+
+// the resources of one collect action in each city that is not angry
+fn income(game: &Game, player: &Player) -> u8 {
+    player
+        .cities
+        .iter()
+        .filter(|city| city.mood_state != MoodState::Angry)
+        .map(|city| {
+            let info = possible_resource_collections(
+                game,
+                city.position,
+                player.index,
+                &base_collect_event_origin(),
+                CostTrigger::NoModifiers,
+            );
+            (info.choices.len() as u8).min(city.size() as u8)
+        })
+        .sum()
+}
+
+fn wonder_progress(game: &Game, player: &Player) -> usize {
+    player
+        .wonder_cards
+        .iter()
+        .map(|wonder| {
+            let info = wonder.info(game);
+            usize::from(player.has_advance(info.required_advance))
+                + usize::from(player.can_afford(&info.cost))
+        })
+        .sum()
+}
@@ -1,10 +1,16 @@
-use std::{mem, time::Duration, vec};
+use std::{mem, sync::Arc, time::Duration, vec};
 
-use tokio::runtime::Runtime;
+use itertools::Itertools;
+use serde::Serialize;
+use web_time::Instant;
 
 use crate::advance::Advance;
+use crate::ai_opening_book::OpeningBook;
+use crate::ai_profile::AiProfile;
+use crate::ai_runtime::AiRuntime;
+use crate::city::City;
 use crate::{
-    ai::{self, ACTION_SCORE_WEIGHTING},
+    ai::{self, ACTION_SCORE_WEIGHTING, CancelHandle},
     barbarians,
     game::Game,
     map::Terrain,
@@ -14,11 +20,23 @@ use crate::{
     utils::{self, Rng},
 };
 
+///
+/// The budget to choose missions with rollouts.
+#[derive(Clone, Copy)]
+pub struct MissionEvaluation<'a> {
+    pub(crate) time: Duration,
+    pub(crate) difficulty: f64,
+    pub(crate) runtime: &'a AiRuntime,
+    pub(crate) cancel: &'a CancelHandle,
+}
+
 #[derive(Clone)]
 pub struct ActiveMissions {
     pub missions: Vec<Mission>,
     pub player_index: usize,
     pub idle_units: Vec<u32>,
+    pub profile: AiProfile,
+    pub opening_book: Option<Arc<OpeningBook>>,
 }
 
 impl ActiveMissions {
@@ -26,17 +44,20 @@ impl ActiveMissions {
         game: &Game,
         player_index: usize,
         rng: &mut Rng,
-        monte_carlo_evaluation: Option<(Duration, f64)>,
+        monte_carlo_evaluation: Option<MissionEvaluation>,
+        profile: AiProfile,
     ) -> Self {
         let mut missions = Self {
             missions: Vec::new(),
             player_index,
             idle_units: Vec::new(),
+            profile,
+            opening_book: None,
         };
         missions
             .idle_units
             .extend(game.players[player_index].units.iter().map(|unit| unit.id));
-        missions.allocate_units(game, rng, monte_carlo_evaluation);
+        missions.allocate_units(game, rng, monte_carlo_evaluation.as_ref());
         missions
     }
 
@@ -44,7 +65,7 @@ impl ActiveMissions {
         &mut self,
         game: &Game,
         rng: &mut Rng,
-        monte_carlo_evaluation: Option<(Duration, f64)>,
+        monte_carlo_evaluation: Option<MissionEvaluation>,
     ) {
         let cloned_missions = self.clone();
         for mission in &mut self.missions {
@@ -57,14 +78,14 @@ impl ActiveMissions {
             }
         }
         self.missions.retain(|mission| !mission.is_complete(game));
-        self.allocate_units(game, rng, monte_carlo_evaluation);
+        self.allocate_units(game, rng, monte_carlo_evaluation.as_ref());
     }
 
     fn allocate_units(
         &mut self,
         game: &Game,
         rng: &mut Rng,
-        monte_carlo_evaluation: Option<(Duration, f64)>,
+        monte_carlo_evaluation: Option<&MissionEvaluation>,
     ) {
         let mut new_missions = Vec::new();
         for unit in &self.idle_units {
@@ -81,25 +102,10 @@ impl ActiveMissions {
                 );
                 continue;
             }
-            let mission = if let Some((evaluation_time, difficulty)) = monte_carlo_evaluation {
-                let runtime = Runtime::new().expect("failed to create runtime");
-                runtime.block_on(self.decide_mission(
-                    game,
-                    difficulty,
-                    missions,
-                    rng,
-                    evaluation_time,
-                ))
+            let mission = if let Some(evaluation) = monte_carlo_evaluation {
+                self.decide_mission(game, evaluation, missions, rng)
             } else {
-                let weightings = missions
-                    .iter()
-                    .map(|mission| mission.priority(game, self).powf(ACTION_SCORE_WEIGHTING))
-                    .collect::<Vec<f64>>();
-                let mission_index = utils::weighted_random_selection(&weightings, rng);
-                missions
-                    .into_iter()
-                    .nth(mission_index)
-                    .expect("index out of bounds")
+                self.decide_mission_by_priority(game, missions, rng)
             };
             new_missions.push(mission);
         }
@@ -353,34 +359,49 @@ impl ActiveMissions {
         missions
     }
 
-    async fn decide_mission(
+    fn decide_mission(
         &self,
         game: &Game,
-        difficulty: f64,
+        evaluation: &MissionEvaluation,
         missions: Vec<Mission>,
         rng: &mut Rng,
-        evaluation_time: Duration,
     ) -> Mission {
-        let time_per_mission = evaluation_time / missions.len() as u32;
-        let mut scores = Vec::new();
+        let difficulty = evaluation.difficulty;
         let players_active_missions = self.get_players_active_missions(game, rng);
+        let missions_players_active_missions = Arc::new(
+            missions
+                .iter()
+                .map(|mission| {
+                    let mut players_active_missions = players_active_missions.clone();
+                    players_active_missions[self.player_index]
+                        .missions
+                        .push(mission.clone());
+                    players_active_missions
+                })
+                .collect_vec(),
+        );
         let difficulty_factor = ai::difficulty_factor(difficulty);
-        for mission in &missions {
-            let mut players_active_missions = players_active_missions.clone();
-            players_active_missions[self.player_index]
-                .missions
-                .push(mission.clone());
-            let score = ai::get_average_score(
-                game.clone(),
-                self.player_index,
+        let player_index = self.player_index;
+        let rollout_game = Arc::new(game.ai_clone());
+        let scores = evaluation
+            .runtime
+            .rollouts(
+                missions.len(),
+                Instant::now() + evaluation.time,
+                evaluation.cancel,
                 rng,
-                time_per_mission,
-                &players_active_missions,
+                move |mission, rng| {
+                    ai::monte_carlo_score(
+                        rng,
+                        player_index,
+                        rollout_game.ai_clone(),
+                        missions_players_active_missions[mission].clone(),
+                    )
+                },
             )
-            .await
-            .powf(difficulty_factor);
-            scores.push(score);
-        }
+            .iter()
+            .map(|s| s.mean().powf(difficulty_factor))
+            .collect_vec();
         let chosen_mission = if difficulty >= 1.0 - f64::EPSILON {
             scores
                 .iter()
@@ -388,6 +409,9 @@ impl ActiveMissions {
                 .max_by(|a, b| a.1.partial_cmp(b.1).expect("floating point error"))
                 .expect("there are no possible actions")
                 .0
+        } else if scores.iter().sum::<f64>() <= f64::EPSILON {
+            // all rollouts were (nearly) lost - the scores can't be used as weights
+            return self.decide_mission_by_priority(game, missions, rng);
         } else {
             utils::weighted_random_selection(&scores, rng)
         };
@@ -397,6 +421,23 @@ impl ActiveMissions {
             .expect("index out of bounds")
     }
 
+    fn decide_mission_by_priority(
+        &self,
+        game: &Game,
+        missions: Vec<Mission>,
+        rng: &mut Rng,
+    ) -> Mission {
+        let weightings = missions
+            .iter()
+            .map(|mission| mission.priority(game, self).powf(ACTION_SCORE_WEIGHTING))
+            .collect::<Vec<f64>>();
+        let mission_index = utils::weighted_random_selection(&weightings, rng);
+        missions
+            .into_iter()
+            .nth(mission_index)
+            .expect("index out of bounds")
+    }
+
     fn missions_of_type(&self, mission_type: &MissionType) -> usize {
         self.missions
             .iter()
@@ -419,12 +460,25 @@ impl ActiveMissions {
                 players_active_missions.push(self.clone());
                 continue;
             }
-            players_active_missions.push(ActiveMissions::new(game, player.index, rng, None));
+            // the profiles of the opponents are unknown
+            let mut missions =
+                ActiveMissions::new(game, player.index, rng, None, AiProfile::default());
+            missions.opening_book.clone_from(&self.opening_book);
+            players_active_missions.push(missions);
         }
         players_active_missions
     }
 }
 
+///
+/// A mission as shown in the AI decision trace.
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct MissionTrace {
+    pub mission_type: String,
+    pub target: Position,
+    pub units: Vec<u32>,
+}
+
 #[derive(Clone)]
 pub struct Mission {
     units: Vec<u32>,
@@ -462,9 +516,38 @@ impl Mission {
     ///
     /// Panics if the game or the mission is invalid.
     #[must_use]
-    #[allow(clippy::only_used_in_recursion)]
-    pub fn priority(&self, _game: &Game, _active_missions: &ActiveMissions) -> f64 {
-        1.0 //todo
+    pub fn priority(&self, game: &Game, active_missions: &ActiveMissions) -> f64 {
+        let w = &active_missions.profile.weights();
+        let value = match &self.mission_type {
+            MissionType::FoundCity => w.city_size + w.victory_points,
+            MissionType::CapturePlayerCity { player_index } => {
+                let size = game.players[*player_index]
+                    .try_get_city(self.target)
+                    .map_or(1, City::size);
+                // the city is gained and the opponent loses it
+                2.0 * (size as f64 * w.city_size + w.victory_points)
+            }
+            MissionType::CaptureBarbarianCamp => w.victory_points,
+            MissionType::DefendCity => game.players[self.player_index]
+                .try_get_city(self.target)
+                .map_or(0.0, |city| city.size() as f64 * w.city_size),
+            MissionType::FightPlayerForces { units, .. }
+            | MissionType::FightBarbarians { units }
+            | MissionType::FightPirates { units } => units.len() as f64 * w.army_unit,
+            MissionType::Explore => w.resource * 2.0,
+            MissionType::Transport | MissionType::Cartography => w.ship,
+        };
+        // at least 1, like the action type priors
+        1.0 + value.max(0.0)
+    }
+
+    #[must_use]
+    pub fn trace(&self) -> MissionTrace {
+        MissionTrace {
+            mission_type: format!("{:?}", self.mission_type),
+            target: self.target,
+            units: self.units.clone(),
+        }
     }
 
     fn update(&mut self, game: &Game, active_missions: &ActiveMissions) {
@@ -594,7 +677,7 @@ impl Mission {
     }
 }
 
-#[derive(Clone, PartialEq)]
+#[derive(Clone, PartialEq, Debug)]
 enum MissionType {
     Explore,
     DefendCity,
@@ -0,0 +1,246 @@
+use crate::action::Action;
+use crate::advance::Advance;
+use crate::collect::{Collect, PositionCollection};
+use crate::game::{Game, GameState};
+use crate::map::{is_standard_map, position_relative_to_home};
+use crate::player::Player;
+use crate::playing_actions::{PlayingAction, PlayingActionType};
+use crate::position::Position;
+use crate::replay::{ReplayGameData, replay_and_observe};
+use serde::{Deserialize, Serialize};
+
+// number of observed actions in a round before the book is used
+const MIN_SAMPLES: u32 = 10;
+// share of the observed actions that a move needs to be played without a search
+const SHORTCUT_SHARE: f64 = 0.6;
+// the priors are between 1 and 1 + BOOK_WEIGHT
+const BOOK_WEIGHT: f64 = 4.0;
+
+///
+/// Statistics of the moves in archived games - to weight the AI playouts and to skip the search
+/// for well known early moves.
+///
+/// Created with `api_tool opening-book`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct OpeningBook {
+    pub games: usize,
+    pub rounds: Vec<RoundStatistics>,
+}
+
+///
+/// The moves of all players in one round of an age.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RoundStatistics {
+    pub age: u32,
+    pub round: u32,
+    pub actions: u32,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub action_types: Vec<(PlayingActionType, u32)>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub advances: Vec<(Advance, u32)>,
+    // relative to the capital - see [`position_relative_to_home`]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub city_positions: Vec<(Position, u32)>,
+    // relative to the capital - see [`position_relative_to_home`]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub collects: Vec<(Collect, u32)>,
+}
+
+impl RoundStatistics {
+    fn new(age: u32, round: u32) -> Self {
+        Self {
+            age,
+            round,
+            actions: 0,
+            action_types: Vec::new(),
+            advances: Vec::new(),
+            city_positions: Vec::new(),
+            collects: Vec::new(),
+        }
+    }
+}
+
+impl OpeningBook {
+    ///
+    /// Adds the moves of an archived game.
+    /// Games that are not played on the standard map are skipped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the game cannot be replayed
+    pub fn add_game(&mut self, data: ReplayGameData) {
+        let mut standard_map = None;
+        replay_and_observe(data, None, |game, action| {
+            if *standard_map.get_or_insert_with(|| is_standard_map(game)) {
+                self.observe(game, action);
+            }
+        });
+        if standard_map == Some(true) {
+            self.games += 1;
+        }
+    }
+
+    fn observe(&mut self, game: &Game, action: &Action) {
+        let Action::Playing(action) = action else {
+            return;
+        };
+        if game.state != GameState::Playing || !game.events.is_empty() {
+            return;
+        }
+        let player = game.player(game.active_player());
+        let action_type = action.playing_action_type(player);
+        let stats = self.round_mut(game.age, game.round);
+        stats.actions += 1;
+        increment(&mut stats.action_types, action_type);
+        match action {
+            PlayingAction::Advance(a) => increment(&mut stats.advances, a.advance),
+            PlayingAction::FoundCity { settler } => {
+                let position = player.get_unit(*settler).position;
+                increment(
+                    &mut stats.city_positions,
+                    position_relative_to_home(game, player, position),
+                );
+            }
+            PlayingAction::Collect(c) => {
+                increment(&mut stats.collects, relative_collect(game, player, c));
+            }
+            _ => {}
+        }
+    }
+
+    fn round_mut(&mut self, age: u32, round: u32) -> &mut RoundStatistics {
+        let i = self
+            .rounds
+            .iter()
+            .position(|r| r.age == age && r.round == round)
+            .unwrap_or_else(|| {
+                self.rounds.push(RoundStatistics::new(age, round));
+                self.rounds.len() - 1
+            });
+        &mut self.rounds[i]
+    }
+
+    // the statistics are relative to the standard map
+    fn round(&self, game: &Game) -> Option<&RoundStatistics> {
+        if !is_standard_map(game) {
+            return None;
+        }
+        self.rounds
+            .iter()
+            .find(|r| r.age == game.age && r.round == game.round && r.actions >= MIN_SAMPLES)
+    }
+
+    ///
+    /// Returns the prior probability weight of the action type in the playouts.
+    #[must_use]
+    pub fn action_type_prior(&self, game: &Game, action_type: &PlayingActionType) -> f64 {
+        self.round(game).map_or(1.0, |r| {
+            prior(share(&r.action_types, action_type, r.actions))
+        })
+    }
+
+    ///
+    /// Returns the prior probability weight of the action in the playouts.
+    #[must_use]
+    pub fn action_prior(&self, game: &Game, action: &PlayingAction) -> f64 {
+        self.round(game)
+            .and_then(|r| detail_share(game, r, action))
+            .map_or(1.0, prior)
+    }
+
+    ///
+    /// Returns a move that was played so often in this situation that it's not worth searching.
+    #[must_use]
+    pub fn shortcut<'a>(&self, game: &Game, actions: &'a [Action]) -> Option<&'a Action> {
+        let r = self.round(game)?;
+        let player = game.player(game.active_player());
+        actions
+            .iter()
+            .filter_map(|action| {
+                let Action::Playing(playing_action) = action else {
+                    return None;
+                };
+                let action_share = share(
+                    &r.action_types,
+                    &playing_action.playing_action_type(player),
+                    r.actions,
+                ) * detail_share(game, r, playing_action)?;
+                (action_share >= SHORTCUT_SHARE).then_some((action, action_share))
+            })
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(action, _)| action)
+    }
+}
+
+// the share of the action among the actions of the same type
+fn detail_share(game: &Game, r: &RoundStatistics, action: &PlayingAction) -> Option<f64> {
+    match action {
+        PlayingAction::Advance(a) => Some(share(&r.advances, &a.advance, total(&r.advances))),
+        PlayingAction::FoundCity { settler } => {
+            let player = game.player(game.active_player());
+            let position = player.get_unit(*settler).position;
+            Some(share(
+                &r.city_positions,
+                &position_relative_to_home(game, player, position),
+                total(&r.city_positions),
+            ))
+        }
+        PlayingAction::Collect(c) => {
+            let player = game.player(game.active_player());
+            Some(share(
+                &r.collects,
+                &relative_collect(game, player, c),
+                total(&r.collects),
+            ))
+        }
+        _ => None,
+    }
+}
+
+// the collect as seen from the capital - sorted, because the order of the collections is arbitrary
+fn relative_collect(game: &Game, player: &Player, collect: &Collect) -> Collect {
+    let mut collections = collect
+        .collections
+        .iter()
+        .map(|c| PositionCollection {
+            position: position_relative_to_home(game, player, c.position),
+            ..c.clone()
+        })
+        .collect::<Vec<_>>();
+    collections.sort_by_key(|c| (c.position.q, c.position.r));
+    Collect::new(
+        position_relative_to_home(game, player, collect.city_position),
+        collections,
+        collect.action_type.clone(),
+    )
+}
+
+fn increment<T: PartialEq>(counts: &mut Vec<(T, u32)>, key: T) {
+    if let Some((_, count)) = counts.iter_mut().find(|(k, _)| *k == key) {
+        *count += 1;
+    } else {
+        counts.push((key, 1));
+    }
+}
+
+fn total<T>(counts: &[(T, u32)]) -> u32 {
+    counts.iter().map(|(_, count)| count).sum()
+}
+
+fn share<T: PartialEq>(counts: &[(T, u32)], key: &T, total: u32) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    counts
+        .iter()
+        .find(|(k, _)| k == key)
+        .map_or(0.0, |(_, count)| f64::from(*count) / f64::from(total))
+}
+
+fn prior(share: f64) -> f64 {
+    1.0 + BOOK_WEIGHT * share
+}
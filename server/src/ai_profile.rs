@@ -0,0 +1,136 @@
+use crate::advance::Advance;
+use crate::ai_evaluation::{EvaluationWeights, STANDARD_WEIGHTS};
+use crate::content::advances::AdvanceGroup;
+use crate::game::Game;
+use serde::Serialize;
+use std::fmt::Display;
+
+// how much more likely an advance of a preferred group is chosen in the playouts
+const PREFERRED_ADVANCE_BIAS: f64 = 3.0;
+
+///
+/// The play style of an AI seat - it changes the evaluation weights, which in turn bias
+/// the mission allocation and action choices, and the preferred advance groups.
+#[derive(Serialize, Clone, Copy, PartialEq, Eq, Debug, Default, Hash)]
+pub enum AiProfile {
+    #[default]
+    Balanced,
+    Expansionist,
+    Militarist,
+    Culture,
+    WonderBuilder,
+    ObjectiveHunter,
+}
+
+impl AiProfile {
+    #[must_use]
+    pub fn all() -> Vec<AiProfile> {
+        vec![
+            AiProfile::Balanced,
+            AiProfile::Expansionist,
+            AiProfile::Militarist,
+            AiProfile::Culture,
+            AiProfile::WonderBuilder,
+            AiProfile::ObjectiveHunter,
+        ]
+    }
+
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        match self {
+            AiProfile::Balanced => "balanced",
+            AiProfile::Expansionist => "expansionist",
+            AiProfile::Militarist => "militarist",
+            AiProfile::Culture => "culture",
+            AiProfile::WonderBuilder => "wonders",
+            AiProfile::ObjectiveHunter => "objectives",
+        }
+    }
+
+    #[must_use]
+    pub fn from_code(code: &str) -> Option<AiProfile> {
+        AiProfile::all()
+            .into_iter()
+            .find(|p| p.code() == code.trim().to_lowercase())
+    }
+
+    #[must_use]
+    pub fn weights(&self) -> EvaluationWeights {
+        let s = STANDARD_WEIGHTS;
+        match self {
+            AiProfile::Balanced => s,
+            AiProfile::Expansionist => EvaluationWeights {
+                city_size: 0.35,
+                settler: 0.6,
+                income: 0.2,
+                ..s
+            },
+            AiProfile::Militarist => EvaluationWeights {
+                army_unit: 0.5,
+                ship: 0.25,
+                ..s
+            },
+            AiProfile::Culture => EvaluationWeights {
+                happy_city: 0.4,
+                influence: 0.5,
+                ..s
+            },
+            AiProfile::WonderBuilder => EvaluationWeights {
+                wonder: 2.0,
+                wonder_card: 1.0,
+                wonder_progress: 0.6,
+                ..s
+            },
+            AiProfile::ObjectiveHunter => EvaluationWeights {
+                objective_card: 1.0,
+                objective_progress: 1.0,
+                action_card: 0.3,
+                ..s
+            },
+        }
+    }
+
+    #[must_use]
+    pub fn preferred_advance_groups(&self) -> Vec<AdvanceGroup> {
+        match self {
+            AiProfile::Balanced => vec![],
+            AiProfile::Expansionist => vec![
+                AdvanceGroup::Agriculture,
+                AdvanceGroup::Construction,
+                AdvanceGroup::Seafaring,
+            ],
+            AiProfile::Militarist => vec![AdvanceGroup::Warfare, AdvanceGroup::Autocracy],
+            AiProfile::Culture => vec![
+                AdvanceGroup::Culture,
+                AdvanceGroup::Spirituality,
+                AdvanceGroup::Democracy,
+            ],
+            AiProfile::WonderBuilder => vec![AdvanceGroup::Construction, AdvanceGroup::Economy],
+            AiProfile::ObjectiveHunter => vec![AdvanceGroup::Education, AdvanceGroup::Science],
+        }
+    }
+
+    ///
+    /// Returns the prior probability weight of choosing the advance in the playouts.
+    #[must_use]
+    pub fn advance_prior(&self, game: &Game, advance: Advance) -> f64 {
+        let preferred = self.preferred_advance_groups().into_iter().any(|g| {
+            game.cache
+                .get_advance_group(g)
+                .advances
+                .iter()
+                .any(|a| a.advance == advance)
+        });
+        if preferred {
+            PREFERRED_ADVANCE_BIAS
+        } else {
+            1.0
+        }
+    }
+}
+
+impl Display for AiProfile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
@@ -0,0 +1,170 @@
+use crate::ai::{CancelHandle, ScoreStatistics};
+use crate::utils::Rng;
+use std::future::Future;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use web_time::Instant;
+
+///
+/// Runs the AI rollouts - on all cores in native builds, one after another in the browser,
+/// where there are no threads.
+///
+/// The worker threads are kept for the lifetime of the AI.
+pub(crate) struct AiRuntime {
+    #[cfg(not(target_arch = "wasm32"))]
+    runtime: tokio::runtime::Runtime,
+}
+
+impl AiRuntime {
+    #[must_use]
+    pub(crate) fn new() -> Self {
+        AiRuntime {
+            #[cfg(not(target_arch = "wasm32"))]
+            runtime: tokio::runtime::Runtime::new().expect("failed to create runtime"),
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    #[allow(clippy::unused_self)]
+    pub(crate) fn block_on<F: Future>(&self, future: F) -> F::Output {
+        use std::pin::pin;
+        use std::task::{Context, Poll, Waker};
+
+        // the rollouts never wait for anything in the browser
+        match pin!(future).poll(&mut Context::from_waker(Waker::noop())) {
+            Poll::Ready(result) => result,
+            Poll::Pending => panic!("AI future should not be pending"),
+        }
+    }
+
+    ///
+    /// Runs rollouts of all candidates until the deadline (or until the AI is cancelled)
+    /// and returns the scores of each candidate.
+    ///
+    /// The workers share one queue of rollouts, so that no worker is idle before the deadline
+    /// and all candidates get the same number of rollouts.
+    /// Every candidate gets at least one rollout - even if the AI is cancelled,
+    /// because a score of 0 for all candidates can't be used for a weighted selection.
+    pub(crate) fn rollouts<F>(
+        &self,
+        candidates: usize,
+        deadline: Instant,
+        cancel: &CancelHandle,
+        rng: &mut Rng,
+        rollout: F,
+    ) -> Vec<ScoreStatistics>
+    where
+        F: Fn(usize, Rng) -> f64 + Send + Sync + 'static,
+    {
+        rng.next_seed();
+        let queue = RolloutQueue {
+            candidates,
+            deadline,
+            cancel: cancel.clone(),
+            next: AtomicUsize::new(0),
+            seed: rng.seed,
+        };
+        self.run_workers(&Arc::new(queue), &Arc::new(rollout))
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn run_workers<F>(&self, queue: &Arc<RolloutQueue>, rollout: &Arc<F>) -> Vec<ScoreStatistics>
+    where
+        F: Fn(usize, Rng) -> f64 + Send + Sync + 'static,
+    {
+        let handles = (0..parallelism())
+            .map(|_| {
+                let queue = queue.clone();
+                let rollout = rollout.clone();
+                self.runtime
+                    .spawn(async move { queue.work(rollout.as_ref()) })
+            })
+            .collect::<Vec<_>>();
+        self.runtime.block_on(async {
+            let mut statistics = vec![ScoreStatistics::default(); queue.candidates];
+            for handle in handles {
+                let worker_statistics = handle.await.expect("multi-threading error");
+                for (s, w) in statistics.iter_mut().zip(worker_statistics) {
+                    s.merge(&w);
+                }
+            }
+            statistics
+        })
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    #[allow(clippy::unused_self)]
+    fn run_workers<F>(&self, queue: &Arc<RolloutQueue>, rollout: &Arc<F>) -> Vec<ScoreStatistics>
+    where
+        F: Fn(usize, Rng) -> f64 + Send + Sync + 'static,
+    {
+        queue.work(rollout.as_ref())
+    }
+}
+
+struct RolloutQueue {
+    candidates: usize,
+    deadline: Instant,
+    cancel: CancelHandle,
+    next: AtomicUsize,
+    seed: u128,
+}
+
+impl RolloutQueue {
+    fn work(&self, rollout: &impl Fn(usize, Rng) -> f64) -> Vec<ScoreStatistics> {
+        let mut statistics = vec![ScoreStatistics::default(); self.candidates];
+        loop {
+            let n = self.next.fetch_add(1, Ordering::Relaxed);
+            if n >= self.candidates
+                && (self.cancel.is_cancelled() || Instant::now() >= self.deadline)
+            {
+                return statistics;
+            }
+            let mut rng = Rng::from_seed(self.seed.wrapping_add(n as u128));
+            rng.next_seed();
+            let candidate = n % self.candidates;
+            statistics[candidate].add(rollout(candidate, rng));
+        }
+    }
+}
+
+///
+/// Returns the number of rollouts that can run at the same time.
+#[must_use]
+pub(crate) fn parallelism() -> usize {
+    if cfg!(target_arch = "wasm32") {
+        1
+    } else {
+        num_cpus::get()
+    }
+}
+
+///
+/// Runs the tasks in parallel (if possible) and returns their results in the same order.
+pub(crate) async fn run_all<T, F>(tasks: Vec<F>) -> Vec<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> T + Send + 'static,
+{
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let handles = tasks
+            .into_iter()
+            .map(|task| tokio::spawn(async move { task() }))
+            .collect::<Vec<_>>();
+        let mut results = Vec::with_capacity(handles.len());
+        for handle in handles {
+            results.push(handle.await.expect("multi-threading error"));
+        }
+        results
+    }
+    #[cfg(target_arch = "wasm32")]
+    {
+        tasks.into_iter().map(|task| task()).collect()
+    }
+}
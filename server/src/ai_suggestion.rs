@@ -0,0 +1,139 @@
+use crate::action::{Action, execute_action};
+use crate::ai::{AI, CandidateTrace};
+use crate::ai_evaluation::evaluation_terms;
+use crate::ai_profile::AiProfile;
+use crate::game::Game;
+use crate::game_api::strip_secret;
+use crate::log::ActionLogAction;
+use crate::utils::Shuffle;
+use crate::wonder::Wonder;
+use itertools::Itertools;
+use std::time::Duration;
+
+///
+/// An action recommended to a human player - it's not executed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Suggestion {
+    pub action: Action,
+    pub reason: String,
+    // the action as it would appear in the log - to show it like the executed actions
+    pub log: ActionLogAction,
+}
+
+///
+/// Returns the best actions for the player according to the AI - the best one first.
+///
+/// The AI only sees what the player can see, i.e. the hidden cards of the opponents are
+/// replaced with random cards from the decks and the unexplored map blocks are shuffled.
+///
+/// # Panics
+///
+/// Panics if it's not the player's turn
+#[must_use]
+pub fn suggest_actions(
+    game: &Game,
+    player_index: usize,
+    thinking_time: Duration,
+    count: usize,
+) -> Vec<Suggestion> {
+    let view = player_view(game, player_index);
+    let mut ai = AI::new(
+        1.,
+        thinking_time,
+        false,
+        &view,
+        player_index,
+        AiProfile::default(),
+    );
+    let (_, trace) = ai.next_action_with_trace(&view);
+    trace
+        .candidates
+        .into_iter()
+        // the candidates that survived more pruning rounds have more reliable scores
+        .sorted_by(|a, b| {
+            b.eliminated_in_round
+                .unwrap_or(usize::MAX)
+                .cmp(&a.eliminated_in_round.unwrap_or(usize::MAX))
+                .then(b.mean_score.total_cmp(&a.mean_score))
+        })
+        .take(count)
+        .map(|c| {
+            let after = execute_action(view.ai_clone(), c.action.clone(), player_index);
+            Suggestion {
+                reason: reason(&view, &after, player_index, &c),
+                log: logged_action(&after, player_index, &c.action),
+                action: c.action,
+            }
+        })
+        .collect()
+}
+
+fn player_view(game: &Game, player_index: usize) -> Game {
+    let mut view = strip_secret(game.clone(), Some(player_index));
+    // guess the hidden cards, so that the opponents can play them in the rollouts
+    for p in &mut view.players {
+        if p.index == player_index {
+            continue;
+        }
+        for id in &mut p.action_cards {
+            if *id == 0 {
+                *id = view.action_cards_left.pop().unwrap_or_default();
+            }
+        }
+        p.action_cards.retain(|id| *id != 0);
+        for id in &mut p.objective_cards {
+            if *id == 0 {
+                *id = view.objective_cards_left.pop().unwrap_or_default();
+            }
+        }
+        p.objective_cards.retain(|id| *id != 0);
+        for w in &mut p.wonder_cards {
+            if *w == Wonder::Hidden {
+                *w = view.wonders_left.pop().unwrap_or(Wonder::Hidden);
+            }
+        }
+        p.wonder_cards.retain(|w| *w != Wonder::Hidden);
+    }
+    // the set of map blocks is known - only their positions are secret
+    let blocks = game
+        .map
+        .unexplored_blocks
+        .iter()
+        .map(|b| b.block.clone())
+        .collect_vec()
+        .shuffled(&mut view.rng);
+    for (b, block) in view.map.unexplored_blocks.iter_mut().zip(blocks) {
+        b.block = block;
+    }
+    view
+}
+
+fn logged_action(game: &Game, player_index: usize, action: &Action) -> ActionLogAction {
+    game.log
+        .iter()
+        .rev()
+        .flat_map(|age| age.rounds.iter().rev())
+        .flat_map(|round| round.turns.iter().rev())
+        .flat_map(|turn| turn.actions.iter().rev())
+        .find(|a| a.player == player_index && &a.action == action)
+        .cloned()
+        .expect("executed action should be logged")
+}
+
+fn reason(game: &Game, after: &Game, player_index: usize, candidate: &CandidateTrace) -> String {
+    let before = evaluation_terms(game, player_index, &AiProfile::default().weights());
+    let gain = evaluation_terms(after, player_index, &AiProfile::default().weights())
+        .into_iter()
+        .zip(before)
+        .map(|((name, a), (_, b))| (name, a - b))
+        .max_by(|(_, a), (_, b)| a.total_cmp(b))
+        .filter(|(_, gain)| *gain > 0.0);
+    let outlook = format!(
+        "expected {:+.1} victory points compared to the best opponent",
+        candidate.mean_score
+    );
+    match gain {
+        Some((name, _)) => format!("Improves your {name} - {outlook}"),
+        None => format!("Prepares later turns - {outlook}"),
+    }
+}
@@ -1,11 +1,23 @@
 #![allow(clippy::missing_panics_doc)]
 
+use itertools::Itertools;
 use serde::Serialize;
-use server::game::Game;
+use server::action::execute_action;
+use server::ai;
+use server::ai::AI;
+use server::ai_opening_book::OpeningBook;
+use server::ai_profile::AiProfile;
+use server::cache::Cache;
+use server::game::{Game, GameContext, GameOptions, GameState, PatchOption};
+use server::game_api;
+use server::game_data::GameData;
+use server::game_setup::{GameSetupBuilder, setup_game};
 use server::replay;
 use server::replay::ReplayGameData;
+use server::rules_inspection::inspect_rules;
 use std::fs::File;
-use std::time::SystemTime;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
 use std::{env, fs};
 
 fn main() {
@@ -20,6 +32,24 @@ fn main() {
         "replay" => {
             replay(args.get(2));
         }
+        "content" => {
+            content(args.get(2));
+        }
+        "bench" => {
+            bench(args.get(2));
+        }
+        "tournament" => {
+            tournament(args.get(2), args.get(3), args.get(4));
+        }
+        "opening-book" => {
+            opening_book(args.get(2));
+        }
+        "ai-trace" => {
+            ai_trace(args.get(2), args.get(3));
+        }
+        "inspect" => {
+            inspect();
+        }
         _ => {
             println!("Unknown command: {command}");
         }
@@ -36,6 +66,213 @@ fn replay(to: Option<&String>) {
     export(game)
 }
 
+fn content(mode: Option<&String>) {
+    match mode.map(String::as_str) {
+        None | Some("standard") => write(
+            &game_api::content(PatchOption::Standard),
+            "content-standard.json",
+        ),
+        Some("balance") => write(
+            &game_api::content(PatchOption::BalancePatch),
+            "content-balance-patch.json",
+        ),
+        Some("diff") => write(&game_api::content_diff(), "content-diff.json"),
+        Some(mode) => println!("Unknown content mode: {mode} (use standard, balance or diff)"),
+    }
+}
+
+// compares the old way of cloning (serde round-trip) with Game::ai_clone
+fn bench(source: Option<&String>) {
+    let game = match source.map(String::as_str) {
+        Some("game") => {
+            let data: GameData =
+                serde_json::from_str(&read_game_str()).expect("Failed to read export file");
+            let cache = Cache::new(&data.options);
+            Game::from_data(data, cache, GameContext::AI)
+        }
+        _ => {
+            let mut game = setup_game(&GameSetupBuilder::new(2).seed("bench".to_string()).build());
+            game.context = GameContext::AI;
+            game
+        }
+    };
+    let duration = Duration::from_secs(5);
+    let data_clone =
+        |game: &Game| Game::from_data(game.cloned_data(), game.cache.clone(), GameContext::AI);
+
+    println!(
+        "clones per second via GameData: {:.0}",
+        clones_per_second(&game, duration, data_clone)
+    );
+    println!(
+        "clones per second via ai_clone: {:.0}",
+        clones_per_second(&game, duration, Game::ai_clone)
+    );
+    println!(
+        "rollouts per second via GameData: {:.2}",
+        ai::benchmark_rollouts(&game, duration, data_clone)
+    );
+    println!(
+        "rollouts per second via ai_clone: {:.2}",
+        ai::benchmark_rollouts(&game, duration, Game::ai_clone)
+    );
+}
+
+// plays AI games with one profile per seat - the seats rotate after each game
+fn tournament(profiles: Option<&String>, games: Option<&String>, patch: Option<&String>) {
+    let profiles = profiles
+        .map_or("balanced,balanced", String::as_str)
+        .split(',')
+        .map(|p| AiProfile::from_code(p).unwrap_or_else(|| panic!("Unknown AI profile: {p}")))
+        .collect::<Vec<_>>();
+    let games: usize = games.map_or(1, |g| g.parse().expect("Failed to parse number of games"));
+    let patch = match patch.map(String::as_str) {
+        Some("balance") => PatchOption::BalancePatch,
+        _ => PatchOption::Standard,
+    };
+    let seats = profiles.len();
+    let book = load_opening_book();
+
+    let mut wins = vec![0; seats];
+    let mut points = vec![0.0; seats];
+    for i in 0..games {
+        let mut game = setup_game(
+            &GameSetupBuilder::new(seats)
+                .seed(format!("tournament{i}"))
+                .options(GameOptions {
+                    patch: patch.clone(),
+                    ..GameOptions::default()
+                })
+                .build(),
+        );
+        let profile_index = |seat: usize| (seat + i) % seats;
+        let mut players = (0..seats)
+            .map(|p| {
+                let mut ai = AI::new(
+                    1.,
+                    Duration::from_millis(200),
+                    false,
+                    &game,
+                    p,
+                    profiles[profile_index(p)],
+                );
+                if let Some(book) = &book {
+                    ai.set_opening_book(book.clone());
+                }
+                ai
+            })
+            .collect::<Vec<_>>();
+        while game.state != GameState::Finished {
+            let p = game.active_player();
+            let action = players[p].next_action(&game);
+            game = execute_action(game, action, p);
+        }
+        // the same ranking as in end_game - a tie counts as a win for all tied players
+        let winners = game_api::rankings(&game)
+            .into_iter()
+            .positions(|rank| rank == 1)
+            .collect::<Vec<_>>();
+        for &winner in &winners {
+            wins[profile_index(winner)] += 1;
+        }
+        for p in 0..seats {
+            points[profile_index(p)] += game.player(p).victory_points(&game);
+        }
+        println!(
+            "game {i}: won by {}",
+            winners
+                .iter()
+                .map(|&w| profiles[profile_index(w)].to_string())
+                .join(", ")
+        );
+        // the games can be mined with the opening-book command
+        write(
+            &ReplayGameData::from_game(&game),
+            &format!("tournament-{i}.json"),
+        );
+    }
+    for (i, profile) in profiles.iter().enumerate() {
+        println!(
+            "{profile}: {} wins, {:.1} average victory points",
+            wins[i],
+            points[i] / games as f32
+        );
+    }
+}
+
+// mines the replays in the directory - the book is used if CLASH_OPENING_BOOK is set
+fn opening_book(dir: Option<&String>) {
+    let mut book = OpeningBook::default();
+    for entry in fs::read_dir(dir.map_or(".", String::as_str)).expect("Failed to read directory") {
+        let path = entry.expect("Failed to read directory entry").path();
+        if path.extension().is_none_or(|e| e != "json") {
+            continue;
+        }
+        let Ok(data) = serde_json::from_str::<ReplayGameData>(
+            &fs::read_to_string(&path).expect("Failed to read replay file"),
+        ) else {
+            println!("Skipping {}: not a replay", path.display());
+            continue;
+        };
+        book.add_game(data);
+    }
+    println!("Mined {} games", book.games);
+    write(&book, "opening-book.json");
+}
+
+fn load_opening_book() -> Option<Arc<OpeningBook>> {
+    let path = env::var("CLASH_OPENING_BOOK").ok()?;
+    let book =
+        serde_json::from_str(&fs::read_to_string(path).expect("Failed to read opening book"))
+            .expect("Failed to parse opening book");
+    Some(Arc::new(book))
+}
+
+// dumps the candidates and missions the AI considered for the next action in game.json
+fn ai_trace(thinking_ms: Option<&String>, profile: Option<&String>) {
+    let thinking = thinking_ms.map_or(1000, |t| t.parse().expect("Failed to parse thinking time"));
+    let profile = profile.map_or(AiProfile::default(), |p| {
+        AiProfile::from_code(p).unwrap_or_else(|| panic!("Unknown AI profile: {p}"))
+    });
+    let data: GameData =
+        serde_json::from_str(&read_game_str()).expect("Failed to read export file");
+    let cache = Cache::new(&data.options);
+    let game = Game::from_data(data, cache, GameContext::Play);
+    let player_index = game.active_player();
+    let mut ai = AI::new(
+        1.,
+        Duration::from_millis(thinking),
+        false,
+        &game,
+        player_index,
+        profile,
+    );
+    if let Some(book) = load_opening_book() {
+        ai.set_opening_book(book);
+    }
+    let (_, trace) = ai.next_action_with_trace(&game);
+    write(&trace, "ai-trace.json");
+}
+
+// reports which objectives and wonders could be completed in game.json
+fn inspect() {
+    let data: GameData =
+        serde_json::from_str(&read_game_str()).expect("Failed to read export file");
+    let cache = Cache::new(&data.options);
+    let game = Game::from_data(data, cache, GameContext::Play);
+    write(&inspect_rules(&game), "inspection.json");
+}
+
+fn clones_per_second(game: &Game, duration: Duration, clone: impl Fn(&Game) -> Game) -> f64 {
+    let start = Instant::now();
+    let mut clones = 0;
+    while start.elapsed() < duration {
+        drop(clone(game));
+        clones += 1;
+    }
+    f64::from(clones) / start.elapsed().as_secs_f64()
+}
+
 fn read_game_str() -> String {
     // read from game.json instead of escaped-game.json if the modification date is newer
     let g = "game.json";
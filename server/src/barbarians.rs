@@ -10,6 +10,7 @@ use crate::content::persistent_events::{
 use crate::events::{EventOrigin, EventPlayer};
 use crate::game::Game;
 use crate::incident::{BASE_EFFECT_PRIORITY, IncidentBuilder, IncidentFilter, play_base_effect};
+use crate::localization::Message;
 use crate::map::Terrain;
 use crate::movement::MoveUnits;
 use crate::player::{Player, end_turn, gain_unit};
@@ -102,7 +103,13 @@ pub(crate) fn barbarians_spawn(mut builder: IncidentBuilder) -> IncidentBuilder
         |game, p, _i| {
             let r = possible_barbarians_reinforcements(game);
             if r.is_empty() {
-                p.log(game, "Barbarians cannot reinforce");
+                p.log_message(
+                    game,
+                    Message::new(
+                        "log.barbarians.barbarians_cannot_reinforce",
+                        "Barbarians cannot reinforce",
+                    ),
+                );
             }
             let needed = 1..=1;
             Some(PositionRequest::new(
@@ -224,9 +231,12 @@ pub(crate) fn barbarians_move(mut builder: IncidentBuilder) -> IncidentBuilder {
     let event_name = "Barbarians move";
     builder = set_info(builder, event_name, |state, game, human| {
         if get_movable_units(game, human.index, state).is_empty() {
-            human.log(
+            human.log_message(
                 game,
-                "Barbarians cannot move - will try to spawn a new city instead",
+                Message::new(
+                    "log.barbarians.barbarians_cannot_move_will_try_to",
+                    "Barbarians cannot move - will try to spawn a new city instead",
+                ),
             );
         } else {
             state.move_units = true;
@@ -323,12 +333,15 @@ fn execute_barbarian_move(
     let units: Vec<u32> = ids.iter().map(|u| u.id).collect();
     state.moved_units.extend(units.iter());
     let unit_types = ids.iter().map(|u| u.unit_type).collect::<Units>();
-    s.log(
+    s.log_message(
         game,
-        &format!(
-            "Barbarians move from {from} to {to}: {}",
-            unit_types.to_string(None)
-        ),
+        Message::new(
+            "log.barbarians.move",
+            "Barbarians move from {from} to {to}: {units}",
+        )
+        .param("from", from)
+        .param("to", to)
+        .param("units", unit_types.to_string(None)),
     );
     move_with_possible_combat(
         game,
@@ -412,7 +425,10 @@ pub(crate) fn set_info(
         BASE_EFFECT_PRIORITY + 200,
         move |game, player, i| {
             if play_base_effect(i) && i.barbarians.is_none() {
-                player.log(game, &format!("Base effect: {name}"));
+                player.log_message(
+                    game,
+                    Message::new("log.base_effect", "Base effect: {name}").param("name", &name),
+                );
                 let mut state = BarbariansEventState::new();
                 init(&mut state, game, player);
                 i.barbarians = Some(state);
@@ -432,7 +448,13 @@ fn add_barbarians_city(builder: IncidentBuilder, event_name: &'static str) -> In
 
             let choices = possible_barbarians_spawns(game, game.player(p.index));
             if choices.is_empty() {
-                p.log(game, "Barbarians cannot spawn a new city");
+                p.log_message(
+                    game,
+                    Message::new(
+                        "log.barbarians.barbarians_cannot_spawn_a_new_city",
+                        "Barbarians cannot spawn a new city",
+                    ),
+                );
             }
             let needed = 1..=1;
             Some(PositionRequest::new(
@@ -486,7 +508,7 @@ fn possible_barbarians_reinforcements(game: &Game) -> Vec<Position> {
     if !barbarian_fighters().iter().any(|u| avail.has_unit(u)) {
         return vec![];
     }
-    cities_that_can_add_units(barbarian)
+    cities_that_can_add_units(game, barbarian)
 }
 
 fn get_barbarian_reinforcement_choices(game: &Game, pos: Position) -> Vec<UnitType> {
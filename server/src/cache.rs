@@ -5,13 +5,14 @@ use crate::civilization::Civilization;
 use crate::content::ability::Ability;
 use crate::content::advances::{AdvanceGroup, AdvanceGroupInfo};
 use crate::content::custom_actions::SpecialActionExecution;
-use crate::content::{
-    ability, action_cards, advances, civilizations, incidents, objective_cards, objectives, wonders,
-};
+use crate::content::{ability, advances, scenarios};
+use crate::content_pack::{ContentLists, registered_content_packs};
+use crate::draft::{DRAFT, draft_handler};
 use crate::game::{Game, GameOptions};
 use crate::incident::Incident;
 use crate::leader::{Leader, LeaderInfo};
 use crate::objective_card::{Objective, ObjectiveCard};
+use crate::scenario::Scenario;
 use crate::special_advance::{SpecialAdvance, SpecialAdvanceInfo};
 use crate::status_phase::{
     StatusPhaseStateType, complete_objectives, determine_first_player, draw_cards, free_advance,
@@ -21,12 +22,20 @@ use crate::tactics_card::TacticsCard;
 use crate::wonder::{Wonder, WonderInfo};
 use itertools::Itertools;
 use std::collections::HashMap;
+use std::sync::Arc;
 
+///
+/// All rules content - shared between all clones of a game, so it's cheap to clone.
 #[derive(Clone)]
 pub struct Cache {
+    data: Arc<CacheData>,
+}
+
+struct CacheData {
     all_abilities: Vec<Ability>,
     abilities_by_name: HashMap<String, Ability>,
     status_phase_handlers: HashMap<StatusPhaseStateType, Ability>,
+    draft_handler: Ability,
 
     all_advance_groups: Vec<AdvanceGroupInfo>,
     advance_groups_by_name: HashMap<AdvanceGroup, AdvanceGroupInfo>,
@@ -52,18 +61,38 @@ pub struct Cache {
     civilizations_by_name: HashMap<String, Civilization>,
     all_special_advances: Vec<SpecialAdvanceInfo>,
     leaders: HashMap<Leader, LeaderInfo>,
+
+    all_scenarios: Vec<Scenario>,
 }
 
 impl Cache {
+    ///
+    /// # Panics
+    ///
+    /// Panics if a content pack of the options is not registered or invalid
     #[must_use]
     pub fn new(options: &GameOptions) -> Self {
-        Cache {
+        Self::try_new(options).unwrap_or_else(|e| panic!("{e}"))
+    }
+
+    ///
+    /// Creates the cache with the base content and the content packs of the options.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a content pack is not registered or if its content collides
+    /// with the base game or another pack
+    pub fn try_new(options: &GameOptions) -> Result<Self, String> {
+        let packs = registered_content_packs(&options.content_packs)?;
+        let content = ContentLists::new(&packs)?;
+        let data = CacheData {
             all_abilities: ability::get_all_uncached(),
             abilities_by_name: ability::get_all_uncached()
                 .into_iter()
                 .map(|a| (a.name.clone(), a))
                 .collect(),
             status_phase_handlers: status_phase_handlers(),
+            draft_handler: draft_handler(),
 
             all_advances: advances::get_all_uncached(options)
                 .into_iter()
@@ -91,66 +120,82 @@ impl Cache {
                 })
                 .collect(),
 
-            all_action_cards: action_cards::get_all_uncached(),
-            action_cards_by_id: action_cards::get_all_uncached()
-                .into_iter()
+            action_cards_by_id: content
+                .action_cards
+                .iter()
+                .cloned()
                 .chain(
-                    incidents::get_all_uncached()
-                        .into_iter()
-                        .filter_map(|incident| incident.action_card)
-                        .collect_vec(),
+                    content
+                        .incidents
+                        .iter()
+                        .filter_map(|incident| incident.action_card.clone()),
                 )
                 .map(|action_card| (action_card.id, action_card))
                 .collect(),
+            all_action_cards: content.action_cards,
 
-            all_objective_cards: objective_cards::get_all_uncached(),
-            objective_cards_by_id: objective_cards::get_all_uncached()
-                .into_iter()
-                .map(|objective_card| (objective_card.id, objective_card))
+            objective_cards_by_id: content
+                .objective_cards
+                .iter()
+                .map(|objective_card| (objective_card.id, objective_card.clone()))
                 .collect(),
+            all_objective_cards: content.objective_cards,
 
-            all_objectives: objectives::get_all_uncached(),
-            objectives_by_name: objectives::get_all_uncached()
-                .into_iter()
-                .map(|objective| (objective.name.clone(), objective))
+            objectives_by_name: content
+                .objectives
+                .iter()
+                .map(|objective| (objective.name.clone(), objective.clone()))
                 .collect(),
+            all_objectives: content.objectives,
 
-            all_wonders: wonders::get_all_uncached()
+            all_wonders: content
+                .wonders
                 .into_iter()
                 .sorted_by_key(|w| w.wonder)
                 .collect_vec(),
 
-            all_incidents: incidents::get_all_uncached(),
-            incidents_by_id: incidents::get_all_uncached()
-                .into_iter()
-                .map(|incident| (incident.id, incident))
+            incidents_by_id: content
+                .incidents
+                .iter()
+                .map(|incident| (incident.id, incident.clone()))
                 .collect(),
+            all_incidents: content.incidents,
 
-            all_civilizations: civilizations::get_all_uncached()
-                .into_iter()
-                .filter(Civilization::can_choose)
+            all_civilizations: content
+                .civilizations
+                .iter()
+                .filter(|c| c.can_choose())
+                .cloned()
                 .sorted_by_key(|c| c.name.clone())
                 .collect_vec(),
-            civilizations_by_name: civilizations::get_all_uncached()
-                .into_iter()
-                .map(|c| (c.name.clone(), c))
+            civilizations_by_name: content
+                .civilizations
+                .iter()
+                .map(|c| (c.name.clone(), c.clone()))
                 .collect(),
-            all_special_advances: civilizations::get_all_uncached()
-                .into_iter()
-                .flat_map(|c| c.special_advances)
+            all_special_advances: content
+                .civilizations
+                .iter()
+                .flat_map(|c| c.special_advances.clone())
                 .sorted_by_key(|s| s.advance)
                 .collect(),
-            leaders: civilizations::get_all_uncached()
+            leaders: content
+                .civilizations
                 .into_iter()
                 .flat_map(|c| c.leaders)
                 .map(|l| (l.leader, l))
                 .collect(),
-        }
+
+            all_scenarios: scenarios::get_all_uncached(),
+        };
+        Ok(Cache {
+            data: Arc::new(data),
+        })
     }
 
     #[must_use]
     pub fn get_advances(&self) -> &HashMap<Advance, AdvanceInfo> {
-        &self.all_advances
+        &self.data.all_advances
     }
 
     ///
@@ -158,19 +203,19 @@ impl Cache {
     /// Panics if advance does not exist
     #[must_use]
     pub fn get_advance(&self, a: Advance) -> &AdvanceInfo {
-        self.all_advances.get(&a).unwrap_or_else(|| {
+        self.data.all_advances.get(&a).unwrap_or_else(|| {
             panic!("Advance {a:?} not found in cache");
         })
     }
 
     #[must_use]
     pub fn get_special_advance(&self, a: SpecialAdvance) -> &SpecialAdvanceInfo {
-        &self.all_special_advances[a as usize]
+        &self.data.all_special_advances[a as usize]
     }
 
     #[must_use]
     pub fn get_advance_groups(&self) -> &Vec<AdvanceGroupInfo> {
-        &self.all_advance_groups
+        &self.data.all_advance_groups
     }
 
     ///
@@ -179,14 +224,15 @@ impl Cache {
     /// Panics if advance group doesn't exist
     #[must_use]
     pub fn get_advance_group(&self, name: AdvanceGroup) -> &AdvanceGroupInfo {
-        self.advance_groups_by_name
+        self.data
+            .advance_groups_by_name
             .get(&name)
             .unwrap_or_else(|| panic!("Advance group {name:?} not found"))
     }
 
     #[must_use]
     pub fn get_governments(&self) -> &Vec<AdvanceGroupInfo> {
-        &self.all_governments
+        &self.data.all_governments
     }
 
     ///
@@ -196,7 +242,8 @@ impl Cache {
     /// Panics if government doesn't exist
     #[must_use]
     pub fn get_government(&self, government: &str) -> &AdvanceGroupInfo {
-        self.governments_by_name
+        self.data
+            .governments_by_name
             .get(government)
             .unwrap_or_else(move || {
                 panic!("Government {government} not found");
@@ -205,17 +252,18 @@ impl Cache {
 
     #[must_use]
     pub fn get_building_advance(&self, building: Building) -> Advance {
-        self.advances_by_building[&building]
+        self.data.advances_by_building[&building]
     }
 
     #[must_use]
     pub fn get_abilities(&self) -> &Vec<Ability> {
-        &self.all_abilities
+        &self.data.all_abilities
     }
 
     #[must_use]
     fn with_ability<T>(&self, name: &str, game: &Game, t: impl Fn(&Ability) -> T) -> T {
-        self.abilities_by_name
+        self.data
+            .abilities_by_name
             .get(name)
             .map_or_else(
                 || {
@@ -230,6 +278,9 @@ impl Cache {
                     if let Some(p) = get_status_phase(game) {
                         return Some(t(self.status_phase_handler(&status_phase_type(p))));
                     }
+                    if name == DRAFT {
+                        return Some(t(self.draft_handler()));
+                    }
                     None
                 },
                 |a| Some(t(a)),
@@ -244,12 +295,17 @@ impl Cache {
 
     #[must_use]
     pub fn status_phase_handler(&self, p: &StatusPhaseStateType) -> &Ability {
-        &self.status_phase_handlers[p]
+        &self.data.status_phase_handlers[p]
+    }
+
+    #[must_use]
+    pub fn draft_handler(&self) -> &Ability {
+        &self.data.draft_handler
     }
 
     #[must_use]
     pub fn get_action_cards(&self) -> &Vec<ActionCard> {
-        &self.all_action_cards
+        &self.data.all_action_cards
     }
 
     ///
@@ -258,7 +314,8 @@ impl Cache {
     /// Panics if action card does not exist
     #[must_use]
     pub fn get_action_card(&self, id: u8) -> &ActionCard {
-        self.action_cards_by_id
+        self.data
+            .action_cards_by_id
             .get(&id)
             .expect("incident action card not found")
     }
@@ -284,7 +341,7 @@ impl Cache {
 
     #[must_use]
     pub fn get_objective_cards(&self) -> &Vec<ObjectiveCard> {
-        &self.all_objective_cards
+        &self.data.all_objective_cards
     }
 
     ///
@@ -293,14 +350,15 @@ impl Cache {
     /// Panics if objective card does not exist
     #[must_use]
     pub fn get_objective_card(&self, id: u8) -> &ObjectiveCard {
-        self.objective_cards_by_id
+        self.data
+            .objective_cards_by_id
             .get(&id)
             .unwrap_or_else(|| panic!("objective card not found {id}"))
     }
 
     #[must_use]
     pub fn get_objectives(&self) -> &Vec<Objective> {
-        &self.all_objectives
+        &self.data.all_objectives
     }
 
     ///
@@ -308,24 +366,25 @@ impl Cache {
     /// Panics if incident does not exist
     #[must_use]
     pub fn get_objective(&self, name: &str) -> &Objective {
-        self.objectives_by_name
+        self.data
+            .objectives_by_name
             .get(name)
             .expect("objective not found")
     }
 
     #[must_use]
     pub fn get_wonders(&self) -> &Vec<WonderInfo> {
-        &self.all_wonders
+        &self.data.all_wonders
     }
 
     #[must_use]
     pub fn get_wonder(&self, w: Wonder) -> &WonderInfo {
-        &self.all_wonders[w as usize]
+        &self.data.all_wonders[w as usize]
     }
 
     #[must_use]
     pub fn get_incidents(&self) -> &Vec<Incident> {
-        &self.all_incidents
+        &self.data.all_incidents
     }
 
     ///
@@ -334,12 +393,33 @@ impl Cache {
     /// Panics if incident does not exist
     #[must_use]
     pub fn get_incident(&self, id: u8) -> &Incident {
-        self.incidents_by_id.get(&id).expect("incident not found")
+        self.data
+            .incidents_by_id
+            .get(&id)
+            .expect("incident not found")
+    }
+
+    #[must_use]
+    pub fn get_scenarios(&self) -> &Vec<Scenario> {
+        &self.data.all_scenarios
+    }
+
+    ///
+    /// # Panics
+    ///
+    /// Panics if scenario does not exist
+    #[must_use]
+    pub fn get_scenario(&self, name: &str) -> &Scenario {
+        self.data
+            .all_scenarios
+            .iter()
+            .find(|s| s.name == name)
+            .unwrap_or_else(|| panic!("scenario not found: {name}"))
     }
 
     #[must_use]
     pub fn get_civilizations(&self) -> &Vec<Civilization> {
-        &self.all_civilizations
+        &self.data.all_civilizations
     }
 
     ///
@@ -348,7 +428,8 @@ impl Cache {
     /// Panics if civilization does not exist
     #[must_use]
     pub fn get_civilization(&self, name: &str) -> Civilization {
-        self.civilizations_by_name
+        self.data
+            .civilizations_by_name
             .get(name)
             .cloned()
             .unwrap_or_else(|| panic!("civilization not found: {name}"))
@@ -360,7 +441,8 @@ impl Cache {
     /// Panics if special advance does not exist
     #[must_use]
     pub fn get_leader(&self, leader: &Leader) -> &LeaderInfo {
-        self.leaders
+        self.data
+            .leaders
             .get(leader)
             .unwrap_or_else(|| panic!("leader not found: {leader:?}"))
     }
@@ -3,6 +3,7 @@ use crate::content::action_cards::synergies::validate_new_plans;
 use crate::content::civilizations::rome::validate_princeps_cards;
 use crate::events::{EventOrigin, EventPlayer};
 use crate::game::Game;
+use crate::localization::Message;
 use crate::log::{ActionLogEntry, add_action_log_item};
 use crate::player::Player;
 use crate::utils::Shuffle;
@@ -111,6 +112,8 @@ pub enum HandCardLocation {
     CompleteObjective(String),
     Public,
     GreatSeer(usize),
+    // dealt for the card draft at the start of the game
+    Draft,
 }
 
 impl HandCardLocation {
@@ -131,6 +134,7 @@ impl HandCardLocation {
                 | HandCardLocation::Hand(_)
                 | HandCardLocation::PlayToDiscardFaceDown
                 | HandCardLocation::GreatSeer(_)
+                | HandCardLocation::Draft
         )
     }
 }
@@ -154,13 +158,19 @@ where
         }
 
         if !new_pile.is_empty() {
-            player.log(game, &format!("Reshuffling {name} pile"));
+            player.log_message(
+                game,
+                Message::new("log.reshuffle_pile", "Reshuffling {name} pile").param("name", name),
+            );
             *get_pile(game) = new_pile.shuffled(&mut game.rng);
         }
     }
 
     if get_pile(game).is_empty() {
-        player.log(game, &format!("No {name} left to draw"));
+        player.log_message(
+            game,
+            Message::new("log.empty_pile", "No {name} left to draw").param("name", name),
+        );
         return None;
     }
 
@@ -313,6 +323,7 @@ pub fn hand_card_message(
                 HandCardLocation::Public => &format!("gains {name} from the public area"),
                 HandCardLocation::GreatSeer(_) => &format!("gains {name} from Great Seer"),
                 HandCardLocation::Incident => &format!("gains {name} from the current event"),
+                HandCardLocation::Draft => &format!("drafts {name}"),
                 _ => {
                     panic!(
                         "Cannot transfer card from played to hand: {card:?} from {from:?} to {to:?}"
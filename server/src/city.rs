@@ -23,6 +23,7 @@ use MoodState::*;
 use itertools::Itertools;
 use num::Zero;
 
+#[derive(Clone)]
 pub struct City {
     pub pieces: CityPieces,
     pub mood_state: MoodState,
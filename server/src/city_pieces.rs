@@ -203,6 +203,7 @@ impl CityPiecesData {
     }
 }
 
+#[derive(Clone)]
 pub struct DestroyedStructures {
     pub pieces: CityPieces,
     pub cities: u8,
@@ -215,7 +215,7 @@ pub(crate) fn on_collect(game: &mut Game, player_index: usize, i: CollectInfo) {
     info.info.execute(game);
 }
 
-pub(crate) struct CollectContext {
+pub struct CollectContext {
     pub player_index: usize,
     pub city_position: Position,
     pub terrain_options: HashMap<Terrain, HashSet<ResourcePile>>,
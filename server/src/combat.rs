@@ -13,6 +13,7 @@ use crate::content::ability::combat_event_origin;
 use crate::content::persistent_events::PersistentEventType;
 use crate::events::{EventOrigin, EventPlayer};
 use crate::game::Game;
+use crate::localization::Message;
 use crate::log::{ActionLogEntry, ActionLogEntryCombatRound};
 use crate::movement::{MoveUnits, MovementRestriction, move_units, stop_current_move};
 use crate::position::Position;
@@ -24,6 +25,7 @@ use crate::wonder::{Wonder, gain_wonder, init_wonder, lose_wonder};
 use combat_stats::active_attackers;
 use itertools::Itertools;
 use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug, Copy)]
 pub enum CombatModifier {
@@ -35,6 +37,51 @@ pub enum CombatModifier {
     GreatWarlord,
 }
 
+impl CombatModifier {
+    #[must_use]
+    pub fn role(self) -> CombatRole {
+        match self {
+            CombatModifier::SteelWeaponsDefender => CombatRole::Defender,
+            CombatModifier::CancelFortressExtraDie
+            | CombatModifier::CancelFortressIgnoreHit
+            | CombatModifier::SteelWeaponsAttacker
+            | CombatModifier::TrojanHorse
+            | CombatModifier::GreatWarlord => CombatRole::Attacker,
+        }
+    }
+}
+
+impl CombatModifier {
+    #[must_use]
+    pub fn message(self) -> Message {
+        match self {
+            CombatModifier::CancelFortressExtraDie => Message::new(
+                "combat_modifier.cancel_fortress_extra_die",
+                "Cancel Fortress Extra Die",
+            ),
+            CombatModifier::CancelFortressIgnoreHit => Message::new(
+                "combat_modifier.cancel_fortress_ignore_hit",
+                "Cancel Fortress Ignore Hit",
+            ),
+            CombatModifier::SteelWeaponsAttacker | CombatModifier::SteelWeaponsDefender => {
+                Message::new("combat_modifier.steel_weapons", "Steel Weapons")
+            }
+            CombatModifier::TrojanHorse => {
+                Message::new("combat_modifier.trojan_horse", "Trojan Horse")
+            }
+            CombatModifier::GreatWarlord => {
+                Message::new("combat_modifier.great_warlord", "Great Warlord")
+            }
+        }
+    }
+}
+
+impl Display for CombatModifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message())
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug, Copy)]
 pub enum CombatRetreatState {
     CanRetreat,
@@ -426,8 +473,15 @@ fn take_over_city(
 }
 
 pub(crate) fn capture_position(game: &mut Game, stats: &mut CombatStats) {
+    // the defender's teammate may share the tile
+    let defender = stats.defender.player;
+    for old_player in std::iter::once(defender).chain(game.teammate(defender)) {
+        capture_position_of_player(game, stats, old_player);
+    }
+}
+
+fn capture_position_of_player(game: &mut Game, stats: &mut CombatStats, old_player: usize) {
     let p = &EventPlayer::new(stats.attacker.player, combat_event_origin());
-    let old_player = stats.defender.player;
     let position = stats.defender.position;
     let captured_settlers = game.players[old_player]
         .get_units(position)
@@ -435,13 +489,14 @@ pub(crate) fn capture_position(game: &mut Game, stats: &mut CombatStats) {
         .map(|unit| unit.id)
         .collect_vec();
     if !captured_settlers.is_empty() {
-        p.log(
+        p.log_message(
             game,
-            &format!(
-                "Kill {} settlers of {}",
-                captured_settlers.len(),
-                game.player_name(old_player)
-            ),
+            Message::new(
+                "log.combat.kill_settlers",
+                "Kill {settlers} settlers of {player}",
+            )
+            .param("settlers", captured_settlers.len())
+            .param("player", game.player_name(old_player)),
         );
     }
     kill_units_with_stats(stats, game, old_player, &captured_settlers);
@@ -480,10 +535,13 @@ fn move_to_enemy_player_tile(
             .wonders_owned
             .contains(Wonder::GreatWall)
     {
-        game.log(
+        game.log_message(
             defender,
             &EventOrigin::Wonder(Wonder::GreatWall),
-            "Automatic win against Barbarians",
+            Message::new(
+                "log.combat.automatic_win_against_barbarians",
+                "Automatic win against Barbarians",
+            ),
         );
 
         let mut s = new_combat_stats(
@@ -544,7 +602,13 @@ fn apply_battle_movement_restriction(game: &mut Game, player_index: usize, unit_
             player_index,
             EventOrigin::SpecialAdvance(SpecialAdvance::Longships),
         )
-        .log(game, "Ignore battle movement restrictions");
+        .log_message(
+            game,
+            Message::new(
+                "log.combat.ignore_battle_movement_restrictions",
+                "Ignore battle movement restrictions",
+            ),
+        );
     }
 
     assert!(military, "Need military units to attack");
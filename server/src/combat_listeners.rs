@@ -7,7 +7,8 @@ use crate::content::ability::{Ability, combat_event_origin};
 use crate::content::persistent_events::{PersistentEventType, PositionRequest, UnitsRequest};
 use crate::events::{EventOrigin, EventPlayer};
 use crate::game::Game;
-use crate::log::current_action_log_mut;
+use crate::localization::Message;
+use crate::log::{ActionLogEntry, current_action_log_mut};
 use crate::movement::move_units;
 use crate::player::gain_unit;
 use crate::player_events::{PersistentEvent, PersistentEvents};
@@ -31,7 +32,7 @@ pub struct CombatStrength {
     pub hit_cancels: u8,
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub roll_log: Vec<String>,
+    pub roll_log: Vec<Message>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tactics_card: Option<u8>,
@@ -323,6 +324,25 @@ pub(crate) fn combat_round_end(game: &mut Game, r: CombatRoundEnd) -> Option<Com
 
 fn attacker_wins(game: &mut Game, mut c: Combat) {
     log_winner(game, c.attacker(), None);
+    if let Some(teammate) = game.enemy_player(c.attacker(), c.defender_position())
+        && game
+            .player(teammate)
+            .get_units(c.defender_position())
+            .iter()
+            .any(|u| u.is_military())
+    {
+        // the defender's teammate still holds the position, so no city is captured
+        c.stats.city_mood = None;
+        EventPlayer::new(c.attacker(), combat_event_origin()).log_message(
+            game,
+            Message::new(
+                "log.combat.position_still_defended",
+                "{player} still defends the position",
+            )
+            .param("player", game.player_name(teammate)),
+        );
+        return end_combat_and_store_stats(game, CombatEnd::new(CombatResult::AttackerWins, c));
+    }
     move_units(
         game,
         c.attacker(),
@@ -335,8 +355,10 @@ fn attacker_wins(game: &mut Game, mut c: Combat) {
 }
 
 fn log_winner(game: &mut Game, player_index: usize, origin: Option<EventOrigin>) {
-    EventPlayer::new(player_index, origin.unwrap_or(combat_event_origin()))
-        .log(game, "wins the battle");
+    EventPlayer::new(player_index, origin.unwrap_or(combat_event_origin())).log_message(
+        game,
+        Message::new("log.combat.wins_the_battle", "wins the battle"),
+    );
 }
 
 fn defender_wins(game: &mut Game, c: Combat) {
@@ -353,7 +375,13 @@ pub(crate) fn draw(game: &mut Game, c: Combat) {
         );
         return end_combat_and_store_stats(game, CombatEnd::new(CombatResult::DefenderWins, c));
     }
-    EventPlayer::new(c.attacker(), combat_event_origin()).log(game, "ends the battle in a draw");
+    EventPlayer::new(c.attacker(), combat_event_origin()).log_message(
+        game,
+        Message::new(
+            "log.combat.ends_the_battle_in_a_draw",
+            "ends the battle in a draw",
+        ),
+    );
     end_combat_and_store_stats(game, CombatEnd::new(CombatResult::Draw, c));
 }
 
@@ -510,10 +538,14 @@ fn add_tactics_listener(
             }
         }
         if reveal_card {
-            game.log(
+            game.log_message(
                 combat.player(role),
                 &EventOrigin::Advance(Advance::Tactics),
-                &format!("Reveal Tactics Card {}", card.name),
+                Message::new(
+                    "log.combat.reveal_tactics_card",
+                    "Reveal Tactics Card {card}",
+                )
+                .param("card", &card.name),
             );
         }
     }
@@ -539,7 +571,11 @@ pub(crate) fn choose_fighter_casualties() -> Ability {
                 }
                 let p = game.player(player.index);
                 if casualties == choices.len() as u8 {
-                    player.log(game, &format!("Remove all {role_str} units",));
+                    player.log_message(
+                        game,
+                        Message::new("log.combat.remove_all_units", "Remove all {role} units")
+                            .param("role", role_str),
+                    );
                     let player1 = player.index;
                     let c = &mut r.combat;
                     kill_combat_units(game, c, player1, &choices);
@@ -552,9 +588,14 @@ pub(crate) fn choose_fighter_casualties() -> Ability {
                     .all(|u| p.get_unit(*u).unit_type == first_type)
                     || !p.is_human()
                 {
-                    player.log(
+                    player.log_message(
                         game,
-                        &format!("Remove {casualties} of their {role_str} units",),
+                        Message::new(
+                            "log.combat.remove_units",
+                            "Remove {casualties} of their {role} units",
+                        )
+                        .param("casualties", casualties)
+                        .param("role", role_str),
                     );
                     let player1 = player.index;
                     let killed_unit_ids = &choices[..casualties as usize];
@@ -563,9 +604,14 @@ pub(crate) fn choose_fighter_casualties() -> Ability {
                     return None;
                 }
 
-                player.log(
+                player.log_message(
                     game,
-                    &format!("Remove {casualties} of their {role_str} units",),
+                    Message::new(
+                        "log.combat.remove_units",
+                        "Remove {casualties} of their {role} units",
+                    )
+                    .param("casualties", casualties)
+                    .param("role", role_str),
                 );
                 Some(UnitsRequest::new(
                     player.index,
@@ -594,18 +640,19 @@ pub(crate) fn offer_retreat() -> Ability {
                 let c = &r.combat;
                 if c.attacker() == player && r.can_retreat() {
                     let name = game.player_name(player);
-                    p.log(game, &format!("{name} can retreat",));
+                    p.log_message(
+                        game,
+                        Message::new("log.combat.can_retreat", "{player} can retreat")
+                            .param("player", name),
+                    );
                     Some("Do you want to retreat?".to_string())
                 } else {
                     None
                 }
             },
             |game, s, e| {
-                if s.choice {
-                    s.log(game, "Retreat");
-                } else {
-                    s.log(game, "Do not retreat");
-                }
+                s.player()
+                    .add_log_entry(game, ActionLogEntry::CombatRetreat { retreat: s.choice });
                 if s.choice {
                     e.combat.retreat = CombatRetreatState::EndAfterCurrentRound;
                 }
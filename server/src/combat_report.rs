@@ -0,0 +1,255 @@
+use crate::advance::Advance;
+use crate::card::{HandCard, HandCardLocation};
+use crate::combat_roll::UnitCombatRoll;
+use crate::combat_stats::CombatStats;
+use crate::content::ability::combat_event_origin;
+use crate::events::EventOrigin;
+use crate::game::Game;
+use crate::localization::Message;
+use crate::log::{
+    ActionLogAction, ActionLogBalance, ActionLogEntry, ActionLogEntryCombatRoll,
+    ActionLogEntryCombatRound, ActionLogItem,
+};
+use crate::tactics_card::CombatRole;
+use crate::unit::Units;
+use num::Zero;
+use serde::{Deserialize, Serialize};
+
+///
+/// Everything that happened in one battle - collected from the action log,
+/// so it's available for finished as well as ongoing battles.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct CombatReport {
+    pub attacker: usize,
+    pub defender: usize,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rounds: Vec<CombatReportRound>,
+    // only present when the battle is over (unless the attacker retreated)
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<CombatStats>,
+}
+
+impl CombatReport {
+    #[must_use]
+    pub fn side(&self, round: usize, role: CombatRole) -> &CombatReportSide {
+        let r = &self.rounds[round];
+        if role.is_attacker() {
+            &r.attacker
+        } else {
+            &r.defender
+        }
+    }
+
+    fn side_mut(&mut self, player: usize) -> Option<&mut CombatReportSide> {
+        let r = self.rounds.last_mut()?;
+        if player == self.attacker {
+            Some(&mut r.attacker)
+        } else if player == self.defender {
+            Some(&mut r.defender)
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct CombatReportRound {
+    pub round: u32,
+    pub attacker: CombatReportSide,
+    pub defender: CombatReportSide,
+    // the attacker's decision after the round (if they were allowed to retreat)
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retreat: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct CombatReportSide {
+    pub player: usize,
+    pub units: Units,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tactics_card: Option<u8>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rolls: Vec<UnitCombatRoll>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "u8::is_zero")]
+    pub combat_value: u8,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "u8::is_zero")]
+    pub hits: u8,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "u8::is_zero")]
+    pub hit_cancels: u8,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub modifiers: Vec<Message>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Units::is_empty")]
+    pub casualties: Units,
+    // log messages of abilities and tactics cards that were used in the round
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub notes: Vec<CombatReportNote>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct CombatReportNote {
+    pub origin: EventOrigin,
+    pub message: Message,
+}
+
+impl CombatReportSide {
+    fn new(player: usize, units: Units) -> Self {
+        Self {
+            player,
+            units,
+            tactics_card: None,
+            rolls: Vec::new(),
+            combat_value: 0,
+            hits: 0,
+            hit_cancels: 0,
+            modifiers: Vec::new(),
+            casualties: Units::empty(),
+            notes: Vec::new(),
+        }
+    }
+
+    fn add_roll(&mut self, r: &ActionLogEntryCombatRoll) {
+        self.rolls.clone_from(&r.rolls);
+        self.combat_value = r.combat_value;
+        self.hits = r.hits;
+        self.hit_cancels = r.hit_cancels;
+        self.modifiers.extend(r.combat_modifiers.iter().cloned());
+        self.modifiers
+            .extend(r.modifiers.iter().map(|m| m.message()));
+    }
+}
+
+///
+/// Returns the reports of all battles in the game, the last one may still be ongoing.
+#[must_use]
+pub fn combat_reports(game: &Game) -> Vec<CombatReport> {
+    let mut reports = vec![];
+    let mut current: Option<CombatReport> = None;
+    for action in game
+        .log
+        .iter()
+        .flat_map(|age| age.rounds.iter())
+        .flat_map(|round| round.turns.iter())
+        .flat_map(|turn| turn.actions.iter())
+    {
+        add_action(action, &mut current, &mut reports);
+    }
+    reports.extend(current);
+    reports
+}
+
+fn add_action(
+    action: &ActionLogAction,
+    current: &mut Option<CombatReport>,
+    reports: &mut Vec<CombatReport>,
+) {
+    for item in &action.items {
+        if let ActionLogEntry::CombatRound(r) = &item.entry {
+            add_round(item.player, r, current, reports);
+            continue;
+        }
+        if let Some(report) = current.as_mut()
+            && add_item(item, report)
+        {
+            // the attacker retreated - there are no combat stats in this case
+            reports.extend(current.take());
+        }
+    }
+    if let Some(stats) = &action.combat_stats
+        && let Some(mut report) = current.take()
+    {
+        report.stats = Some(stats.clone());
+        reports.push(report);
+    }
+}
+
+fn add_round(
+    attacker: usize,
+    r: &ActionLogEntryCombatRound,
+    current: &mut Option<CombatReport>,
+    reports: &mut Vec<CombatReport>,
+) {
+    if r.round == 1 {
+        reports.extend(current.take());
+    }
+    let report = current.get_or_insert_with(|| CombatReport {
+        attacker,
+        defender: r.defending_player,
+        rounds: vec![],
+        stats: None,
+    });
+    report.rounds.push(CombatReportRound {
+        round: r.round,
+        attacker: CombatReportSide::new(attacker, r.attackers.clone()),
+        defender: CombatReportSide::new(r.defending_player, r.defenders.clone()),
+        retreat: None,
+    });
+}
+
+// returns true if the attacker retreated
+fn add_item(item: &ActionLogItem, report: &mut CombatReport) -> bool {
+    if let Some(retreat) = retreat_decision(item) {
+        if let Some(r) = report.rounds.last_mut() {
+            r.retreat = Some(retreat);
+        }
+        return retreat;
+    }
+
+    let Some(side) = report.side_mut(item.player) else {
+        return false;
+    };
+    match &item.entry {
+        ActionLogEntry::CombatRoll(r) => side.add_roll(r),
+        ActionLogEntry::HandCard {
+            card: HandCard::ActionCard(id),
+            to: HandCardLocation::PlayToDiscardFaceDown,
+            ..
+        } if item.origin == EventOrigin::Advance(Advance::Tactics) && *id != 0 => {
+            side.tactics_card = Some(*id);
+        }
+        ActionLogEntry::Units {
+            units,
+            balance: ActionLogBalance::Loss,
+            ..
+        } if item.origin == combat_event_origin() => {
+            for unit_type in units.clone().to_vec() {
+                side.casualties += &unit_type;
+            }
+        }
+        ActionLogEntry::Message(message) => {
+            side.notes.push(CombatReportNote {
+                origin: item.origin.clone(),
+                message: message.clone(),
+            });
+        }
+        _ => {}
+    }
+    false
+}
+
+fn retreat_decision(item: &ActionLogItem) -> Option<bool> {
+    match &item.entry {
+        ActionLogEntry::CombatRetreat { retreat } => Some(*retreat),
+        // archived games logged the decision as text
+        ActionLogEntry::Message(m)
+            if item.origin == EventOrigin::Ability("Retreat".to_string()) =>
+        {
+            match m.key.as_str() {
+                "Retreat" => Some(true),
+                "Do not retreat" => Some(false),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
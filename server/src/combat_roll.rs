@@ -1,4 +1,4 @@
-use crate::combat::Combat;
+use crate::combat::{Combat, CombatModifier};
 use crate::combat_listeners::CombatStrength;
 use crate::content::ability::combat_event_origin;
 use crate::events::EventPlayer;
@@ -70,6 +70,7 @@ pub(crate) struct CombatRoundStats {
     rolls: Vec<UnitCombatRoll>,
     combat_value: u8,
     hit_cancels: u8,
+    modifiers: Vec<CombatModifier>,
     strength: CombatStrength,
 }
 
@@ -93,6 +94,13 @@ impl CombatRoundStats {
         );
         let combat_value = rolls.combat_value as u8;
         let hit_cancels = rolls.hit_cancels + strength.hit_cancels;
+        let role = c.role(player);
+        let modifiers = c
+            .modifiers
+            .iter()
+            .filter(|m| m.role() == role)
+            .copied()
+            .collect();
 
         CombatRoundStats {
             strength,
@@ -100,6 +108,7 @@ impl CombatRoundStats {
             rolls: unit_rolls,
             combat_value,
             hit_cancels,
+            modifiers,
             fighters: fighting.len() as u8,
         }
     }
@@ -123,7 +132,9 @@ impl CombatRoundStats {
                 rolls: self.rolls.clone(),
                 combat_value: self.combat_value,
                 hits: combat_hits.hits(),
+                hit_cancels: self.hit_cancels,
                 combat_modifiers: self.strength.roll_log.clone(),
+                modifiers: self.modifiers.clone(),
             }),
         );
         combat_hits
@@ -1,6 +1,7 @@
 pub mod ability;
 pub mod action_cards;
 pub mod advances;
+pub mod catalog;
 pub mod civilizations;
 pub mod custom_actions;
 pub mod effects;
@@ -8,5 +9,6 @@ pub mod incidents;
 pub mod objective_cards;
 pub mod objectives;
 pub mod persistent_events;
+pub mod scenarios;
 pub mod tactics_cards;
 pub mod wonders;
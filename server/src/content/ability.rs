@@ -27,6 +27,8 @@ use crate::game::Game;
 use crate::objective_card::select_objectives;
 use crate::pirates::{pirates_bonus, pirates_round_bonus};
 use crate::playing_actions::pay_for_action;
+use crate::team::team_trade;
+use crate::trade::use_trade;
 use crate::unit::choose_carried_units_to_remove;
 use crate::wonder::{build_wonder_handler, draw_wonder_card_handler, use_draw_replacement_wonder};
 
@@ -128,6 +130,10 @@ pub fn get_all_uncached() -> Vec<Ability> {
         use_draw_replacement_wonder(),
         // civilization related
         lose_raid_resource(),
+        // team play related
+        team_trade(),
+        // trading related
+        use_trade(),
     ]
 }
 
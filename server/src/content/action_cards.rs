@@ -13,8 +13,12 @@ use crate::content::action_cards::synergies::synergies_action_cards;
 use development::development_action_cards;
 use itertools::Itertools;
 
+///
+/// # Panics
+///
+/// Panics if action card ids are not unique
 #[must_use]
-pub(crate) fn get_all_uncached() -> Vec<ActionCard> {
+pub fn get_all_uncached() -> Vec<ActionCard> {
     let all = vec![
         inspiration_action_cards(),
         development_action_cards(),
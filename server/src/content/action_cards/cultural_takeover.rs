@@ -10,6 +10,7 @@ use crate::cultural_influence::{
     InfluenceCultureAttemptInfo, available_influence_actions, influence_culture_boost_cost,
 };
 use crate::game::Game;
+use crate::localization::Message;
 use crate::player::{Player, gain_unit, remove_unit};
 use crate::playing_actions::PlayingActionType;
 use crate::structure::Structure;
@@ -82,7 +83,13 @@ pub(crate) fn cultural_takeover(id: u8, tactics_card: TacticsCardFactory) -> Act
                 // skip this the second time where we only select a unit type to add
                 game.permanent_effects
                     .push(PermanentEffect::CulturalTakeover);
-                p.log(game, "You may influence Barbarian cities of size 1.");
+                p.log_message(
+                    game,
+                    Message::new(
+                        "log.action_card.you_may_influence_barbarian_cities_of",
+                        "You may influence Barbarian cities of size 1.",
+                    ),
+                );
             }
         },
     )
@@ -16,6 +16,7 @@ use crate::content::tactics_cards::{
     improved_defenses, peltasts, tactical_retreat,
 };
 use crate::game::Game;
+use crate::localization::Message;
 use crate::player::{Player, gain_unit};
 use crate::playing_actions::PlayingActionType;
 use crate::unit::UnitType;
@@ -52,10 +53,13 @@ fn city_development(id: u8, tactics_card: TacticsCardFactory) -> ActionCard {
             game.permanent_effects
                 .push(PermanentEffect::Construct(ConstructEffect::CityDevelopment));
             gain_action(game, p); // to offset the action spent for building
-            p.log(
+            p.log_message(
                 game,
-                "You may build a building in a city without \
+                Message::new(
+                    "log.action_card.you_may_build_a_building_in",
+                    "You may build a building in a city without \
                 spending an action and without paying resources.",
+                ),
             );
         },
     )
@@ -78,9 +82,12 @@ fn production_focus(id: u8, tactics_card: TacticsCardFactory) -> ActionCard {
         |game, p, _| {
             game.permanent_effects
                 .push(PermanentEffect::Collect(CollectEffect::ProductionFocus));
-            p.log(
+            p.log_message(
                 game,
-                "Production Focus: You may collect multiple times from the same tile.",
+                Message::new(
+                    "log.action_card.production_focus_you_may_collect_multiple",
+                    "Production Focus: You may collect multiple times from the same tile.",
+                ),
             );
         },
     )
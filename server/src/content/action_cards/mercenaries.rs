@@ -8,6 +8,7 @@ use crate::content::civilizations::rome::owner_of_sulla_in_range;
 use crate::content::persistent_events::{PaymentRequest, PositionRequest};
 use crate::content::tactics_cards::TacticsCardFactory;
 use crate::game::Game;
+use crate::localization::Message;
 use crate::log::ActionLogEntry;
 use crate::movement::MoveUnits;
 use crate::player::Player;
@@ -39,7 +40,7 @@ pub(crate) fn mercenaries(id: u8, tactics_card: TacticsCardFactory) -> ActionCar
         |game, p, _| {
             let (r, log) = barbarian_army_positions_in_range2(game, p.get(game));
             for l in log {
-                p.log(game, &l);
+                p.log_message(game, l);
             }
             if r.is_empty() {
                 return None;
@@ -52,10 +53,14 @@ pub(crate) fn mercenaries(id: u8, tactics_card: TacticsCardFactory) -> ActionCar
             ))
         },
         |game, s, a| {
-            s.log(
+            s.log_message(
                 game,
-                &format!(
-                    "Selected Barbarian armies to move: {}",
+                Message::new(
+                    "log.mercenaries.select_armies",
+                    "Selected Barbarian armies to move: {positions}",
+                )
+                .param(
+                    "positions",
                     s.choice.iter().map(ToString::to_string).join(", "),
                 ),
             );
@@ -112,7 +117,14 @@ fn move_army(b: ActionCardBuilder, i: i32) -> ActionCardBuilder {
             },
             |game, s, a| {
                 let pos = s.choice[0];
-                s.log(game, &format!("Selected Barbarian army to move: {pos}",));
+                s.log_message(
+                    game,
+                    Message::new(
+                        "log.mercenaries.select_army",
+                        "Selected Barbarian army to move: {position}",
+                    )
+                    .param("position", pos),
+                );
                 remove_element(&mut a.selected_positions, &pos);
                 a.selected_position = Some(pos);
             },
@@ -142,9 +154,13 @@ fn move_army(b: ActionCardBuilder, i: i32) -> ActionCardBuilder {
             },
             |game, s, a| {
                 let to = s.choice[0];
-                s.log(
+                s.log_message(
                     game,
-                    &format!("Selected destination for Barbarian army: {to}",),
+                    Message::new(
+                        "log.mercenaries.select_destination",
+                        "Selected destination for Barbarian army: {position}",
+                    )
+                    .param("position", to),
                 );
 
                 let from = a.selected_position.expect("position not found");
@@ -177,9 +193,9 @@ fn move_army(b: ActionCardBuilder, i: i32) -> ActionCardBuilder {
 fn barbarian_army_positions_in_range2(
     game: &Game,
     player: &Player,
-) -> (Vec<Position>, Vec<String>) {
+) -> (Vec<Position>, Vec<Message>) {
     let my = player_positions(player);
-    let mut log = Vec::new();
+    let mut blocked = Vec::new();
 
     let positions = get_barbarians_player(game)
         .units
@@ -193,10 +209,7 @@ fn barbarian_army_positions_in_range2(
                 if owner_of_sulla_in_range(p, game)
                     .is_some_and(|sulla_owner| player.index != sulla_owner)
                 {
-                    log.push(format!(
-                        "{} cannot move Barbarian army at {p} because Sulla is in range",
-                        player.get_name()
-                    ));
+                    blocked.push(p);
                     return false;
                 }
                 true
@@ -204,8 +217,19 @@ fn barbarian_army_positions_in_range2(
         })
         .collect();
 
-    log.sort();
-    log.dedup();
+    blocked.sort();
+    blocked.dedup();
+    let log = blocked
+        .into_iter()
+        .map(|p| {
+            Message::new(
+                "log.action_card.sulla_in_range",
+                "{player} cannot move Barbarian army at {position} because Sulla is in range",
+            )
+            .param("player", player.get_name())
+            .param("position", p)
+        })
+        .collect();
 
     (positions, log)
 }
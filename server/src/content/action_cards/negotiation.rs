@@ -10,6 +10,7 @@ use crate::content::tactics_cards::{
     TacticsCardFactory, archers, defensive_formation, encircled, high_ground, martyr, scout,
 };
 use crate::game::Game;
+use crate::localization::Message;
 use crate::log::{add_start_turn_action_if_needed, current_turn_log_without_redo};
 use crate::playing_actions::PlayingAction;
 use crate::utils::remove_element_by;
@@ -64,9 +65,13 @@ fn negotiations(id: u8, tactics_card: TacticsCardFactory) -> ActionCard {
                     relations: DiplomaticRelations::new(s.player_index, s.choice),
                     remaining_turns: 2,
                 }));
-            s.log(
+            s.log_message(
                 game,
-                &format!("Started negotiations with {}", game.player_name(s.choice)),
+                Message::new(
+                    "log.negotiations.start",
+                    "Started negotiations with {player}",
+                )
+                .param("player", game.player_name(s.choice)),
             );
         },
     )
@@ -98,11 +103,25 @@ pub(crate) fn use_negotiations() -> Ability {
                     add_start_turn_action_if_needed(game, p.index);
                     // must be in reverse order to not mess up the indices during deletion
                     for i in delete.iter().rev() {
-                        p.log(game, &format!("May attack {partner_name} again.",));
+                        p.log_message(
+                            game,
+                            Message::new(
+                                "log.negotiations.may_attack",
+                                "May attack {player} again.",
+                            )
+                            .param("player", &partner_name),
+                        );
                         game.permanent_effects.remove(*i);
                     }
                     for _ in remain {
-                        p.log(game, &format!("May not attack {partner_name} this turn.",));
+                        p.log_message(
+                            game,
+                            Message::new(
+                                "log.negotiations.may_not_attack",
+                                "May not attack {player} this turn.",
+                            )
+                            .param("player", &partner_name),
+                        );
                     }
                 }
             },
@@ -161,9 +180,10 @@ fn assassination(id: u8, tactics_card: TacticsCardFactory) -> ActionCard {
         |game, s, _| {
             game.permanent_effects
                 .push(PermanentEffect::AssassinationLoseAction(s.choice));
-            s.log(
+            s.log_message(
                 game,
-                &format!("Assassinated {}", game.player_name(s.choice),),
+                Message::new("log.assassination", "Assassinated {player}")
+                    .param("player", game.player_name(s.choice)),
             );
         },
     )
@@ -223,9 +243,12 @@ fn mass_production(id: u8, tactics_card: TacticsCardFactory) -> ActionCard {
         |game, p, _| {
             game.permanent_effects
                 .push(PermanentEffect::Collect(CollectEffect::MassProduction));
-            p.log(
+            p.log_message(
                 game,
-                "Can use Mass Production to collect from 2 additional tiles.",
+                Message::new(
+                    "log.action_card.can_use_mass_production_to_collect",
+                    "Can use Mass Production to collect from 2 additional tiles.",
+                ),
             );
         },
     )
@@ -5,6 +5,7 @@ use crate::content::persistent_events::{HandCardsRequest, PlayerRequest};
 use crate::content::tactics_cards::TacticsCardFactory;
 use crate::events::EventPlayer;
 use crate::game::Game;
+use crate::localization::Message;
 use crate::objective_card::{deinit_objective_card, init_objective_card};
 use crate::player::Player;
 use crate::utils::remove_element;
@@ -32,12 +33,13 @@ pub(crate) fn spy(id: u8, tactics_card: TacticsCardFactory) -> ActionCard {
         },
         |game, s, a| {
             let p = s.choice;
-            s.log(
+            s.log_message(
                 game,
-                &format!(
-                    "Decided to looked at all Wonder, Action, and Objective cards of {}",
-                    game.player_name(p)
-                ),
+                Message::new(
+                    "log.spy.look",
+                    "Decided to looked at all Wonder, Action, and Objective cards of {player}",
+                )
+                .param("player", game.player_name(p)),
             );
             a.selected_player = Some(p);
         },
@@ -101,7 +103,13 @@ fn swap_spy_cards(
     other: usize,
 ) -> Result<(), String> {
     if swap.is_empty() {
-        player.log(game, "Decided not to swap a card");
+        player.log_message(
+            game,
+            Message::new(
+                "log.action_card.decided_not_to_swap_a_card",
+                "Decided not to swap a card",
+            ),
+        );
         return Ok(());
     }
 
@@ -167,12 +175,14 @@ fn swap_spy_cards(
             "objective"
         }
     };
-    player.log(
+    player.log_message(
         game,
-        &format!(
-            "Decided to swap an {t} card with {}",
-            game.player_name(other)
-        ),
+        Message::new(
+            "log.spy.swap",
+            "Decided to swap an {card_type} card with {player}",
+        )
+        .param("card_type", t)
+        .param("player", game.player_name(other)),
     );
 
     Ok(())
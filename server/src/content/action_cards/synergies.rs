@@ -13,6 +13,7 @@ use crate::content::tactics_cards::{
     wedge_formation,
 };
 use crate::game::Game;
+use crate::localization::Message;
 use crate::objective_card::{
     discard_objective_card, draw_objective_card_from_pile, gain_objective_card,
 };
@@ -196,9 +197,13 @@ fn synergies(id: u8, tactics_card: TacticsCardFactory) -> ActionCard {
         },
         |game, s, i| {
             let advance = &s.choice;
-            s.log(
+            s.log_message(
                 game,
-                &format!("Selected {} as first advance", advance.name(game)),
+                Message::new(
+                    "log.synergies.first_advance",
+                    "Selected {advance} as first advance",
+                )
+                .param("advance", advance.name(game)),
             );
             i.selected_advance = Some(*advance);
         },
@@ -224,9 +229,13 @@ fn synergies(id: u8, tactics_card: TacticsCardFactory) -> ActionCard {
         },
         |game, s, i| {
             let advance = &s.choice;
-            s.log(
+            s.log_message(
                 game,
-                &format!("Selected {} as second advance", advance.name(game)),
+                Message::new(
+                    "log.synergies.second_advance",
+                    "Selected {advance} as second advance",
+                )
+                .param("advance", advance.name(game)),
             );
             i.selected_advance = Some(*advance);
         },
@@ -387,8 +396,9 @@ fn militia(id: u8, tactics_card: TacticsCardFactory) -> ActionCard {
         "Militia",
         "Gain 1 infantry in one of your cities.",
         |c| c.free_action().culture_tokens(1),
-        |_game, player, _a| {
-            player.available_units().infantry > 0 && !cities_that_can_add_units(player).is_empty()
+        |game, player, _a| {
+            player.available_units().infantry > 0
+                && !cities_that_can_add_units(game, player).is_empty()
         },
     )
     .tactics_card(tactics_card)
@@ -397,7 +407,7 @@ fn militia(id: u8, tactics_card: TacticsCardFactory) -> ActionCard {
         0,
         |game, p, _| {
             let player = p.get(game);
-            let cities = cities_that_can_add_units(player);
+            let cities = cities_that_can_add_units(game, player);
             Some(PositionRequest::new(
                 cities,
                 1..=1,
@@ -445,9 +455,13 @@ fn tech_trade(id: u8, tactics_card: TacticsCardFactory) -> ActionCard {
         },
         |game, s, a| {
             let p = s.choice;
-            s.log(
+            s.log_message(
                 game,
-                &format!("Selected {} as trade partner", game.player_name(p)),
+                Message::new(
+                    "log.select_trade_partner",
+                    "Selected {player} as trade partner",
+                )
+                .param("player", game.player_name(p)),
             );
             a.selected_player = Some(p);
         },
@@ -500,9 +514,13 @@ fn new_ideas(id: u8, tactics_card: TacticsCardFactory) -> ActionCard {
         },
         |game, s, i| {
             let advance = &s.choice;
-            s.log(
+            s.log_message(
                 game,
-                &format!("Selected {} as advance for New Ideas.", advance.name(game)),
+                Message::new(
+                    "log.new_ideas.advance",
+                    "Selected {advance} as advance for New Ideas.",
+                )
+                .param("advance", advance.name(game)),
             );
             i.selected_advance = Some(*advance);
         },
@@ -5,6 +5,7 @@ use crate::collect::{CollectContext, CollectInfo};
 use crate::content::advances::{AdvanceGroup, AdvanceGroupInfo, advance_group_builder};
 use crate::events::EventPlayer;
 use crate::game::{Game, GameOptions};
+use crate::localization::Message;
 use crate::map::Terrain::Barren;
 use crate::resource_pile::ResourcePile;
 use std::collections::HashSet;
@@ -84,7 +85,11 @@ fn husbandry_collect(i: &mut CollectInfo, c: &CollectContext, game: &Game, p: &E
 
     i.info.add_log(
         p,
-        &format!("Can collect {allowed} resources from 2 land spaces away"),
+        Message::new(
+            "log.advance.collect_range2",
+            "Can collect {allowed} resources from 2 land spaces away",
+        )
+        .param("allowed", allowed),
     );
 
     game.map
@@ -6,6 +6,7 @@ use crate::content::advances::{AdvanceGroup, AdvanceGroupInfo, advance_group_bui
 use crate::content::custom_actions::CustomActionType::{AbsolutePower, ForcedLabor};
 use crate::content::persistent_events::ResourceRewardRequest;
 use crate::game::GameOptions;
+use crate::localization::Message;
 use crate::player::Player;
 use crate::resource_pile::ResourcePile;
 
@@ -116,7 +117,13 @@ fn forced_labor() -> AdvanceBuilder {
                 |event| &mut event.custom_action,
                 0,
                 |game, p, _| {
-                    p.log(game, "Treating Angry cities as neutral");
+                    p.log_message(
+                        game,
+                        Message::new(
+                            "log.advance.treating_angry_cities_as_neutral",
+                            "Treating Angry cities as neutral",
+                        ),
+                    );
                 },
             )
         },
@@ -4,6 +4,7 @@ use crate::advance::{Advance, AdvanceBuilder, AdvanceInfo};
 use crate::content::advances::{AdvanceGroup, AdvanceGroupInfo, advance_group_builder};
 use crate::content::custom_actions::PlayingActionModifier;
 use crate::game::GameOptions;
+use crate::localization::Message;
 use crate::payment::PaymentConversion;
 use crate::playing_actions::PlayingActionType;
 use crate::resource_pile::ResourcePile;
@@ -56,8 +57,13 @@ fn sanitation() -> AdvanceBuilder {
         |cost, units, _, p| {
             if units.settlers > 0 {
                 // insert at beginning so that it's preferred over gold
-                cost.info
-                    .add_log(p, "Reduce the cost of 1 Settler to 1 mood token");
+                cost.info.add_log(
+                    p,
+                    Message::new(
+                        "log.advance.reduce_the_cost_of_1_settler",
+                        "Reduce the cost of 1 Settler to 1 mood token",
+                    ),
+                );
 
                 cost.cost.conversions.insert(
                     0,
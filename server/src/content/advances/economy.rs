@@ -169,8 +169,8 @@ where
             let (_, routes) = trade_route_reward(game, &s.player()).expect("No trade route reward");
             let log = trade_route_log(game, s.player_index, &routes, s.actively_selected);
 
-            for l in &log {
-                s.log(game, l);
+            for l in log {
+                s.log_message(game, l);
             }
             let p = game.player(s.player_index);
             if p.has_special_advance(SpecialAdvance::Raiding) {
@@ -9,6 +9,7 @@ use crate::content::advances::{AdvanceGroup, AdvanceGroupInfo, advance_group_bui
 use crate::content::custom_actions::PlayingActionModifier;
 use crate::content::persistent_events::PaymentRequest;
 use crate::game::GameOptions;
+use crate::localization::Message;
 use crate::log::{ActionLogEntry, current_turn_log_mut};
 use crate::objective_card::gain_objective_card_from_pile;
 use crate::playing_actions::PlayingActionType;
@@ -107,7 +108,8 @@ fn public_education() -> AdvanceBuilder {
                     |i| &mut i.info.info,
                     |i, (), (), p| {
                         i.total += ResourcePile::ideas(1);
-                        i.info.add_log(p, "Gain 1 idea");
+                        i.info
+                            .add_log(p, Message::new("log.advance.gain_1_idea", "Gain 1 idea"));
                     },
                 );
             }
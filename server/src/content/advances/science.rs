@@ -7,6 +7,7 @@ use crate::content::ability::Ability;
 use crate::content::advances::{AdvanceGroup, AdvanceGroupInfo, advance_group_builder};
 use crate::content::persistent_events::ResourceRewardRequest;
 use crate::game::GameOptions;
+use crate::localization::Message;
 use crate::log::ActionLogBalance;
 use crate::resource::ResourceType;
 use crate::resource_pile::ResourcePile;
@@ -47,7 +48,13 @@ pub fn use_observatory() -> Ability {
             |game, p, b| {
                 if b.building == Building::Observatory {
                     gain_action_card_from_pile(game, p);
-                    p.log(game, "Observatory gained 1 action card");
+                    p.log_message(
+                        game,
+                        Message::new(
+                            "log.advance.observatory_gained_1_action_card",
+                            "Observatory gained 1 action card",
+                        ),
+                    );
                 }
             },
         )
@@ -121,7 +128,13 @@ fn metallurgy() -> AdvanceBuilder {
                     .lose_resources(game, ResourcePile::ore(1), ActionLogBalance::Pay);
                 s.player().gain_resources(game, ResourcePile::gold(1));
             } else {
-                s.log(game, "Did not convert ore to gold");
+                s.log_message(
+                    game,
+                    Message::new(
+                        "log.advance.did_not_convert_ore_to_gold",
+                        "Did not convert ore to gold",
+                    ),
+                );
             }
         },
     )
@@ -5,6 +5,7 @@ use crate::city_pieces::Building::Port;
 use crate::content::ability::Ability;
 use crate::content::advances::{AdvanceGroup, AdvanceGroupInfo, advance_group_builder};
 use crate::game::{Game, GameOptions};
+use crate::localization::Message;
 use crate::position::Position;
 use crate::resource_pile::ResourcePile;
 use std::collections::HashSet;
@@ -47,8 +48,10 @@ fn war_ships() -> AdvanceBuilder {
     .add_combat_strength_listener(5, |game, c, s, role| {
         if c.first_round() && (c.is_disembarking_attacker(role, game) || c.is_sea_battle(game)) {
             s.hit_cancels += 1;
-            s.roll_log
-                .push("WarShips ignore the first hit in the first round of combat".to_string());
+            s.roll_log.push(Message::new(
+                "log.combat_value.warships_ignore_the_first_hit_in",
+                "WarShips ignore the first hit in the first round of combat",
+            ));
         }
     })
 }
@@ -6,6 +6,7 @@ use crate::content::ability::Ability;
 use crate::content::advances::{AdvanceGroup, AdvanceGroupInfo, advance_group_builder};
 use crate::content::persistent_events::ResourceRewardRequest;
 use crate::game::GameOptions;
+use crate::localization::Message;
 use crate::payment::PaymentConversion;
 use crate::resource::ResourceType;
 use crate::resource_pile::ResourcePile;
@@ -67,7 +68,13 @@ fn rituals() -> AdvanceBuilder {
                 ResourceType::Ideas,
                 ResourceType::Gold,
             ] {
-                cost.info.add_log(p, "Can pay with any resource");
+                cost.info.add_log(
+                    p,
+                    Message::new(
+                        "log.advance.can_pay_with_any_resource",
+                        "Can pay with any resource",
+                    ),
+                );
                 cost.cost.conversions.push(PaymentConversion::unlimited(
                     ResourcePile::mood_tokens(1),
                     ResourcePile::of(*r, 1),
@@ -133,7 +140,13 @@ fn state_religion() -> AdvanceBuilder {
                             ResourcePile::empty(),
                             1,
                         ));
-                        i.info.add_log(p, "Reduce the food cost to 0");
+                        i.info.add_log(
+                            p,
+                            Message::new(
+                                "log.advance.reduce_the_food_cost_to_0",
+                                "Reduce the food cost to 0",
+                            ),
+                        );
                     },
                 );
             }
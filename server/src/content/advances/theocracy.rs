@@ -4,7 +4,8 @@ use crate::city_pieces::Building::Temple;
 use crate::construct::ConstructAdvanceBonus;
 use crate::content::advances::{AdvanceGroup, AdvanceGroupInfo, advance_group_builder};
 use crate::content::persistent_events::{AdvanceRequest, PositionRequest};
-use crate::game::GameOptions;
+use crate::game::{Game, GameOptions};
+use crate::localization::Message;
 use crate::player::{Player, can_add_army_unit, gain_unit};
 use crate::position::Position;
 use crate::resource::apply_resource_limit;
@@ -30,7 +31,13 @@ fn dogma() -> AdvanceBuilder {
         Note: Dogma Advance does not apply when you conquer a city with a Temple.",
     )
     .add_once_initializer(move |game, player| {
-        player.log(game, "Ideas limit reduced to 2");
+        player.log_message(
+            game,
+            Message::new(
+                "log.advance.ideas_limit_reduced_to_2",
+                "Ideas limit reduced to 2",
+            ),
+        );
         let p = player.get_mut(game);
         p.resource_limit.ideas = 2;
         apply_resource_limit(p);
@@ -102,7 +109,13 @@ fn conversion() -> AdvanceBuilder {
                 && !info.is_defender(p.index)
             {
                 info.roll_boost += 1;
-                info.info.add_log(p, "gains +1 to Influence Culture roll");
+                info.info.add_log(
+                    p,
+                    Message::new(
+                        "log.advance.gains_1_to_influence_culture_roll",
+                        "gains +1 to Influence Culture roll",
+                    ),
+                );
             }
         },
     )
@@ -129,8 +142,10 @@ fn fanaticism() -> AdvanceBuilder {
         .add_combat_strength_listener(1, |game, c, s, _role| {
             if c.first_round() && c.defender_temple(game) {
                 s.extra_combat_value += 2;
-                s.roll_log
-                    .push("Player gets +2 combat value for Fanaticism Advance".to_string());
+                s.roll_log.push(Message::new(
+                    "log.combat_value.fanaticism",
+                    "Player gets +2 combat value for Fanaticism Advance",
+                ));
             }
         })
         .add_position_request(
@@ -143,7 +158,7 @@ fn fanaticism() -> AdvanceBuilder {
                     && game.player(player_index).available_units().infantry > 0
                 {
                     let p = game.player(player_index);
-                    let choices = cities_that_can_add_units(p);
+                    let choices = cities_that_can_add_units(game, p);
                     let needed = 1..=1;
                     Some(PositionRequest::new(
                         choices,
@@ -160,11 +175,11 @@ fn fanaticism() -> AdvanceBuilder {
         )
 }
 
-pub(crate) fn cities_that_can_add_units(p: &Player) -> Vec<Position> {
+pub(crate) fn cities_that_can_add_units(game: &Game, p: &Player) -> Vec<Position> {
     let choices: Vec<Position> = p
         .cities
         .iter()
-        .filter(|c| can_add_army_unit(p, c.position))
+        .filter(|c| can_add_army_unit(game, p, c.position))
         .map(|c| c.position)
         .collect();
     choices
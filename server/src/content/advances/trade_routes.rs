@@ -2,6 +2,7 @@ use crate::advance::Advance;
 use crate::city::{City, MoodState};
 use crate::events::EventPlayer;
 use crate::game::Game;
+use crate::localization::Message;
 use crate::payment::ResourceReward;
 use crate::player::Player;
 use crate::position::Position;
@@ -41,24 +42,33 @@ pub(crate) fn trade_route_log(
     player_index: usize,
     trade_routes: &[TradeRoute],
     selected: bool,
-) -> Vec<String> {
+) -> Vec<Message> {
     let mut log = Vec::new();
     if selected {
-        log.push(format!(
-            "{} selected trade routes",
-            game.player_name(player_index),
-        ));
+        log.push(
+            Message::new(
+                "log.trade_routes.selected",
+                "{player} selected trade routes",
+            )
+            .param("player", game.player_name(player_index)),
+        );
     }
     for t in trade_routes {
-        log.push(format!(
-            "{} at {} traded with city {}",
-            game.players[player_index]
-                .get_unit(t.unit_id)
-                .unit_type
-                .non_leader_name(),
-            t.from,
-            t.to,
-        ));
+        log.push(
+            Message::new(
+                "log.trade_routes.traded",
+                "{unit} at {from} traded with city {to}",
+            )
+            .param(
+                "unit",
+                game.players[player_index]
+                    .get_unit(t.unit_id)
+                    .unit_type
+                    .non_leader_name(),
+            )
+            .param("from", t.from)
+            .param("to", t.to),
+        );
     }
     log
 }
@@ -11,6 +11,7 @@ use crate::content::advances::{AdvanceGroup, AdvanceGroupInfo, advance_group_bui
 use crate::content::persistent_events::PaymentRequest;
 use crate::events::EventPlayer;
 use crate::game::{Game, GameOptions};
+use crate::localization::Message;
 use crate::payment::{PaymentConversion, PaymentOptions};
 use crate::player::Player;
 use crate::resource::ResourceType;
@@ -88,11 +89,23 @@ fn siegecraft() -> AdvanceBuilder {
             let payment = &s.choice;
             if !payment[0].is_empty() {
                 modifiers.push(CancelFortressExtraDie);
-                s.log(game, "Cancel Fortress Extra Die");
+                s.log_message(
+                    game,
+                    Message::new(
+                        "log.advance.cancel_fortress_extra_die",
+                        "Cancel Fortress Extra Die",
+                    ),
+                );
             }
             if !payment[1].is_empty() {
                 modifiers.push(CancelFortressIgnoreHit);
-                s.log(game, "Cancel Fortress Ignore Hit");
+                s.log_message(
+                    game,
+                    Message::new(
+                        "log.advance.cancel_fortress_ignore_hit",
+                        "Cancel Fortress Ignore Hit",
+                    ),
+                );
             }
 
             c.modifiers.extend(modifiers);
@@ -154,8 +167,13 @@ fn draft() -> AdvanceBuilder {
                 // insert at beginning so that it's preferred over gold
 
                 let pile = ResourcePile::mood_tokens(draft_cost(p.get(game)));
-                cost.info
-                    .add_log(p, "Reduce the cost of 1 Infantry to 1 mood token");
+                cost.info.add_log(
+                    p,
+                    Message::new(
+                        "log.advance.reduce_the_cost_of_1_infantry",
+                        "Reduce the cost of 1 Infantry to 1 mood token",
+                    ),
+                );
                 cost.cost.conversions.insert(
                     0,
                     PaymentConversion::limited(UnitType::cost(&UnitType::Infantry), pile, 1),
@@ -199,12 +217,18 @@ fn fortress(game: &Game, c: &Combat, s: &mut CombatStrength, role: CombatRole) {
     }
 
     if !c.modifiers.contains(&CancelFortressExtraDie) {
-        s.roll_log.push("fortress added one extra die".to_string());
+        s.roll_log.push(Message::new(
+            "log.combat_value.fortress_added_one_extra_die",
+            "fortress added one extra die",
+        ));
         s.extra_dies += 1;
     }
 
     if !c.modifiers.contains(&CancelFortressIgnoreHit) {
-        s.roll_log.push("fortress cancelled one hit".to_string());
+        s.roll_log.push(Message::new(
+            "log.combat_value.fortress_cancelled_one_hit",
+            "fortress cancelled one hit",
+        ));
         s.hit_cancels += 1;
     }
 }
@@ -224,8 +248,13 @@ fn use_steel_weapons(game: &Game, c: &Combat, s: &mut CombatStrength, role: Comb
 
     let add_combat_value = |s: &mut CombatStrength, value: u8| {
         s.extra_combat_value += value as i8;
-        s.roll_log
-            .push(format!("steel weapons added {value} combat value"));
+        s.roll_log.push(
+            Message::new(
+                "log.combat_value.steel_weapons",
+                "steel weapons added {value} combat value",
+            )
+            .param("value", value),
+        );
     };
 
     if role.is_attacker() {
@@ -0,0 +1,409 @@
+use crate::action_cost::{ActionCost, ActionResourceCost};
+use crate::content::effects::event_help;
+use crate::events::EventOrigin;
+use crate::game::{Game, GameOptions, PatchOption};
+use crate::game_setup::{GameSetupBuilder, setup_game};
+use crate::objective_card::ObjectiveType;
+use crate::player::CostTrigger;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum ContentCategory {
+    Advances,
+    Governments,
+    Civilizations,
+    SpecialAdvances,
+    Leaders,
+    Wonders,
+    ActionCards,
+    TacticsCards,
+    ObjectiveCards,
+    Objectives,
+    Incidents,
+}
+
+///
+/// A rules element in a form that can be shown on a website or compared between patches.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct CatalogEntry {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub details: BTreeMap<String, String>,
+}
+
+impl CatalogEntry {
+    fn new(id: &impl ToString, name: &str, description: &str) -> Self {
+        Self {
+            id: id.to_string(),
+            name: name.to_string(),
+            description: description.to_string(),
+            details: BTreeMap::new(),
+        }
+    }
+
+    fn detail(mut self, key: &str, value: &impl ToString) -> Self {
+        let value = value.to_string();
+        if !value.is_empty() {
+            self.details.insert(key.to_string(), value);
+        }
+        self
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct ContentCatalog {
+    pub patch: PatchOption,
+    pub content: BTreeMap<ContentCategory, Vec<CatalogEntry>>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub enum CatalogChangeType {
+    Added,
+    Removed,
+    Changed,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct CatalogFieldChange {
+    pub field: String,
+    pub old: String,
+    pub new: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct CatalogChange {
+    pub category: ContentCategory,
+    pub id: String,
+    pub change: CatalogChangeType,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<CatalogFieldChange>,
+}
+
+///
+/// Exports all rules content for the given patch.
+#[must_use]
+pub fn content_catalog(patch: PatchOption) -> ContentCatalog {
+    let game = setup_game(
+        &GameSetupBuilder::new(2)
+            .skip_random_map()
+            .options(GameOptions {
+                patch: patch.clone(),
+                ..GameOptions::default()
+            })
+            .build(),
+    );
+    ContentCatalog {
+        patch,
+        content: BTreeMap::from([
+            (ContentCategory::Advances, advances(&game)),
+            (ContentCategory::Governments, governments(&game)),
+            (ContentCategory::Civilizations, civilizations(&game)),
+            (ContentCategory::SpecialAdvances, special_advances(&game)),
+            (ContentCategory::Leaders, leaders(&game)),
+            (ContentCategory::Wonders, wonders(&game)),
+            (ContentCategory::ActionCards, action_cards(&game)),
+            (ContentCategory::TacticsCards, tactics_cards(&game)),
+            (ContentCategory::ObjectiveCards, objective_cards(&game)),
+            (ContentCategory::Objectives, objectives(&game)),
+            (ContentCategory::Incidents, incidents(&game)),
+        ]),
+    }
+}
+
+///
+/// Returns all entries that were added, removed or changed from `old` to `new`.
+#[must_use]
+pub fn diff_catalogs(old: &ContentCatalog, new: &ContentCatalog) -> Vec<CatalogChange> {
+    let mut changes = vec![];
+    for (category, new_entries) in &new.content {
+        let old_entries = old.content.get(category).map_or(&[][..], Vec::as_slice);
+        for n in new_entries {
+            match old_entries.iter().find(|o| o.id == n.id) {
+                None => changes.push(change(*category, n, CatalogChangeType::Added)),
+                Some(o) if o != n => {
+                    let mut c = change(*category, n, CatalogChangeType::Changed);
+                    c.fields = diff_entries(o, n);
+                    changes.push(c);
+                }
+                Some(_) => {}
+            }
+        }
+        for o in old_entries {
+            if !new_entries.iter().any(|n| n.id == o.id) {
+                changes.push(change(*category, o, CatalogChangeType::Removed));
+            }
+        }
+    }
+    changes
+}
+
+fn change(category: ContentCategory, e: &CatalogEntry, change: CatalogChangeType) -> CatalogChange {
+    CatalogChange {
+        category,
+        id: e.id.clone(),
+        change,
+        fields: vec![],
+    }
+}
+
+fn diff_entries(old: &CatalogEntry, new: &CatalogEntry) -> Vec<CatalogFieldChange> {
+    let fields = |e: &CatalogEntry| {
+        let mut f = e.details.clone();
+        f.insert("name".to_string(), e.name.clone());
+        f.insert("description".to_string(), e.description.clone());
+        f
+    };
+    let old = fields(old);
+    let new = fields(new);
+    old.keys()
+        .chain(new.keys())
+        .unique()
+        .filter_map(|field| {
+            let o = old.get(field).cloned().unwrap_or_default();
+            let n = new.get(field).cloned().unwrap_or_default();
+            (o != n).then(|| CatalogFieldChange {
+                field: field.clone(),
+                old: o,
+                new: n,
+            })
+        })
+        .collect()
+}
+
+fn description(game: &Game, origin: &EventOrigin) -> String {
+    // the first message is the name
+    event_help(game, origin).iter().skip(1).join("\n")
+}
+
+fn advances(game: &Game) -> Vec<CatalogEntry> {
+    // the cost without the discounts of any advance
+    let player = game.player(0);
+    game.cache
+        .get_advance_groups()
+        .iter()
+        .flat_map(|g| {
+            g.advances.iter().map(|a| {
+                CatalogEntry::new(&format!("{:?}", a.advance), &a.name, &a.description)
+                    .detail("group", &g.advance_group)
+                    .detail(
+                        "cost",
+                        &player
+                            .advance_cost(a.advance, game, CostTrigger::NoModifiers)
+                            .cost,
+                    )
+                    .detail(
+                        "required",
+                        &a.required
+                            .map(|r| r.name(game).to_string())
+                            .unwrap_or_default(),
+                    )
+                    .detail(
+                        "contradicting",
+                        &a.contradicting.iter().map(|c| c.name(game)).join(", "),
+                    )
+                    .detail(
+                        "unlocked_building",
+                        &a.unlocked_building
+                            .map(|b| b.to_string())
+                            .unwrap_or_default(),
+                    )
+                    .detail("government", &a.government.as_deref().unwrap_or_default())
+                    .detail(
+                        "bonus",
+                        &a.bonus
+                            .as_ref()
+                            .map(|b| b.resources().to_string())
+                            .unwrap_or_default(),
+                    )
+            })
+        })
+        .collect()
+}
+
+fn governments(game: &Game) -> Vec<CatalogEntry> {
+    game.cache
+        .get_governments()
+        .iter()
+        .map(|g| {
+            CatalogEntry::new(&g.name, &g.name, "")
+                .detail("advances", &g.advances.iter().map(|a| &a.name).join(", "))
+        })
+        .collect()
+}
+
+fn civilizations(game: &Game) -> Vec<CatalogEntry> {
+    game.cache
+        .get_civilizations()
+        .iter()
+        .map(|c| {
+            CatalogEntry::new(&c.name, &c.name, "")
+                .detail(
+                    "special_advances",
+                    &c.special_advances.iter().map(|s| &s.name).join(", "),
+                )
+                .detail("leaders", &c.leaders.iter().map(|l| &l.name).join(", "))
+        })
+        .collect()
+}
+
+fn special_advances(game: &Game) -> Vec<CatalogEntry> {
+    game.cache
+        .get_civilizations()
+        .iter()
+        .flat_map(|c| {
+            c.special_advances.iter().map(|s| {
+                CatalogEntry::new(&format!("{:?}", s.advance), &s.name, &s.description)
+                    .detail("civilization", &c.name)
+                    .detail("requirement", &s.requirement.name(game))
+            })
+        })
+        .collect()
+}
+
+fn leaders(game: &Game) -> Vec<CatalogEntry> {
+    game.cache
+        .get_civilizations()
+        .iter()
+        .flat_map(|c| {
+            c.leaders.iter().map(|l| {
+                CatalogEntry::new(
+                    &format!("{:?}", l.leader),
+                    &l.name,
+                    &l.abilities
+                        .iter()
+                        .map(|a| format!("{}: {}", a.name, a.description))
+                        .join("\n"),
+                )
+                .detail("civilization", &c.name)
+            })
+        })
+        .collect()
+}
+
+fn wonders(game: &Game) -> Vec<CatalogEntry> {
+    game.cache
+        .get_wonders()
+        .iter()
+        .map(|w| {
+            CatalogEntry::new(&format!("{:?}", w.wonder), &w.name(), &w.description)
+                .detail("cost", &w.cost)
+                .detail("required_advance", &w.required_advance.name(game))
+                .detail("owned_victory_points", &w.owned_victory_points)
+                .detail("built_victory_points", &w.built_victory_points)
+        })
+        .collect()
+}
+
+fn action_cards(game: &Game) -> Vec<CatalogEntry> {
+    game.cache
+        .get_action_cards()
+        .iter()
+        .map(|a| {
+            let c = &a.civil_card;
+            CatalogEntry::new(&a.id, &c.name, &c.description)
+                .detail("action", &action_cost(&c.action_type))
+                .detail(
+                    "tactics_card",
+                    &a.tactics_card
+                        .as_ref()
+                        .map(|t| t.name.clone())
+                        .unwrap_or_default(),
+                )
+        })
+        .collect()
+}
+
+fn action_cost(cost: &ActionCost) -> String {
+    let action = if cost.free {
+        "Free action"
+    } else {
+        "Regular action"
+    };
+    match &cost.cost {
+        ActionResourceCost::Free => action.to_string(),
+        ActionResourceCost::Resources(r) => format!("{action}, cost: {r}"),
+        ActionResourceCost::Tokens(t) => format!("{action}, cost: {t} mood or culture tokens"),
+        ActionResourceCost::AdvanceCostWithoutDiscount => {
+            format!("{action}, cost: advance cost")
+        }
+    }
+}
+
+fn tactics_cards(game: &Game) -> Vec<CatalogEntry> {
+    game.cache
+        .get_action_cards()
+        .iter()
+        .filter_map(|a| a.tactics_card.as_ref())
+        .map(|t| {
+            CatalogEntry::new(&t.id, &t.name, &t.description)
+                .detail("units", &t.fighter_requirement.iter().join(", "))
+                .detail(
+                    "role",
+                    &t.role_requirement
+                        .map(|r| r.to_string())
+                        .unwrap_or_default(),
+                )
+                .detail(
+                    "location",
+                    &t.location_requirement
+                        .as_ref()
+                        .map(ToString::to_string)
+                        .unwrap_or_default(),
+                )
+        })
+        .collect()
+}
+
+fn objective_cards(game: &Game) -> Vec<CatalogEntry> {
+    game.cache
+        .get_objective_cards()
+        .iter()
+        .map(|c| {
+            CatalogEntry::new(
+                &c.id,
+                &c.name(),
+                &c.objectives
+                    .iter()
+                    .map(|o| format!("{}: {}", o.name, o.description))
+                    .join("\n"),
+            )
+        })
+        .collect()
+}
+
+fn objectives(game: &Game) -> Vec<CatalogEntry> {
+    game.cache
+        .get_objectives()
+        .iter()
+        .map(|o| {
+            CatalogEntry::new(&o.name, &o.name, &o.description).detail(
+                "type",
+                &match o.get_type() {
+                    ObjectiveType::Instant => "Instant",
+                    ObjectiveType::StatusPhase => "Status Phase",
+                },
+            )
+        })
+        .collect()
+}
+
+fn incidents(game: &Game) -> Vec<CatalogEntry> {
+    game.cache
+        .get_incidents()
+        .iter()
+        .map(|i| {
+            CatalogEntry::new(
+                &i.id,
+                &i.name,
+                &description(game, &EventOrigin::Incident(i.id)),
+            )
+            .detail("base_effect", &i.base_effect)
+        })
+        .collect()
+}
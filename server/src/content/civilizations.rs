@@ -1,6 +1,8 @@
 pub(crate) mod china;
+pub(crate) mod egypt;
 pub(crate) mod greece;
 pub(crate) mod maya;
+pub(crate) mod persia;
 pub(crate) mod rome;
 pub mod vikings;
 
@@ -20,6 +22,8 @@ pub fn get_all_uncached() -> Vec<Civilization> {
         greece::greece(),
         china::china(),
         vikings::vikings(),
+        egypt::egypt(),
+        persia::persia(),
         // not finished yet: maya
     ]
 }
@@ -11,6 +11,7 @@ use crate::content::persistent_events::{PaymentRequest, UnitsRequest};
 use crate::game::{Game, GameState};
 use crate::leader::{Leader, LeaderInfo};
 use crate::leader_ability::LeaderAbility;
+use crate::localization::Message;
 use crate::map::Terrain;
 use crate::movement::{MoveState, possible_move_destinations};
 use crate::player::{Player, can_add_army_unit};
@@ -59,8 +60,11 @@ fn rice() -> SpecialAdvanceInfo {
                 .count()
                 .min(2);
             i.total += ResourcePile::food(food as u8);
-            i.info
-                .add_log(p, &format!("Added {}", ResourcePile::food(food as u8)));
+            i.info.add_log(
+                p,
+                Message::new("log.china.added", "Added {resources}")
+                    .param("resources", ResourcePile::food(food as u8)),
+            );
         },
     )
     .build()
@@ -99,11 +103,15 @@ fn expansion() -> SpecialAdvanceInfo {
                 ..MoveState::default()
             });
 
-            player.log(
+            player.log_message(
                 game,
-                &format!(
-                    "Expansion allows to move the settlers at {}",
-                    settlers.iter().map(ToString::to_string).join(", ")
+                Message::new(
+                    "log.china.expansion",
+                    "Expansion allows to move the settlers at {positions}",
+                )
+                .param(
+                    "positions",
+                    settlers.iter().map(ToString::to_string).join(", "),
                 ),
             );
         },
@@ -143,7 +151,7 @@ fn fireworks() -> SpecialAdvanceInfo {
     .build()
 }
 
-fn ignore_hit_ability<B: AbilityInitializerSetup>(
+pub(crate) fn ignore_hit_ability<B: AbilityInitializerSetup>(
     b: B,
     pile: ResourcePile,
     priority: i32,
@@ -163,12 +171,24 @@ fn ignore_hit_ability<B: AbilityInitializerSetup>(
             let cost = p.payment_options().resources(player, pile.clone());
 
             if !apply_ignore_hit(e, player_index, false) {
-                p.log(game, "Won't reduce the hits, no payment made.");
+                p.log_message(
+                    game,
+                    Message::new(
+                        "log.china.won_t_reduce_the_hits_no",
+                        "Won't reduce the hits, no payment made.",
+                    ),
+                );
                 return None;
             }
 
             if !player.can_afford(&cost) {
-                p.log(game, "Not enough resources, no payment made.");
+                p.log_message(
+                    game,
+                    Message::new(
+                        "log.china.not_enough_resources_no_payment_made",
+                        "Not enough resources, no payment made.",
+                    ),
+                );
                 return None;
             }
 
@@ -176,7 +196,10 @@ fn ignore_hit_ability<B: AbilityInitializerSetup>(
         },
         move |game, s, e| {
             if !s.choice[0].is_empty() {
-                s.log(game, "Ignore the first hit");
+                s.log_message(
+                    game,
+                    Message::new("log.china.ignore_the_first_hit", "Ignore the first hit"),
+                );
                 apply_ignore_hit(e, s.player_index, true);
             }
         },
@@ -201,14 +224,14 @@ fn imperial_army() -> SpecialAdvanceInfo {
         CustomActionType::ImperialArmy,
         |c| c.once_per_turn().action().no_resources(),
         use_imperial_army,
-        |_game, p| {
+        |game, p| {
             !p.units
                 .iter()
                 .filter(|u| {
                     // infantry can always be converted - if the settler limit is reached,
                     // the player has to convert a settler to infantry
                     // we're not checking if that settler can be converted here, but later
-                    (u.is_settler() && can_add_army_unit(p, u.position)) || u.is_infantry()
+                    (u.is_settler() && can_add_army_unit(game, p, u.position)) || u.is_infantry()
                 })
                 .map(|u| u.id)
                 .collect_vec()
@@ -250,7 +273,11 @@ fn use_imperial_army(b: AbilityBuilder) -> AbilityBuilder {
                 }
             }
 
-            s.log(game, &format!("Convert {}", names.join(", ")));
+            s.log_message(
+                game,
+                Message::new("log.china.convert", "Convert {units}")
+                    .param("units", names.join(", ")),
+            );
         },
     )
 }
@@ -384,8 +411,10 @@ fn wu() -> LeaderInfo {
                 && c.fighting_units(game, p).len() >= c.fighting_units(game, c.opponent(p)).len()
             {
                 s.extra_combat_value += 2;
-                s.roll_log
-                    .push("Wu Zetian adds +2 combat value".to_string());
+                s.roll_log.push(Message::new(
+                    "log.combat_value.wu_zetian_adds_2_combat_value",
+                    "Wu Zetian adds +2 combat value",
+                ));
             }
         })
         .build(),
@@ -0,0 +1,219 @@
+use crate::ability_initializer::AbilityInitializerSetup;
+use crate::advance::Advance;
+use crate::city::increase_mood_state;
+use crate::city_pieces::Building;
+use crate::civilization::Civilization;
+use crate::content::advances::AdvanceGroup;
+use crate::content::custom_actions::CustomActionType;
+use crate::leader::{Leader, LeaderInfo, leader_position};
+use crate::leader_ability::LeaderAbility;
+use crate::localization::Message;
+use crate::map::{Block, Terrain};
+use crate::resource_pile::ResourcePile;
+use crate::special_advance::{SpecialAdvance, SpecialAdvanceInfo, SpecialAdvanceRequirement};
+use crate::wonder::Wonder;
+
+pub(crate) fn egypt() -> Civilization {
+    Civilization::new(
+        "Egypt",
+        vec![flood(), master_builders(), hieroglyphs(), pharaoh()],
+        vec![ramesses(), hatshepsut(), cleopatra()],
+        Some(Block::new([
+            Terrain::Fertile,
+            Terrain::Barren,
+            Terrain::Fertile,
+            Terrain::Water,
+        ])),
+    )
+}
+
+fn flood() -> SpecialAdvanceInfo {
+    SpecialAdvanceInfo::builder(
+        SpecialAdvance::Flood,
+        SpecialAdvanceRequirement::Advance(Advance::Irrigation),
+        "Flood",
+        "Collect from at least 1 Grassland space in a city that is adjacent to Water: \
+        Gain 1 additional food.",
+    )
+    .add_transient_event_listener(
+        |event| &mut event.collect_total,
+        3,
+        |i, game, collections, p| {
+            let city = game.get_any_city(i.city);
+            let next_to_water = city
+                .position
+                .neighbors()
+                .iter()
+                .any(|n| game.map.get(*n) == Some(&Terrain::Water));
+            // Grassland is the player facing name of `Terrain::Fertile`
+            if next_to_water
+                && collections
+                    .iter()
+                    .any(|c| game.map.get(c.position) == Some(&Terrain::Fertile))
+            {
+                i.total += ResourcePile::food(1);
+                i.info.add_log(
+                    p,
+                    Message::new("log.egypt.flood_adds_1_food", "Flood adds 1 food"),
+                );
+            }
+        },
+    )
+    .build()
+}
+
+fn master_builders() -> SpecialAdvanceInfo {
+    SpecialAdvanceInfo::builder(
+        SpecialAdvance::MasterBuilders,
+        SpecialAdvanceRequirement::Advance(Advance::Engineering),
+        "Master Builders",
+        "Building a wonder costs 1 culture token less.",
+    )
+    .add_transient_event_listener(
+        |event| &mut event.wonder_cost,
+        1,
+        |i, w, _, p| {
+            i.cost.default.culture_tokens = i.cost.default.culture_tokens.saturating_sub(1);
+            i.info.add_log(
+                p,
+                Message::new(
+                    "log.egypt.wonder_cost",
+                    "Reduce the cost of {wonder} by 1 culture token",
+                )
+                .param("wonder", w.wonder),
+            );
+        },
+    )
+    .build()
+}
+
+fn hieroglyphs() -> SpecialAdvanceInfo {
+    SpecialAdvanceInfo::builder(
+        SpecialAdvance::Hieroglyphs,
+        SpecialAdvanceRequirement::Advance(Advance::Writing),
+        "Hieroglyphs",
+        "Gain 1 idea when constructing a Temple or an Academy.",
+    )
+    .add_simple_persistent_event_listener(
+        |event| &mut event.construct,
+        5,
+        |game, p, b| {
+            if matches!(b.building, Building::Temple | Building::Academy) {
+                p.gain_resources(game, ResourcePile::ideas(1));
+            }
+        },
+    )
+    .build()
+}
+
+fn pharaoh() -> SpecialAdvanceInfo {
+    SpecialAdvanceInfo::builder(
+        SpecialAdvance::Pharaoh,
+        SpecialAdvanceRequirement::AnyGovernment,
+        "Pharaoh",
+        "When you found a city, increase its mood by 1.",
+    )
+    .add_simple_persistent_event_listener(
+        |event| &mut event.found_city,
+        1,
+        |game, p, position| {
+            increase_mood_state(game, *position, 1, &p.origin);
+        },
+    )
+    .build()
+}
+
+fn ramesses() -> LeaderInfo {
+    LeaderInfo::new(
+        Leader::Ramesses,
+        "Ramesses II",
+        LeaderAbility::wonder_expert(Wonder::Pyramids),
+        LeaderAbility::builder(
+            "Battle of Kadesh",
+            "Land battle with leader in one of your cities: Gain +2 combat value.",
+        )
+        .add_combat_strength_listener(107, |game, c, s, r| {
+            let p = c.player(r);
+            if c.is_land_battle_with_leader(r, game)
+                && c.defender_city(game)
+                    .is_some_and(|city| city.player_index == p)
+            {
+                s.extra_combat_value += 2;
+                s.roll_log.push(Message::new(
+                    "log.combat_value.battle_of_kadesh_adds_2_combat",
+                    "Battle of Kadesh adds +2 combat value",
+                ));
+            }
+        })
+        .build(),
+    )
+}
+
+fn hatshepsut() -> LeaderInfo {
+    LeaderInfo::new(
+        Leader::Hatshepsut,
+        "Hatshepsut",
+        LeaderAbility::advance_gain_custom_action(
+            "Expedition to Punt",
+            CustomActionType::ExpeditionToPunt,
+            AdvanceGroup::Economy,
+        ),
+        LeaderAbility::builder(
+            "Prosperity",
+            "Gain 1 additional gold when collecting in the leader city.",
+        )
+        .add_transient_event_listener(
+            |event| &mut event.collect_total,
+            4,
+            |i, game, _, p| {
+                if leader_position(game.player(i.info.player)) == i.city {
+                    i.total += ResourcePile::gold(1);
+                    i.info.add_log(
+                        p,
+                        Message::new("log.egypt.prosperity_adds_1_gold", "Prosperity adds 1 gold"),
+                    );
+                }
+            },
+        )
+        .build(),
+    )
+}
+
+fn cleopatra() -> LeaderInfo {
+    LeaderInfo::new(
+        Leader::Cleopatra,
+        "Cleopatra VII",
+        LeaderAbility::builder(
+            "Queen of the Nile",
+            "Gain 1 mood token when recruiting in the leader city.",
+        )
+        .add_simple_persistent_event_listener(
+            |event| &mut event.recruit,
+            5,
+            |game, p, r| {
+                if leader_position(p.get(game)) == r.city_position {
+                    p.gain_resources(game, ResourcePile::mood_tokens(1));
+                }
+            },
+        )
+        .build(),
+        LeaderAbility::builder(
+            "Charisma",
+            "Cleopatra survives a battle and you win: Gain 2 culture tokens.",
+        )
+        .add_simple_persistent_event_listener(
+            |event| &mut event.combat_end,
+            26,
+            |game, p, s| {
+                let player_index = p.index;
+                if s.is_winner(player_index)
+                    && s.is_battle()
+                    && s.player(player_index).survived_leader()
+                {
+                    p.gain_resources(game, ResourcePile::culture_tokens(2));
+                }
+            },
+        )
+        .build(),
+    )
+}
@@ -15,6 +15,7 @@ use crate::events::check_event_origin;
 use crate::game::Game;
 use crate::leader::{Leader, LeaderInfo, leader_position};
 use crate::leader_ability::{LeaderAbility, activate_leader_city, can_activate_leader_city};
+use crate::localization::Message;
 use crate::map::{block_has_player_city, get_map_setup};
 use crate::payment::PaymentConversion;
 use crate::player::Player;
@@ -64,8 +65,13 @@ fn sparta() -> SpecialAdvanceInfo {
         0,
         |cost, units, game, p| {
             if units.infantry > 0 {
-                cost.info
-                    .add_log(p, "Can pay the Draft cost as culture tokens");
+                cost.info.add_log(
+                    p,
+                    Message::new(
+                        "log.greece.can_pay_the_draft_cost_as",
+                        "Can pay the Draft cost as culture tokens",
+                    ),
+                );
                 cost.cost.conversions.insert(
                     0,
                     PaymentConversion::limited(
@@ -89,8 +95,10 @@ fn sparta() -> SpecialAdvanceInfo {
                     opponent,
                     r,
                     |_game, _combat, s: &mut CombatStrength, _role| {
-                        s.roll_log
-                            .push("Sparta denies playing tactics cards".to_string());
+                        s.roll_log.push(Message::new(
+                            "log.combat_value.sparta_denies_playing_tactics_cards",
+                            "Sparta denies playing tactics cards",
+                        ));
                         s.deny_tactics_card = true;
                     },
                 );
@@ -228,8 +236,13 @@ fn alexander() -> LeaderInfo {
                     .filter(|b| block_has_player_city(game, b, player))
                     .count();
                 s.extra_combat_value += extra as i8;
-                s.roll_log
-                    .push(format!("Ruler of the World adds {extra} combat value",));
+                s.roll_log.push(
+                    Message::new(
+                        "log.combat_value.ruler_of_the_world",
+                        "Ruler of the World adds {value} combat value",
+                    )
+                    .param("value", extra),
+                );
             }
         })
         .build(),
@@ -253,12 +266,13 @@ fn use_idol(b: AbilityBuilder) -> AbilityBuilder {
             let HandCard::ActionCard(id) = s.choice[0] else {
                 panic!("expected action card");
             };
-            s.log(
+            s.log_message(
                 game,
-                &format!(
-                    "Decided to play {} as a free action",
-                    game.cache.get_civil_card(id).name
-                ),
+                Message::new(
+                    "log.greece.free_civil_card",
+                    "Decided to play {card} as a free action",
+                )
+                .param("card", &game.cache.get_civil_card(id).name),
             );
 
             PlayingAction::ActionCard(id)
@@ -322,8 +336,13 @@ fn leonidas() -> LeaderInfo {
             let extra = op.saturating_sub(pl) * 2;
             if c.has_leader(r, game) && extra > 0 {
                 s.extra_combat_value += extra as i8;
-                s.roll_log
-                    .push(format!("Hero of Thermopylae adds {extra} combat value",));
+                s.roll_log.push(
+                    Message::new(
+                        "log.combat_value.hero_of_thermopylae",
+                        "Hero of Thermopylae adds {value} combat value",
+                    )
+                    .param("value", extra),
+                );
             }
         })
         .build(),
@@ -346,7 +365,10 @@ fn pericles() -> LeaderInfo {
         .add_combat_strength_listener(102, |game, c, s, r| {
             if c.has_leader(r, game) && c.is_sea_battle(game) {
                 s.extra_combat_value += 2;
-                s.roll_log.push("Admiral adds +2 combat value".to_string());
+                s.roll_log.push(Message::new(
+                    "log.combat_value.admiral_adds_2_combat_value",
+                    "Admiral adds +2 combat value",
+                ));
             }
         })
         .build(),
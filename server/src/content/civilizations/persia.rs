@@ -0,0 +1,244 @@
+use crate::ability_initializer::AbilityInitializerSetup;
+use crate::advance::Advance;
+use crate::city::{MoodState, set_city_mood};
+use crate::civilization::Civilization;
+use crate::combat::Combat;
+use crate::content::advances::AdvanceGroup;
+use crate::content::civilizations::china::ignore_hit_ability;
+use crate::content::custom_actions::CustomActionType;
+use crate::leader::{Leader, LeaderInfo, leader_position};
+use crate::leader_ability::LeaderAbility;
+use crate::localization::Message;
+use crate::map::capital_city_position;
+use crate::resource_pile::ResourcePile;
+use crate::special_advance::{SpecialAdvance, SpecialAdvanceInfo, SpecialAdvanceRequirement};
+use crate::unit::UnitType;
+
+pub(crate) fn persia() -> Civilization {
+    Civilization::new(
+        "Persia",
+        vec![royal_road(), satrapies(), horse_breeding(), immortals()],
+        vec![cyrus(), darius(), xerxes()],
+        None,
+    )
+}
+
+fn royal_road() -> SpecialAdvanceInfo {
+    SpecialAdvanceInfo::builder(
+        SpecialAdvance::RoyalRoad,
+        SpecialAdvanceRequirement::Advance(Advance::Roads),
+        "Royal Road",
+        "Once per turn, when you move land units from one of your cities \
+        to another one of your cities: Gain 1 gold.",
+    )
+    .add_transient_event_listener(
+        |event| &mut event.before_move,
+        2,
+        |game, i, (), p| {
+            let player = p.get(game);
+            if player.event_info.contains_key("royal_road")
+                || player.try_get_city(i.from).is_none()
+                || player.try_get_city(i.to).is_none()
+                || !i
+                    .units
+                    .iter()
+                    .any(|id| player.get_unit(*id).is_land_based())
+            {
+                return;
+            }
+            p.get_mut(game)
+                .event_info
+                .insert("royal_road".to_string(), "used".to_string());
+            p.gain_resources(game, ResourcePile::gold(1));
+        },
+    )
+    .build()
+}
+
+fn satrapies() -> SpecialAdvanceInfo {
+    SpecialAdvanceInfo::builder(
+        SpecialAdvance::Satrapies,
+        SpecialAdvanceRequirement::AnyGovernment,
+        "Satrapies",
+        "When collecting in a city that is at least 3 spaces away from your capital: \
+        You may collect gold instead of any other resource.",
+    )
+    .add_transient_event_listener(
+        |event| &mut event.collect_options,
+        3,
+        |i, c, game, p| {
+            let capital = capital_city_position(game, game.player(c.player_index));
+            if c.city_position.distance(capital) < 3 {
+                return;
+            }
+            for options in i.choices.values_mut() {
+                options.insert(ResourcePile::gold(1));
+            }
+            i.info.add_log(
+                p,
+                Message::new(
+                    "log.persia.can_collect_gold_instead_of_other",
+                    "Can collect gold instead of other resources",
+                ),
+            );
+        },
+    )
+    .build()
+}
+
+fn horse_breeding() -> SpecialAdvanceInfo {
+    SpecialAdvanceInfo::builder(
+        SpecialAdvance::HorseBreeding,
+        SpecialAdvanceRequirement::Advance(Advance::Husbandry),
+        "Horse Breeding",
+        "Cavalry units cost 1 food less.",
+    )
+    .add_transient_event_listener(
+        |event| &mut event.recruit_cost,
+        3,
+        |cost, units, _, p| {
+            let discount = units.cavalry.min(cost.cost.default.food);
+            if discount > 0 {
+                cost.cost.default.food -= discount;
+                cost.info.add_log(
+                    p,
+                    Message::new(
+                        "log.persia.cavalry_cost",
+                        "Reduce the cost of Cavalry by {discount} food",
+                    )
+                    .param("discount", discount),
+                );
+            }
+        },
+    )
+    .build()
+}
+
+fn immortals() -> SpecialAdvanceInfo {
+    SpecialAdvanceInfo::builder(
+        SpecialAdvance::Immortals,
+        SpecialAdvanceRequirement::Advance(Advance::SteelWeapons),
+        "Immortals",
+        "In land battles with at least 3 army units: Gain +1 combat value.",
+    )
+    .add_combat_strength_listener(108, |game, c, s, r| {
+        if c.is_land_battle(game) && c.fighting_units(game, c.player(r)).len() >= 3 {
+            s.extra_combat_value += 1;
+            s.roll_log.push(Message::new(
+                "log.combat_value.immortals_add_1_combat_value",
+                "Immortals add +1 combat value",
+            ));
+        }
+    })
+    .build()
+}
+
+fn cyrus() -> LeaderInfo {
+    LeaderInfo::new(
+        Leader::Cyrus,
+        "Cyrus the Great",
+        LeaderAbility::builder(
+            "Liberator",
+            "Cities captured by the leader become Neutral instead of Angry.",
+        )
+        .add_simple_persistent_event_listener(
+            |event| &mut event.combat_end,
+            27,
+            |game, p, s| {
+                if s.captured_city(p.index).is_some() && s.player(p.index).survived_leader() {
+                    set_city_mood(game, s.defender.position, &p.origin, MoodState::Neutral);
+                }
+            },
+        )
+        .build(),
+        LeaderAbility::builder(
+            "King of Kings",
+            "Land battle with leader against a city: Gain +1 combat value.",
+        )
+        .add_combat_strength_listener(109, |game, c, s, r| {
+            if c.is_land_battle_with_leader(r, game)
+                && c.attacker() == c.player(r)
+                && c.defender_city(game).is_some()
+            {
+                s.extra_combat_value += 1;
+                s.roll_log.push(Message::new(
+                    "log.combat_value.king_of_kings_adds_1_combat",
+                    "King of Kings adds +1 combat value",
+                ));
+            }
+        })
+        .build(),
+    )
+}
+
+fn darius() -> LeaderInfo {
+    LeaderInfo::new(
+        Leader::Darius,
+        "Darius I",
+        LeaderAbility::advance_gain_custom_action(
+            "Administrator",
+            CustomActionType::Administrator,
+            AdvanceGroup::Construction,
+        ),
+        LeaderAbility::builder(
+            "Cavalry Charge",
+            "Land battle with leader: Gain +1 combat value for each of your Cavalry units \
+            (up to 3).",
+        )
+        .add_combat_strength_listener(110, |game, c, s, r| {
+            if !c.is_land_battle_with_leader(r, game) {
+                return;
+            }
+            let p = c.player(r);
+            let cavalry = c
+                .fighting_units(game, p)
+                .iter()
+                .filter(|id| game.player(p).get_unit(**id).unit_type == UnitType::Cavalry)
+                .count()
+                .min(3);
+            if cavalry > 0 {
+                s.extra_combat_value += cavalry as i8;
+                s.roll_log.push(
+                    Message::new(
+                        "log.combat_value.cavalry_charge",
+                        "Cavalry Charge adds +{value} combat value",
+                    )
+                    .param("value", cavalry),
+                );
+            }
+        })
+        .build(),
+    )
+}
+
+fn xerxes() -> LeaderInfo {
+    LeaderInfo::new(
+        Leader::Xerxes,
+        "Xerxes I",
+        ignore_hit_ability(
+            LeaderAbility::builder(
+                "Immortal Guard",
+                "Battle with leader: You may pay 2 gold to ignore a hit.",
+            ),
+            ResourcePile::gold(2),
+            93,
+            Combat::has_leader,
+        )
+        .build(),
+        LeaderAbility::builder(
+            "Great Army",
+            "Gain 1 mood token when recruiting at least 2 army units in the leader city.",
+        )
+        .add_simple_persistent_event_listener(
+            |event| &mut event.recruit,
+            6,
+            |game, p, r| {
+                let army_units = r.units.infantry + r.units.cavalry + r.units.elephants;
+                if leader_position(p.get(game)) == r.city_position && army_units >= 2 {
+                    p.gain_resources(game, ResourcePile::mood_tokens(1));
+                }
+            },
+        )
+        .build(),
+    )
+}
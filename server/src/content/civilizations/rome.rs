@@ -12,6 +12,7 @@ use crate::leader::{Leader, LeaderInfo, leader_position};
 use crate::leader_ability::{
     LeaderAbility, LeaderAbilityBuilder, activate_leader_city, can_activate_leader_city,
 };
+use crate::localization::Message;
 use crate::map::{block_for_position, block_has_player_city};
 use crate::objective_card::{discard_objective_card, gain_objective_card_from_pile};
 use crate::payment::{PaymentConversion, PaymentConversionType, base_resources};
@@ -106,7 +107,13 @@ fn captivi() -> SpecialAdvanceInfo {
                 ResourcePile::mood_tokens(1),
                 PaymentConversionType::Unlimited,
             ));
-            i.info.add_log(p, "May replace resources with mood tokens");
+            i.info.add_log(
+                p,
+                Message::new(
+                    "log.rome.may_replace_resources_with_mood_tokens",
+                    "May replace resources with mood tokens",
+                ),
+            );
         },
     )
     .build()
@@ -185,7 +192,10 @@ fn augustus() -> LeaderInfo {
                 )
             {
                 s.extra_combat_value += 2;
-                s.roll_log.push("Emperor adds 2 combat value".to_string());
+                s.roll_log.push(Message::new(
+                    "log.combat_value.emperor_adds_2_combat_value",
+                    "Emperor adds 2 combat value",
+                ));
             }
         })
         .build(),
@@ -291,7 +301,8 @@ fn proconsul() -> LeaderAbility {
             }
 
             let p = game.player(player.index);
-            if p.available_units().infantry == 0 || !can_add_army_unit(p, leader_position(p)) {
+            if p.available_units().infantry == 0 || !can_add_army_unit(game, p, leader_position(p))
+            {
                 return None;
             }
             s.captured_city(player.index)
@@ -349,7 +360,10 @@ fn sulla() -> LeaderInfo {
         .add_combat_strength_listener(104, |game, c, s, r| {
             if c.has_leader(r, game) && c.is_barbarian_battle(r, game) {
                 s.extra_combat_value += 2;
-                s.roll_log.push("Sulla adds 2 combat value".to_string());
+                s.roll_log.push(Message::new(
+                    "log.combat_value.sulla_adds_2_combat_value",
+                    "Sulla adds 2 combat value",
+                ));
             }
         })
         .build(),
@@ -387,15 +401,19 @@ fn add_barbarian_control(builder: LeaderAbilityBuilder) -> LeaderAbilityBuilder
         },
         |game, s, movable| {
             let may_not_move = &s.choice;
-            s.log(
+            s.log_message(
                 game,
-                &format!(
-                    "Selected Barbarian Armies that may NOT move: {}",
+                Message::new(
+                    "log.rome.barbarians_may_not_move",
+                    "Selected Barbarian Armies that may NOT move: {positions}",
+                )
+                .param(
+                    "positions",
                     may_not_move
                         .iter()
                         .map(ToString::to_string)
                         .collect_vec()
-                        .join(", ")
+                        .join(", "),
                 ),
             );
             for pos in may_not_move {
@@ -14,6 +14,7 @@ use crate::events::EventOrigin;
 use crate::game::Game;
 use crate::leader::{Leader, LeaderInfo, leader_position};
 use crate::leader_ability::{LeaderAbility, activate_leader_city, can_activate_leader_city};
+use crate::localization::Message;
 use crate::log::{ActionLogEntry, add_start_turn_action_if_needed};
 use crate::map::{Block, Terrain, block_for_position, capital_city_position};
 use crate::movement::MoveUnits;
@@ -97,18 +98,20 @@ fn ship_construction() -> SpecialAdvanceInfo {
                 unload_units += &unit.unit_type;
             }
             if !unload_units.is_empty() {
-                s.log(
+                s.log_message(
                     game,
-                    &format!("Unloaded {} from ships", unload_units.to_string(None)),
+                    Message::new("log.vikings.unload", "Unloaded {units} from ships")
+                        .param("units", unload_units.to_string(None)),
                 );
             }
-            s.log(
+            s.log_message(
                 game,
-                &format!(
-                    "Converted {} to {}",
-                    Units::new(0, 0, all_ships.len() as u8, 0, 0, None).to_string(None),
-                    units.to_string(None)
-                ),
+                Message::new("log.vikings.convert_ships", "Converted {ships} to {units}")
+                    .param(
+                        "ships",
+                        Units::new(0, 0, all_ships.len() as u8, 0, 0, None).to_string(None),
+                    )
+                    .param("units", units.to_string(None)),
             );
         },
     )
@@ -224,7 +227,10 @@ pub(crate) fn lose_raid_resource() -> Ability {
         },
         |game, s, ()| {
             add_start_turn_action_if_needed(game, s.player_index);
-            s.log(game, &format!("Lose {}", s.choice[0]));
+            s.log_message(
+                game,
+                Message::new("log.lose", "Lose {choice}").param("choice", &s.choice[0]),
+            );
         },
     )
     .build()
@@ -243,10 +249,12 @@ pub(crate) fn add_raid_bonus(game: &mut Game, player: usize, routes: &[TradeRout
                 .insert(RAID.to_string(), "true".to_string())
                 .is_none()
             {
-                game.log(
+                game.log_message(
                     player,
                     &EventOrigin::SpecialAdvance(SpecialAdvance::Raiding),
-                    &format!("Raided {opponent_name} at {position}"),
+                    Message::new("log.vikings.raid", "Raided {player} at {position}")
+                        .param("player", &opponent_name)
+                        .param("position", position),
                 );
             }
         }
@@ -274,12 +282,21 @@ fn runes() -> SpecialAdvanceInfo {
                 let p = game.player_mut(s.player_index);
                 p.destroyed_structures.add_building(Building::Obelisk);
                 p.gain_objective_victory_points(1.0, &s.origin);
-                s.log(
+                s.log_message(
                     game,
-                    "Converted an Obelisk to a Rune Stone for 1 objective point",
+                    Message::new(
+                        "log.vikings.converted_an_obelisk_to_a_rune",
+                        "Converted an Obelisk to a Rune Stone for 1 objective point",
+                    ),
                 );
             } else {
-                s.log(game, "Did not convert an Obelisk to a Rune Stone");
+                s.log_message(
+                    game,
+                    Message::new(
+                        "log.vikings.did_not_convert_an_obelisk_to",
+                        "Did not convert an Obelisk to a Rune Stone",
+                    ),
+                );
             }
         },
     )
@@ -445,7 +462,14 @@ fn use_legendary_explorer(b: AbilityBuilder) -> AbilityBuilder {
                 .or_insert(Data::Positions(Vec::new()))
                 .positions_mut()
                 .push(position);
-            p.log(game, &format!("Place an explorer token at {position}"));
+            p.log_message(
+                game,
+                Message::new(
+                    "log.vikings.explorer_token",
+                    "Place an explorer token at {position}",
+                )
+                .param("position", position),
+            );
         },
     )
 }
@@ -494,7 +518,10 @@ fn ragnar() -> LeaderInfo {
         .add_combat_strength_listener(106, |game, c, s, r| {
             if c.has_leader(r, game) && c.is_disembarking_attacker(r, game) {
                 s.extra_combat_value += 2;
-                s.roll_log.push("Ragnar adds 2 combat value".to_string());
+                s.roll_log.push(Message::new(
+                    "log.combat_value.ragnar_adds_2_combat_value",
+                    "Ragnar adds 2 combat value",
+                ));
             }
         })
         .build(),
@@ -220,6 +220,18 @@ pub enum CustomActionType {
     Danegeld,
     LegendaryExplorer,
     NewColonies,
+
+    // Egypt
+    ExpeditionToPunt,
+
+    // Persia
+    Administrator,
+
+    // Team play
+    TeamTrade,
+
+    // Trading
+    Trade,
 }
 
 impl CustomActionType {
@@ -1,6 +1,7 @@
 use crate::content::incidents::great_diplomat::{DiplomaticRelations, Negotiations};
 use crate::events::EventOrigin;
 use crate::game::Game;
+use crate::localization::Message;
 use crate::wonder::Wonder;
 use serde::{Deserialize, Serialize};
 
@@ -61,7 +62,7 @@ pub enum PermanentEffect {
 
 impl PermanentEffect {
     #[must_use]
-    pub fn description(&self, game: &Game) -> Vec<String> {
+    pub fn description(&self, game: &Game) -> Vec<Message> {
         let cache = &game.cache;
         match self {
             PermanentEffect::Pestilence => incident_effect(game, 1),
@@ -74,55 +75,77 @@ impl PermanentEffect {
                 CollectEffect::MassProduction => incident_effect(game, 29),
             },
             PermanentEffect::RevolutionLoseAction(p) => {
-                vec![format!(
-                    "{} loses an action due to Civil War",
-                    game.player_name(*p)
-                )]
+                vec![
+                    Message::new(
+                        "effect.revolution_lose_action",
+                        "{player} loses an action due to Civil War",
+                    )
+                    .param("player", game.player_name(*p)),
+                ]
             }
             PermanentEffect::PublicWonderCard(w) => {
-                vec![format!(
-                    "Public wonder for anyone to draw: {}",
-                    cache.get_wonder(*w).name()
-                )]
+                vec![
+                    Message::new(
+                        "effect.public_wonder_card",
+                        "Public wonder for anyone to draw: {wonder}",
+                    )
+                    .param("wonder", cache.get_wonder(*w).name()),
+                ]
             }
             PermanentEffect::SolarEclipse => incident_effect(game, 41),
             PermanentEffect::TrojanHorse => incident_effect(game, 42),
             PermanentEffect::Anarchy(a) => {
-                vec![format!(
-                    "{} has lost {} advances (each worth 1 victory point) due to Anarchy",
-                    game.player_name(a.player),
-                    a.advances_lost
-                )]
+                vec![
+                    Message::new(
+                        "effect.anarchy",
+                        "{player} has lost {advances} advances \
+                        (each worth 1 victory point) due to Anarchy",
+                    )
+                    .param("player", game.player_name(a.player))
+                    .param("advances", a.advances_lost),
+                ]
             }
             PermanentEffect::DiplomaticRelations(r) => {
-                vec![format!(
-                    "{} (active player) and {} are in diplomatic relations \
-                    and cannot attack each other (unless they pay 2 culture tokens).",
-                    game.player_name(r.active_player),
-                    game.player_name(r.passive_player)
-                )]
+                vec![
+                    Message::new(
+                        "effect.diplomatic_relations",
+                        "{active} (active player) and {passive} are in diplomatic relations \
+                        and cannot attack each other (unless they pay 2 culture tokens).",
+                    )
+                    .param("active", game.player_name(r.active_player))
+                    .param("passive", game.player_name(r.passive_player)),
+                ]
             }
             // can also be 16, but that doesn't matter for the help text
             PermanentEffect::CulturalTakeover => civil_effect(game, 15),
             PermanentEffect::Negotiations(n) => {
-                vec![format!(
-                    "{} and {} are in negotiations. ({} turns left).",
-                    game.player_name(n.relations.active_player),
-                    game.player_name(n.relations.passive_player),
-                    n.remaining_turns
-                )]
+                vec![
+                    Message::new(
+                        "effect.negotiations",
+                        "{active} and {passive} are in negotiations. ({turns} turns left).",
+                    )
+                    .param("active", game.player_name(n.relations.active_player))
+                    .param("passive", game.player_name(n.relations.passive_player))
+                    .param("turns", n.remaining_turns),
+                ]
             }
             PermanentEffect::AssassinationLoseAction(p) => {
-                vec![format!(
-                    "{} loses an action due to Assassination",
-                    game.player_name(*p)
-                )]
+                vec![
+                    Message::new(
+                        "effect.assassination_lose_action",
+                        "{player} loses an action due to Assassination",
+                    )
+                    .param("player", game.player_name(*p)),
+                ]
             }
             PermanentEffect::GreatSeer(s) => {
-                let mut desc = vec![format!(
-                    "{} has been assigned the following objectives: ",
-                    game.player_name(s.player)
-                )];
+                let mut desc = vec![
+                    Message::new(
+                        "effect.great_seer",
+                        "{player} has been assigned the following objectives: ",
+                    )
+                    .param("player", game.player_name(s.player)),
+                ];
                 for o in &s.assigned_objectives {
                     let card = o.objective_card;
                     let name = if card == 0 {
@@ -131,7 +154,11 @@ impl PermanentEffect {
                         cache.get_objective_card(card).name()
                     };
 
-                    desc.push(format!("{}: {name}", game.player_name(o.player)));
+                    desc.push(
+                        Message::new("effect.great_seer_objective", "{player}: {objective}")
+                            .param("player", game.player_name(o.player))
+                            .param("objective", name),
+                    );
                 }
                 desc
             }
@@ -139,33 +166,35 @@ impl PermanentEffect {
     }
 }
 
-fn incident_effect(game: &Game, id: u8) -> Vec<String> {
+fn incident_effect(game: &Game, id: u8) -> Vec<Message> {
     event_help(game, &EventOrigin::Incident(id))
 }
 
-fn civil_effect(game: &Game, id: u8) -> Vec<String> {
+fn civil_effect(game: &Game, id: u8) -> Vec<Message> {
     event_help(game, &EventOrigin::CivilCard(id))
 }
 
 #[must_use]
-pub fn event_help(game: &Game, origin: &EventOrigin) -> Vec<String> {
-    let mut h = vec![origin.name(game)];
+pub fn event_help(game: &Game, origin: &EventOrigin) -> Vec<Message> {
+    let key = origin.message_key();
+    let mut h = vec![Message::new(&format!("{key}.name"), &origin.name(game))];
     let cache = &game.cache;
+    let description = |text: &str| vec![Message::new(&format!("{key}.description"), text)];
     let d = match origin {
-        EventOrigin::Advance(a) => vec![a.info(game).description.clone()],
-        EventOrigin::Wonder(w) => vec![game.cache.get_wonder(*w).description.clone()],
-        EventOrigin::Ability(b) => vec![cache.ability_description(b, game)],
-        EventOrigin::CivilCard(id) => vec![cache.get_civil_card(*id).description.clone()],
-        EventOrigin::TacticsCard(id) => vec![cache.get_tactics_card(*id).description.clone()],
+        EventOrigin::Advance(a) => description(&a.info(game).description),
+        EventOrigin::Wonder(w) => description(&game.cache.get_wonder(*w).description),
+        EventOrigin::Ability(b) => description(&cache.ability_description(b, game)),
+        EventOrigin::CivilCard(id) => description(&cache.get_civil_card(*id).description),
+        EventOrigin::TacticsCard(id) => description(&cache.get_tactics_card(*id).description),
         EventOrigin::Incident(id) => cache.get_incident(*id).description(game),
-        EventOrigin::Objective(name) => vec![cache.get_objective(name).description.clone()],
-        EventOrigin::LeaderAbility(l) => vec![
-            game.player(game.active_player())
+        EventOrigin::Objective(name) => description(&cache.get_objective(name).description),
+        EventOrigin::LeaderAbility(l) => description(
+            &game
+                .player(game.active_player())
                 .get_leader_ability(l)
-                .description
-                .clone(),
-        ],
-        EventOrigin::SpecialAdvance(s) => vec![s.info(game).description.clone()],
+                .description,
+        ),
+        EventOrigin::SpecialAdvance(s) => description(&s.info(game).description),
     };
     h.extend(d);
     h
@@ -19,32 +19,41 @@ use crate::content::incidents::great_persons::great_person_incidents;
 use crate::content::incidents::pandemics::pandemics_incidents;
 use crate::content::incidents::trade::trade_incidents;
 use crate::content::incidents::trojan::trojan_incidents;
-use crate::incident::Incident;
+use crate::incident::{Incident, IncidentCategory};
 use itertools::Itertools;
 use std::vec;
 
+///
+/// # Panics
+///
+/// Panics if incident ids are not unique
 #[must_use]
-pub(crate) fn get_all_uncached() -> Vec<Incident> {
+pub fn get_all_uncached() -> Vec<Incident> {
     let all = vec![
         // 1+
-        pestilence_incidents(),
+        (IncidentCategory::Disaster, pestilence_incidents()),
         // 9+
-        good_years_incidents(),
+        (IncidentCategory::Other, good_years_incidents()),
         // 29+
-        earthquake_incidents(),
+        (IncidentCategory::Disaster, earthquake_incidents()),
         // 34+
-        civil_war_incidents(),
+        (IncidentCategory::Other, civil_war_incidents()),
         // 41+
-        trojan_incidents(),
+        (IncidentCategory::Other, trojan_incidents()),
         // 45+
-        trade_incidents(),
+        (IncidentCategory::Trade, trade_incidents()),
         // 49+
-        pandemics_incidents(),
+        (IncidentCategory::Disaster, pandemics_incidents()),
         // 18+
-        great_person_incidents(),
+        (IncidentCategory::GreatPerson, great_person_incidents()),
     ]
     .into_iter()
-    .flatten()
+    .flat_map(|(category, incidents)| {
+        incidents.into_iter().map(move |mut incident| {
+            incident.category = category;
+            incident
+        })
+    })
     .collect_vec();
     assert_eq!(
         all.iter().unique_by(|i| i.id).count(),
@@ -9,6 +9,7 @@ use crate::game::Game;
 use crate::incident::{
     DecreaseMood, Incident, IncidentBaseEffect, IncidentBuilder, MoodModifier, decrease_mod_and_log,
 };
+use crate::localization::Message;
 use crate::payment::{PaymentConversion, PaymentConversionType};
 use crate::player::Player;
 use crate::player_events::IncidentTarget;
@@ -18,6 +19,7 @@ use crate::status_phase::{
     ChangeGovernmentOption, add_change_government, can_change_government_for_free, get_status_phase,
 };
 use crate::unit::kill_units;
+use crate::victory_points::gain_victory_points_message;
 use crate::wonder::draw_public_wonder;
 use itertools::Itertools;
 
@@ -135,7 +137,13 @@ fn revolution() -> Incident {
     b = b.add_simple_incident_listener(IncidentTarget::ActivePlayer, 2, |game, p, i| {
         if can_lose_action(game) && i.player.sacrifice == 0 {
             if get_status_phase(game).is_some() {
-                p.log(game, "Lose an action for the next turn");
+                p.log_message(
+                    game,
+                    Message::new(
+                        "log.incident.lose_an_action_for_the_next",
+                        "Lose an action for the next turn",
+                    ),
+                );
                 game.permanent_effects
                     .push(PermanentEffect::RevolutionLoseAction(p.index));
             } else {
@@ -238,14 +246,7 @@ fn uprising() -> Incident {
             let pile = &s.choice[0];
             let v = pile.amount() as f32 / 2_f32;
             player.gain_event_victory_points(v, &s.origin);
-            s.log(
-                game,
-                &format!(
-                    "Gain {} victory point{}",
-                    v,
-                    if v == 1.0 { "" } else { "s" }
-                ),
-            );
+            s.log_message(game, gain_victory_points_message(v));
         },
     )
     .build()
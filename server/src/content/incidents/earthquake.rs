@@ -7,6 +7,7 @@ use crate::content::persistent_events::{
 use crate::events::{EventOrigin, EventPlayer};
 use crate::game::Game;
 use crate::incident::{DecreaseMood, Incident, IncidentBaseEffect, MoodModifier};
+use crate::localization::Message;
 use crate::player_events::{IncidentInfo, IncidentTarget};
 use crate::position::Position;
 use crate::structure::Structure;
@@ -52,7 +53,14 @@ fn volcano() -> Incident {
         |game, s, _| {
             let pos = s.choice[0];
             let player_index = s.player_index;
-            s.log(game, &format!("Selected city {pos} to be destroyed"));
+            s.log_message(
+                game,
+                Message::new(
+                    "log.earthquake.select_city",
+                    "Selected city {position} to be destroyed",
+                )
+                .param("position", pos),
+            );
             let city = game.player(player_index).get_city(pos);
             let buildings = city.pieces.buildings(None);
             let wonders = city.pieces.wonders.iter().copied().collect_vec();
@@ -187,10 +195,14 @@ fn destroy_city_center(game: &mut Game, position: Position, origin: &EventOrigin
     );
     p.gain_event_victory_points(2.0, origin);
     p.destroyed_structures.cities += 1;
-    game.log(
+    game.log_message(
         owner,
         origin,
-        &format!("Gain 2 points for the city center at {position}"),
+        Message::new(
+            "log.earthquake.city_center_points",
+            "Gain 2 points for the city center at {position}",
+        )
+        .param("position", position),
     );
 }
 
@@ -206,10 +218,15 @@ fn destroy_building(game: &mut Game, b: Building, position: Position, origin: &E
     o.destroyed_structures.add_building(b);
     let player = EventPlayer::new(city_owner, origin.clone());
     lose_building(game, &player, b, position);
-    game.log(
+    game.log_message(
         owner,
         origin,
-        &format!("Gain 2 points for the {b} at {position}"),
+        Message::new(
+            "log.earthquake.building_points",
+            "Gain 2 points for the {building} at {position}",
+        )
+        .param("building", b)
+        .param("position", position),
     );
 }
 
@@ -224,9 +241,15 @@ fn destroy_wonder_for_points(
 
     let a = wonder.info(game).owned_victory_points;
     p.get_mut(game).gain_event_victory_points(a as f32, origin);
-    p.log(
+    p.log_message(
         game,
-        &format!("Gain {a} points for the {} at {position}", wonder.name()),
+        Message::new(
+            "log.earthquake.wonder_points",
+            "Gain {points} points for the {wonder} at {position}",
+        )
+        .param("points", a)
+        .param("wonder", wonder.name())
+        .param("position", position),
     );
 }
 
@@ -7,6 +7,7 @@ use crate::content::effects::PermanentEffect;
 use crate::content::persistent_events::UnitsRequest;
 use crate::game::Game;
 use crate::incident::{DecreaseMood, Incident, IncidentBaseEffect, MoodModifier};
+use crate::localization::Message;
 use crate::log::ActionLogBalance;
 use crate::player::Player;
 use crate::player_events::IncidentTarget;
@@ -69,7 +70,10 @@ fn pestilence() -> Incident {
             {
                 game.permanent_effects
                     .retain(|e| e != &PermanentEffect::Pestilence);
-                p.log(game, "Pestilence removed");
+                p.log_message(
+                    game,
+                    Message::new("log.incident.pestilence_removed", "Pestilence removed"),
+                );
             }
         },
     )
@@ -151,7 +155,13 @@ fn epidemics() -> Incident {
 
 pub(crate) fn kill_incident_units(game: &mut Game, s: &SelectedMultiChoice<Vec<u32>>) {
     if s.choice.is_empty() {
-        s.log(game, "Declined to kill units");
+        s.log_message(
+            game,
+            Message::new(
+                "log.incident.declined_to_kill_units",
+                "Declined to kill units",
+            ),
+        );
         return;
     }
 
@@ -222,7 +232,10 @@ pub(crate) fn famine(
             }
             player.lose_resources(game, ResourcePile::food(lost), ActionLogBalance::Loss);
 
-            player.log(game, &format!("Lost {lost} food",));
+            player.log_message(
+                game,
+                Message::new("log.famine.lost_food", "Lost {food} food").param("food", lost),
+            );
         })
         .add_decrease_mood(target, MoodModifier::MakeAngry, move |p, game, i| {
             if player_pred2(p) && i.player.payment.is_empty() {
@@ -1,5 +1,6 @@
 use crate::content::persistent_events::{PositionRequest, ResourceRewardRequest};
 use crate::incident::{Incident, IncidentBaseEffect, IncidentBuilder};
+use crate::localization::Message;
 use crate::player::gain_unit;
 use crate::player_events::IncidentTarget;
 use crate::resource::ResourceType;
@@ -183,12 +184,13 @@ pub(crate) fn select_player_to_gain_settler(mut b: IncidentBuilder) -> IncidentB
         |p, _, _| p.available_units().settlers > 0 && !p.cities.is_empty(),
         12,
         |game, c, i| {
-            c.log(
+            c.log_message(
                 game,
-                &format!(
-                    "{} was selected to gain 1 settler.",
-                    game.player_name(c.choice)
-                ),
+                Message::new(
+                    "log.good_year.selected",
+                    "{player} was selected to gain 1 settler.",
+                )
+                .param("player", game.player_name(c.choice)),
             );
             i.selected_player = Some(c.choice);
         },
@@ -11,6 +11,7 @@ use crate::content::incidents::great_persons::GreatPersonType;
 use crate::content::incidents::great_persons::{great_person_card, tech_great_person_description};
 use crate::content::persistent_events::HandCardsRequest;
 use crate::game::Game;
+use crate::localization::Message;
 use crate::player::Player;
 use crate::player_events::CostInfo;
 use crate::playing_actions::PlayingActionType;
@@ -46,14 +47,23 @@ pub(crate) fn great_engineer() -> ActionCard {
             if s.choice {
                 game.permanent_effects
                     .push(Construct(ConstructEffect::GreatEngineer));
-                s.log(
+                s.log_message(
                     game,
-                    "Great Engineer: You may build a building in a city without \
+                    Message::new(
+                        "log.great_person.great_engineer_you_may_build_a",
+                        "Great Engineer: You may build a building in a city without \
                     spending an action and without activating it.",
+                    ),
                 );
                 gain_action(game, &s.player()); // to offset the action spent for building
             } else {
-                s.log(game, "Great Engineer: You decided not to use the ability.");
+                s.log_message(
+                    game,
+                    Message::new(
+                        "log.great_person.great_engineer_you_decided_not_to",
+                        "Great Engineer: You decided not to use the ability.",
+                    ),
+                );
             }
         },
     )
@@ -174,7 +184,7 @@ fn playable_wonders(game: &Game, player: &Player) -> Vec<Wonder> {
 
 fn architect_wonder_cost(game: &Game, player: &Player, w: Wonder) -> CostInfo {
     let mut info = wonder_cost(game, player, w);
-    info.cost.default.culture_tokens -= 3;
+    info.cost.default.culture_tokens = info.cost.default.culture_tokens.saturating_sub(3);
     info.ignore_required_advances = true;
     info.ignore_action_cost = true; // we already paid for the action with the architect card
     info
@@ -5,6 +5,7 @@ use crate::content::effects::PermanentEffect;
 use crate::content::incidents::great_persons::{GreatPersonType, great_person_card};
 use crate::game::Game;
 use crate::incident::IncidentBuilder;
+use crate::localization::Message;
 use crate::player_events::IncidentTarget;
 use crate::utils::remove_element_by;
 use serde::{Deserialize, Serialize};
@@ -29,7 +30,13 @@ pub(crate) fn great_diplomat() -> ActionCard {
         |e| &mut e.play_action_card,
         0,
         |game, p, _| {
-            p.log(game, "Ended diplomatic relations.");
+            p.log_message(
+                game,
+                Message::new(
+                    "log.great_person.ended_diplomatic_relations",
+                    "Ended diplomatic relations.",
+                ),
+            );
             remove_element_by(&mut game.permanent_effects, |e| {
                 matches!(e, PermanentEffect::DiplomaticRelations(_))
             });
@@ -77,12 +84,13 @@ pub(crate) fn choose_diplomat_partner(b: IncidentBuilder) -> IncidentBuilder {
         |_, _, _| true,
         1,
         |game, s, _| {
-            s.log(
+            s.log_message(
                 game,
-                &format!(
-                    "Initiated diplomatic relations with {}",
-                    game.player_name(s.choice),
-                ),
+                Message::new(
+                    "log.great_diplomat.initiated",
+                    "Initiated diplomatic relations with {player}",
+                )
+                .param("player", game.player_name(s.choice)),
             );
             game.permanent_effects
                 .push(PermanentEffect::DiplomaticRelations(
@@ -99,12 +107,13 @@ pub(crate) fn use_diplomatic_relations() -> Ability {
             2,
             |game, p, _| {
                 if let Some(partner) = diplomatic_relations_partner(game, p.index) {
-                    p.log(
+                    p.log_message(
                         game,
-                        &format!(
-                            "Diplomatic relations with {} ended with a surprise attack.",
-                            game.player_name(partner),
-                        ),
+                        Message::new(
+                            "log.great_diplomat.surprise_attack",
+                            "Diplomatic relations with {player} ended with a surprise attack.",
+                        )
+                        .param("player", game.player_name(partner)),
                     );
                     remove_element_by(&mut game.permanent_effects, |e| {
                         matches!(e, PermanentEffect::DiplomaticRelations(_))
@@ -9,6 +9,7 @@ use crate::content::persistent_events::{PaymentRequest, PositionRequest};
 use crate::events::{EventOrigin, EventPlayer, check_event_origin};
 use crate::explore::move_to_unexplored_block;
 use crate::game::Game;
+use crate::localization::Message;
 use crate::map::{BlockPosition, block_has_player_city, block_tiles, get_map_setup};
 use crate::payment::PaymentOptions;
 use crate::player::Player;
@@ -45,9 +46,22 @@ pub(crate) fn great_explorer() -> ActionCard {
             |game, s, a| {
                 let pos = s.choice.first().copied();
                 if let Some(pos) = pos {
-                    s.log(game, &format!("Decided to build a city {pos}",));
+                    s.log_message(
+                        game,
+                        Message::new(
+                            "log.great_explorer.build_city",
+                            "Decided to build a city {position}",
+                        )
+                        .param("position", pos),
+                    );
                 } else {
-                    s.log(game, "decided not to build a city");
+                    s.log_message(
+                        game,
+                        Message::new(
+                            "log.great_person.decided_not_to_build_a_city",
+                            "decided not to build a city",
+                        ),
+                    );
                 }
                 a.selected_position = pos;
             },
@@ -80,10 +94,20 @@ pub(crate) fn explore_adjacent_block(builder: ActionCardBuilder) -> ActionCardBu
         |game, p, _| Some(action_explore_request(game, p.index)),
         |game, s, a| {
             let Some(&position) = s.choice.first() else {
-                s.log(game, "Decided not to explore");
+                s.log_message(
+                    game,
+                    Message::new(
+                        "log.great_person.decided_not_to_explore",
+                        "Decided not to explore",
+                    ),
+                );
                 return;
             };
-            s.log(game, &format!("Explored {position}"));
+            s.log_message(
+                game,
+                Message::new("log.great_explorer.explored", "Explored {position}")
+                    .param("position", position),
+            );
             let dest = game
                 .map
                 .unexplored_blocks
@@ -106,7 +130,13 @@ fn place_city_request(
         .get(game)
         .can_afford(&city_cost(p.get(game), check_event_origin()))
     {
-        p.log(game, "Player cannot afford to build a city");
+        p.log_message(
+            game,
+            Message::new(
+                "log.great_person.player_cannot_afford_to_build_a",
+                "Player cannot afford to build a city",
+            ),
+        );
     }
 
     let choices = positions
@@ -16,8 +16,10 @@ use crate::content::incidents::great_warlord::great_warlord;
 use crate::content::persistent_events::{
     AdvanceRequest, HandCardsRequest, PaymentRequest, PositionRequest,
 };
+use crate::events::EventPlayer;
 use crate::game::Game;
 use crate::incident::{Incident, IncidentBaseEffect, IncidentBuilder};
+use crate::localization::Message;
 use crate::payment::PaymentOptions;
 use crate::player::Player;
 use crate::player_events::IncidentTarget;
@@ -115,14 +117,27 @@ fn incident(
                         "Pay to gain the Action Card",
                     )])
                 } else {
-                    player.log(game, &format!("Cannot afford to buy {name}"));
+                    player.log_message(
+                        game,
+                        Message::new(
+                            "log.great_persons.cannot_afford",
+                            "Cannot afford to buy {name}",
+                        )
+                        .param("name", &name),
+                    );
                     None
                 }
             },
             move |game, s, i| {
                 let pile = &s.choice[0];
                 if pile.is_empty() {
-                    s.log(game, "Declined to gain the Action Card");
+                    s.log_message(
+                        game,
+                        Message::new(
+                            "log.great_person.declined_to_gain_the_action_card",
+                            "Declined to gain the Action Card",
+                        ),
+                    );
                     return;
                 }
                 game.player_mut(s.player_index).action_cards.push(card_id);
@@ -214,7 +229,13 @@ fn great_artist() -> ActionCard {
                 .map(|c| c.position)
                 .collect_vec();
             if cities.is_empty() {
-                p.log(game, "No cities to make happy");
+                p.log_message(
+                    game,
+                    Message::new(
+                        "log.great_person.no_cities_to_make_happy",
+                        "No cities to make happy",
+                    ),
+                );
             }
             let needed = 1..=1;
             Some(PositionRequest::new(cities, needed, "Make a city Happy"))
@@ -243,38 +264,26 @@ fn great_prophet() -> ActionCard {
     .add_position_request(
         |e| &mut e.play_action_card,
         1,
-        |game, p, _| {
-            let player = p.get(game);
-            if !player.is_building_available(Building::Temple, game) {
-                p.log(
-                    game,
-                    "Cannot build a Temple: it is not available or already built",
-                );
-                return None;
-            }
-            if !player.can_afford(&temple_cost(game, player)) {
-                p.log(game, "Cannot build a Temple: not enough resources");
-                return None;
-            }
-
-            let cities = player
-                .cities
-                .iter()
-                .filter(|c| c.pieces.temple.is_none())
-                .map(|c| c.position)
-                .collect_vec();
-            if cities.is_empty() {
-                p.log(game, "No cities can build a Temple");
-            }
-            let needed = 0..=1;
-            Some(PositionRequest::new(cities, needed, "Build a Temple"))
-        },
+        |game, p, _| temple_request(game, p),
         |game, s, a| {
             let pos = s.choice.first().copied();
             if let Some(pos) = pos {
-                s.log(game, &format!("Decided to build a Temple at {pos}",));
+                s.log_message(
+                    game,
+                    Message::new(
+                        "log.great_persons.build_temple",
+                        "Decided to build a Temple at {position}",
+                    )
+                    .param("position", pos),
+                );
             } else {
-                s.log(game, "Declined to build a Temple");
+                s.log_message(
+                    game,
+                    Message::new(
+                        "log.great_person.declined_to_build_a_temple",
+                        "Declined to build a Temple",
+                    ),
+                );
             }
             a.selected_position = pos;
         },
@@ -292,7 +301,13 @@ fn great_prophet() -> ActionCard {
         |game, s, a| {
             let pile = s.choice[0].clone();
             if pile.is_empty() {
-                s.log(game, "Declined to build the Temple");
+                s.log_message(
+                    game,
+                    Message::new(
+                        "log.great_person.declined_to_build_the_temple",
+                        "Declined to build the Temple",
+                    ),
+                );
                 return;
             }
 
@@ -310,6 +325,48 @@ fn great_prophet() -> ActionCard {
     .build()
 }
 
+fn temple_request(game: &mut Game, p: &EventPlayer) -> Option<PositionRequest> {
+    let player = p.get(game);
+    if !player.is_building_available(Building::Temple, game) {
+        p.log_message(
+            game,
+            Message::new(
+                "log.great_person.cannot_build_a_temple_it_is",
+                "Cannot build a Temple: it is not available or already built",
+            ),
+        );
+        return None;
+    }
+    if !player.can_afford(&temple_cost(game, player)) {
+        p.log_message(
+            game,
+            Message::new(
+                "log.great_person.cannot_build_a_temple_not_enough",
+                "Cannot build a Temple: not enough resources",
+            ),
+        );
+        return None;
+    }
+
+    let cities = player
+        .cities
+        .iter()
+        .filter(|c| c.pieces.temple.is_none())
+        .map(|c| c.position)
+        .collect_vec();
+    if cities.is_empty() {
+        p.log_message(
+            game,
+            Message::new(
+                "log.great_person.no_cities_can_build_a_temple",
+                "No cities can build a Temple",
+            ),
+        );
+    }
+    let needed = 0..=1;
+    Some(PositionRequest::new(cities, needed, "Build a Temple"))
+}
+
 fn temple_cost(game: &Game, player: &Player) -> PaymentOptions {
     player
         .building_cost(game, Building::Temple, game.execute_cost_trigger())
@@ -456,9 +513,21 @@ fn great_athlete() -> ActionCard {
         |game, s, a| {
             a.answer = Some(s.choice);
             if s.choice {
-                s.log(game, "Decided to convert culture to mood tokens");
+                s.log_message(
+                    game,
+                    Message::new(
+                        "log.great_person.decided_to_convert_culture_to_mood",
+                        "Decided to convert culture to mood tokens",
+                    ),
+                );
             } else {
-                s.log(game, "Decided to convert mood to culture tokens");
+                s.log_message(
+                    game,
+                    Message::new(
+                        "log.great_person.decided_to_convert_mood_to_culture",
+                        "Decided to convert mood to culture tokens",
+                    ),
+                );
             }
         },
     )
@@ -491,7 +560,13 @@ fn great_athlete() -> ActionCard {
         |game, s, _| {
             let from = &s.choice[0];
             if from.is_empty() {
-                s.log(game, "Declined to convert culture to mood");
+                s.log_message(
+                    game,
+                    Message::new(
+                        "log.great_person.declined_to_convert_culture_to_mood",
+                        "Declined to convert culture to mood",
+                    ),
+                );
                 return;
             }
             let to = if from.culture_tokens > 0 {
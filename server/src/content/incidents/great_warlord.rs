@@ -8,6 +8,7 @@ use crate::content::incidents::great_persons::{
 };
 use crate::game::GameState;
 use crate::game::GameState::Movement;
+use crate::localization::Message;
 use crate::movement::MoveState;
 use std::mem;
 
@@ -60,9 +61,10 @@ pub(crate) fn use_great_warlord() -> Ability {
                     && r.combat.attacker() == p.index
                 {
                     r.attacker_strength.extra_combat_value += 2;
-                    r.attacker_strength
-                        .roll_log
-                        .push("Player gets +2 combat value for Great Warlord".to_string());
+                    r.attacker_strength.roll_log.push(Message::new(
+                        "log.combat_value.great_warlord",
+                        "Player gets +2 combat value for Great Warlord",
+                    ));
                 }
             },
         )
@@ -10,12 +10,14 @@ use crate::content::persistent_events::{
 };
 use crate::game::Game;
 use crate::incident::{DecreaseMood, Incident, IncidentBaseEffect, MoodModifier};
+use crate::localization::Message;
 use crate::map::{Map, Terrain};
 use crate::objective_card::discard_objective_card;
 use crate::player::Player;
 use crate::player_events::{IncidentInfo, IncidentTarget};
 use crate::position::Position;
 use crate::resource::ResourceType;
+use crate::victory_points::gain_victory_points_message;
 use itertools::Itertools;
 use std::ops::RangeInclusive;
 
@@ -36,12 +38,13 @@ fn pandemics() -> Incident {
         IncidentTarget::AllPlayers,
         2,
         |game, p, i| {
-            p.log(
+            p.log_message(
                 game,
-                &format!(
-                    "Lose a total of {} units, cards, and resources",
-                    pandemics_cost(p.get(game))
-                ),
+                Message::new(
+                    "log.pandemics.lose_total",
+                    "Lose a total of {amount} units, cards, and resources",
+                )
+                .param("amount", pandemics_cost(p.get(game))),
             );
 
             let player = p.get(game);
@@ -119,7 +122,10 @@ fn pandemics() -> Incident {
             )])
         },
         |game, s, _| {
-            s.log(game, &format!("Lose {}", s.choice[0]));
+            s.log_message(
+                game,
+                Message::new("log.lose", "Lose {choice}").param("choice", &s.choice[0]),
+            );
         },
     )
     .build()
@@ -196,7 +202,7 @@ fn black_death() -> Incident {
         |game, s, _| {
             kill_incident_units(game, s);
             let vp = s.choice.len() as f32;
-            s.log(game, &format!("Gain {vp} victory points"));
+            s.log_message(game, gain_victory_points_message(vp));
             game.player_mut(s.player_index)
                 .gain_event_victory_points(vp, &s.origin);
         },
@@ -266,10 +272,19 @@ fn fire() -> Incident {
                 .collect_vec();
             if cities.is_empty() {
                 if player.resources.wood > 0 {
-                    p.log(game, "Lose 1 wood");
+                    p.log_message(
+                        game,
+                        Message::new("log.incident.lose_1_wood", "Lose 1 wood"),
+                    );
                     return None;
                 }
-                p.log(game, "No cities on a Forest and no wood to lose");
+                p.log_message(
+                    game,
+                    Message::new(
+                        "log.incident.no_cities_on_a_forest_and",
+                        "No cities on a Forest and no wood to lose",
+                    ),
+                );
                 return None;
             }
             let needed = 1..=1;
@@ -3,6 +3,7 @@ use crate::city_pieces::{Building, gain_building};
 use crate::content::persistent_events::{PositionRequest, ResourceRewardRequest};
 use crate::game::Game;
 use crate::incident::{Incident, IncidentBaseEffect, PassedIncident};
+use crate::localization::Message;
 use crate::player::Player;
 use crate::player_events::IncidentTarget;
 use crate::resource_pile::ResourcePile;
@@ -123,7 +124,13 @@ fn reformation() -> Incident {
             .count()
             > 1
         {
-            p.log(game, "Has no temples: Select a player to execute the event");
+            p.log_message(
+                game,
+                Message::new(
+                    "log.incident.has_no_temples_select_a_player",
+                    "Has no temples: Select a player to execute the event",
+                ),
+            );
         }
     })
     // select a player to execute the incident
@@ -135,12 +142,13 @@ fn reformation() -> Incident {
         |game, s, i| {
             // pass the event to the player itself
             i.passed = Some(PassedIncident::NewPlayer(s.choice));
-            s.log(
+            s.log_message(
                 game,
-                &format!(
-                    "Selected {} to execute the event",
-                    game.player_name(s.choice)
-                ),
+                Message::new(
+                    "log.incident.select_player",
+                    "Selected {player} to execute the event",
+                )
+                .param("player", game.player_name(s.choice)),
             );
         },
     )
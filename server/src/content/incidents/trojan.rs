@@ -10,6 +10,7 @@ use crate::events::{EventOrigin, EventPlayer};
 use crate::game::Game;
 use crate::incident::{Incident, IncidentBaseEffect};
 use crate::leader::Leader;
+use crate::localization::Message;
 use crate::player::{Player, can_add_army_unit, gain_unit};
 use crate::player_events::{IncidentInfo, IncidentTarget};
 use crate::position::Position;
@@ -64,13 +65,22 @@ pub(crate) fn decide_trojan_horse() -> Ability {
             },
             |game, s, c| {
                 if s.choice[0].is_empty() {
-                    s.log(game, "Declined to activate the Trojan Horse");
+                    s.log_message(
+                        game,
+                        Message::new(
+                            "log.incident.declined_to_activate_the_trojan_horse",
+                            "Declined to activate the Trojan Horse",
+                        ),
+                    );
                 } else {
                     let player = game.player_mut(s.player_index);
                     player.gain_event_victory_points(1_f32, &EventOrigin::Incident(42));
-                    s.log(
+                    s.log_message(
                         game,
-                        "Activated the Trojan Horse and gained 1 victory point",
+                        Message::new(
+                            "log.incident.activated_the_trojan_horse_and_gained",
+                            "Activated the Trojan Horse and gained 1 victory point",
+                        ),
                     );
                     game.permanent_effects
                         .retain(|e| !matches!(e, PermanentEffect::TrojanHorse));
@@ -123,10 +133,13 @@ pub(crate) fn solar_eclipse_end_combat() -> Ability {
                     };
                     game.player_mut(winner)
                         .gain_event_victory_points(1_f32, &EventOrigin::Incident(41));
-                    game.log(
+                    game.log_message(
                         winner,
                         &player.origin,
-                        "Gain 1 victory point for the Solar Eclipse",
+                        Message::new(
+                            "log.incident.gain_1_victory_point_for_the",
+                            "Gain 1 victory point for the Solar Eclipse",
+                        ),
                     );
                 }
             },
@@ -154,12 +167,21 @@ fn guillotine() -> Incident {
         },
         |game, s, i| {
             if s.choice {
-                s.log(game, "Chose to select a new leader");
+                s.log_message(
+                    game,
+                    Message::new(
+                        "log.incident.chose_to_select_a_new_leader",
+                        "Chose to select a new leader",
+                    ),
+                );
                 i.selected_player = Some(s.player_index);
             } else {
-                s.log(
+                s.log_message(
                     game,
-                    "Gained 2 victory points instead of choosing a new leader",
+                    Message::new(
+                        "log.incident.gained_2_victory_points_instead_of",
+                        "Gained 2 victory points instead of choosing a new leader",
+                    ),
                 );
                 game.player_mut(s.player_index)
                     .gain_event_victory_points(2_f32, &s.origin);
@@ -172,7 +194,7 @@ fn guillotine() -> Incident {
         |game, p, i| {
             new_leader_chosen(p.index, i).then(|| {
                 PositionRequest::new(
-                    new_leader_positions(p.get(game)),
+                    new_leader_positions(game, p.get(game)),
                     1..=1,
                     "Select a city to choose a new leader in",
                 )
@@ -180,7 +202,11 @@ fn guillotine() -> Incident {
         },
         |game, s, i| {
             let pos = s.choice[0];
-            s.log(game, &format!("Chose a new leader in {pos}"));
+            s.log_message(
+                game,
+                Message::new("log.trojan.new_leader", "Chose a new leader in {position}")
+                    .param("position", pos),
+            );
             i.selected_position = Some(pos);
         },
     )
@@ -207,29 +233,40 @@ fn guillotine() -> Incident {
         },
     )
     .add_simple_incident_listener(IncidentTarget::ActivePlayer, 0, |game, p, _i| {
-        let available_leaders = &p.get(game).available_leaders;
-        if !available_leaders.is_empty() {
-            p.log(
-                game,
-                &format!(
-                    "Lose leaders due to the Guillotine: {}",
-                    available_leaders.iter().map(|l| l.name(game)).join(", ")
-                ),
-            );
-            p.get_mut(game).available_leaders = vec![];
-        }
+        lose_available_leaders(game, p);
     })
     .build()
 }
 
+fn lose_available_leaders(game: &mut Game, p: &EventPlayer) {
+    let available_leaders = &p.get(game).available_leaders;
+    if !available_leaders.is_empty() {
+        p.log_message(
+            game,
+            Message::new(
+                "log.trojan.guillotine",
+                "Lose leaders due to the Guillotine: {leaders}",
+            )
+            .param(
+                "leaders",
+                available_leaders.iter().map(|l| l.name(game)).join(", "),
+            ),
+        );
+        p.get_mut(game).available_leaders = vec![];
+    }
+}
+
 fn should_choose_new_leader(game: &mut Game, player: &EventPlayer) -> Option<String> {
     kill_leader(game, player);
 
     let p = player.get(game);
-    if p.available_leaders.is_empty() || new_leader_positions(p).is_empty() {
-        player.log(
+    if p.available_leaders.is_empty() || new_leader_positions(game, p).is_empty() {
+        player.log_message(
             game,
-            "Has no leaders left to choose from after the Guillotine - gained 2 victory points",
+            Message::new(
+                "log.incident.has_no_leaders_left_to_choose",
+                "Has no leaders left to choose from after the Guillotine - gained 2 victory points",
+            ),
         );
         player
             .get_mut(game)
@@ -258,11 +295,11 @@ fn kill_leader(game: &mut Game, player: &EventPlayer) {
     }
 }
 
-fn new_leader_positions(player: &Player) -> Vec<Position> {
+fn new_leader_positions(game: &Game, player: &Player) -> Vec<Position> {
     player
         .cities
         .iter()
-        .filter_map(|c| can_add_army_unit(player, c.position).then_some(c.position))
+        .filter_map(|c| can_add_army_unit(game, player, c.position).then_some(c.position))
         .collect()
 }
 
@@ -302,12 +339,13 @@ fn anarchy() -> Incident {
         let lost = old - player.advances.len();
         player.gain_event_victory_points(lost as f32, &p.origin);
         if lost > 0 {
-            p.log(
+            p.log_message(
                 game,
-                &format!(
-                    "Lose {lost} government advances due to Anarchy - \
-                     adding {lost} victory points",
-                ),
+                Message::new(
+                    "log.trojan.anarchy",
+                    "Lose {lost} government advances due to Anarchy - adding {lost} victory points",
+                )
+                .param("lost", lost),
             );
 
             game.permanent_effects
@@ -338,10 +376,13 @@ pub(crate) fn anarchy_advance() -> Ability {
                     }
                 }) && p.index == a.player
                 {
-                    p.log(
+                    p.log_message(
                         game,
-                        "Gain a government advance, taking a game event token \
+                        Message::new(
+                            "log.incident.gain_a_government_advance_taking_a",
+                            "Gain a government advance, taking a game event token \
                             instead of triggering a game event (and losing 1 victory point)",
+                        ),
                     );
                     let p = p.get_mut(game);
                     p.incident_tokens += 1;
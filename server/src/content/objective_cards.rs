@@ -27,8 +27,12 @@ use crate::objective_card::ObjectiveCard;
 use itertools::Itertools;
 use std::vec;
 
+///
+/// # Panics
+///
+/// Panics if objective card ids are not unique
 #[must_use]
-pub(crate) fn get_all_uncached() -> Vec<ObjectiveCard> {
+pub fn get_all_uncached() -> Vec<ObjectiveCard> {
     let all = vec![
         ObjectiveCard::new(1, large_civ(), draft()),
         ObjectiveCard::new(2, science_lead(), conqueror()),
@@ -9,8 +9,12 @@ use crate::content::objective_cards;
 use crate::objective_card::Objective;
 use itertools::Itertools;
 
+///
+/// # Panics
+///
+/// Panics if objective names are not unique
 #[must_use]
-pub(crate) fn get_all_uncached() -> Vec<Objective> {
+pub fn get_all_uncached() -> Vec<Objective> {
     let mut all = objective_cards::get_all_uncached()
         .into_iter()
         .flat_map(|card| card.objectives.map(|o| (o.name.clone(), o)))
@@ -1,5 +1,4 @@
 use crate::content::advances::AdvanceGroup;
-use crate::content::objectives::city_objectives::leading_player;
 use crate::game::Game;
 use crate::objective_card::Objective;
 use crate::player::Player;
@@ -8,9 +7,22 @@ fn advance_group_complete(objective: &str, group: AdvanceGroup) -> Objective {
     let desc = format!("You have all {group} advances.");
     Objective::builder(objective, &desc)
         .status_phase_check(move |game, player| all_advances_in_group(player, group, game))
+        .status_phase_progress(move |game, player| {
+            let (has, all) = advances_in_group(player, group, game);
+            format!("You have {has} of {all} {group} advances")
+        })
         .build()
 }
 
+fn advances_in_group(player: &Player, group: AdvanceGroup, game: &Game) -> (usize, usize) {
+    let advances = &game.cache.get_advance_group(group).advances;
+    let has = advances
+        .iter()
+        .filter(|a| player.has_advance(a.advance))
+        .count();
+    (has, advances.len())
+}
+
 fn all_advances_in_group(player: &Player, group: AdvanceGroup, game: &Game) -> bool {
     game.cache
         .get_advance_group(group)
@@ -58,6 +70,16 @@ pub(crate) fn government() -> Objective {
             .iter()
             .any(|g| all_advances_in_group(player, g.advance_group, game))
     })
+    .status_phase_progress(|game, player| {
+        let (has, all) = game
+            .cache
+            .get_governments()
+            .iter()
+            .map(|g| advances_in_group(player, g.advance_group, game))
+            .max_by_key(|(has, _)| *has)
+            .unwrap_or((0, 0));
+        format!("You have {has} of {all} advances of a government type")
+    })
     .build()
 }
 
@@ -66,14 +88,12 @@ pub(crate) fn goal_focused() -> Objective {
         "Goal Focused",
         "You have more complete advance groups than any other player.",
     )
-    .status_phase_check(|game, player| {
-        leading_player(game, player, 1, |p, g| {
-            g.cache
-                .get_advance_groups()
-                .iter()
-                .filter(|g| g.advances.iter().all(|a| p.has_advance(a.advance)))
-                .count()
-        })
+    .status_phase_leading(1, |p, g| {
+        g.cache
+            .get_advance_groups()
+            .iter()
+            .filter(|g| g.advances.iter().all(|a| p.has_advance(a.advance)))
+            .count()
     })
     .build()
 }
@@ -83,13 +103,12 @@ pub(crate) fn diversified_research() -> Objective {
         "Diversified Research",
         "You have at least 1 advance in 9 different advance groups.",
     )
-    .status_phase_check(|game, player| {
+    .status_phase_count(9, |game, player| {
         game.cache
             .get_advance_groups()
             .iter()
             .filter(|g| g.advances.iter().any(|a| player.has_advance(a.advance)))
             .count()
-            >= 9
     })
     .build()
 }
@@ -108,5 +127,17 @@ pub(crate) fn high_culture() -> Objective {
             .all(|a| player.has_special_advance(a.advance))
             && player.recruited_leaders.len() >= 2
     })
+    .status_phase_progress(|_game, player| {
+        let advances = &player.civilization.special_advances;
+        format!(
+            "You have {} of {} civilization advances and {} of 2 leaders",
+            advances
+                .iter()
+                .filter(|a| player.has_special_advance(a.advance))
+                .count(),
+            advances.len(),
+            player.recruited_leaders.len()
+        )
+    })
     .build()
 }
@@ -38,9 +38,7 @@ fn building_lead(objective: &'static str, building: Building) -> Objective {
         objective,
         &format!("You have more cities with a {building} than any other player",),
     )
-    .status_phase_check(move |game, player| {
-        leading_player(game, player, 1, move |p, _| buildings(p, building))
-    })
+    .status_phase_leading(1, move |p, _| buildings(p, building))
     .build()
 }
 
@@ -53,7 +51,7 @@ fn buildings(p: &Player, b: Building) -> usize {
 
 pub(crate) fn large_civ() -> Objective {
     Objective::builder("Large Civilization", "You have at least 6 cities")
-        .status_phase_check(|_game, player| player.cities.len() >= 6)
+        .status_phase_count(6, |_game, player| player.cities.len())
         .build()
 }
 
@@ -61,17 +59,34 @@ pub(crate) fn leading_player(
     game: &Game,
     player: &Player,
     margin: usize,
-    value: impl Fn(&Player, &Game) -> usize + 'static + Sync + Send,
+    value: impl Fn(&Player, &Game) -> usize,
 ) -> bool {
-    value(player, game)
-        >= game
-            .players
-            .iter()
-            .filter(|p| p.index != player.index && p.is_human())
-            .map(|p| value(p, game))
-            .max()
-            .unwrap_or(0)
-            + margin
+    value(player, game) >= best_other_player(game, player, &value) + margin
+}
+
+pub(crate) fn leading_player_progress(
+    game: &Game,
+    player: &Player,
+    value: impl Fn(&Player, &Game) -> usize,
+) -> String {
+    format!(
+        "You have {} - another player has {}",
+        value(player, game),
+        best_other_player(game, player, &value)
+    )
+}
+
+fn best_other_player(
+    game: &Game,
+    player: &Player,
+    value: &impl Fn(&Player, &Game) -> usize,
+) -> usize {
+    game.players
+        .iter()
+        .filter(|p| p.index != player.index && p.is_human())
+        .map(|p| value(p, game))
+        .max()
+        .unwrap_or(0)
 }
 
 pub(crate) fn advanced_culture() -> Objective {
@@ -80,20 +95,22 @@ pub(crate) fn advanced_culture() -> Objective {
         "You have more advances than any other player - at least 6.",
     )
     .status_phase_check(|game, player| {
-        player.advances.len() >= 6 && leading_player(game, player, 1, move |p, _| p.advances.len())
+        player.advances.len() >= 6 && leading_player(game, player, 1, |p, _| p.advances.len())
+    })
+    .status_phase_progress(|game, player| {
+        leading_player_progress(game, player, |p, _| p.advances.len())
     })
     .build()
 }
 
 pub(crate) fn happy_population() -> Objective {
     Objective::builder("Happy Population", "You have at least 4 happy cities.")
-        .status_phase_check(|_game, player| {
+        .status_phase_count(4, |_game, player| {
             player
                 .cities
                 .iter()
                 .filter(|c| c.mood_state == MoodState::Happy)
                 .count()
-                >= 4
         })
         .build()
 }
@@ -104,36 +121,34 @@ pub(crate) fn architecture() -> Objective {
         "You have at least 4 different types of buildings \
         (that are not influenced by another player).",
     )
-    .status_phase_check(|_game, player| {
+    .status_phase_count(4, |_game, player| {
         player
             .cities
             .iter()
             .flat_map(|c| c.pieces.buildings(Some(player.index)))
             .unique()
             .count()
-            >= 4
     })
     .build()
 }
 
 pub(crate) fn consulate() -> Objective {
     Objective::builder("Consulate", "2 cities are culturally influenced by you.")
-        .status_phase_check(|game, player| {
+        .status_phase_count(2, |game, player| {
             game.players
                 .iter()
                 .filter(|p| p.index != player.index)
                 .flat_map(|p| &p.cities)
                 .filter(|c| !c.pieces.buildings(Some(player.index)).is_empty())
                 .count()
-                >= 2
         })
         .build()
 }
 
 pub(crate) fn metropolis() -> Objective {
     Objective::builder("Metropolis", "You have at least 1 city with size 5.")
-        .status_phase_check(|_game, player| {
-            player.cities.iter().filter(|c| c.size() >= 5).count() >= 1
+        .status_phase_count(1, |_game, player| {
+            player.cities.iter().filter(|c| c.size() >= 5).count()
         })
         .build()
 }
@@ -143,7 +158,7 @@ pub(crate) fn expansionist() -> Objective {
         "Expansionist",
         "You have at least 4 cities that are not adjacent to other cities.",
     )
-    .status_phase_check(|game, player| {
+    .status_phase_count(4, |game, player| {
         player
             .cities
             .iter()
@@ -154,7 +169,6 @@ pub(crate) fn expansionist() -> Objective {
                     .all(|n| game.try_get_any_city(*n).is_none())
             })
             .count()
-            >= 4
     })
     .build()
 }
@@ -164,7 +178,7 @@ pub(crate) fn culture_power() -> Objective {
         "Culture Power",
         "You have influenced more buildings than any other player.",
     )
-    .status_phase_check(|game, player| leading_player(game, player, 1, influenced_buildings))
+    .status_phase_leading(1, influenced_buildings)
     .build()
 }
 
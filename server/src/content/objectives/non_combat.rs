@@ -76,6 +76,9 @@ pub(crate) fn magnificent_culture() -> Objective {
             })
         })
     })
+    .status_phase_progress(|_game, _player| {
+        "You did not build a wonder in the last round".to_string()
+    })
     .add_simple_persistent_event_listener(
         |event| &mut event.play_wonder_card,
         0,
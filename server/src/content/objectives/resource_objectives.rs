@@ -1,3 +1,4 @@
+use crate::localization::Message;
 use crate::log::ActionLogBalance;
 use crate::objective_card::Objective;
 use crate::resource::ResourceType;
@@ -12,6 +13,7 @@ pub(crate) fn optimized_storage() -> Objective {
         let r = &player.resources;
         r.food >= 3 && r.ore >= 3 && r.wood >= 3
     })
+    .status_phase_progress(|_game, player| format!("You have {}", player.resources))
     .build()
 }
 
@@ -50,9 +52,15 @@ pub(crate) fn pay_resources(
         &format!("You have at least {want}: Pay {pay}{suffix}."),
     )
     .status_phase_check(move |_game, player| player.resources.has_at_least(&want))
+    .status_phase_progress(|_game, player| format!("You have {}", player.resources))
     .status_phase_update(move |game, player| {
         player.lose_resources(game, pay.clone(), ActionLogBalance::Pay);
-        player.log(game, &format!("Pay {pay} for {objective}",));
+        player.log_message(
+            game,
+            Message::new("log.objective.pay", "Pay {payment} for {objective}")
+                .param("payment", &pay)
+                .param("objective", objective),
+        );
     })
     .build()
 }
@@ -1,6 +1,6 @@
 use crate::card::HandCardLocation;
 use crate::content::advances::trade_routes::find_trade_routes;
-use crate::content::objectives::city_objectives::leading_player;
+use crate::content::objectives::city_objectives::{leading_player, leading_player_progress};
 use crate::content::objectives::non_combat::last_player_round;
 use crate::log::ActionLogEntry;
 use crate::map::capital_city_position;
@@ -15,7 +15,7 @@ pub(crate) fn sea_blockade() -> Objective {
         "At least 2 of your ships are on the \
         port location of another player",
     )
-    .status_phase_check(|game, player| {
+    .status_phase_count(2, |game, player| {
         let enemy_ports = game
             .players
             .iter()
@@ -31,7 +31,6 @@ pub(crate) fn sea_blockade() -> Objective {
             .iter()
             .filter(|u| enemy_ports.contains(&u.position))
             .count()
-            >= 2
     })
     .build()
 }
@@ -45,6 +44,9 @@ pub(crate) fn large_fleet() -> Objective {
         let ships = ship_count(player);
         ships >= 4 || (ships >= 2 && leading_player(game, player, 1, |p, _| ship_count(p)))
     })
+    .status_phase_progress(|game, player| {
+        leading_player_progress(game, player, |p, _| ship_count(p))
+    })
     .build()
 }
 
@@ -57,10 +59,8 @@ pub(crate) fn large_army() -> Objective {
         "Large Army",
         "You have at least 4 more army units than any other player.",
     )
-    .status_phase_check(|game, player| {
-        leading_player(game, player, 4, |p, _| {
-            p.units.iter().filter(|u| u.is_army_unit()).count()
-        })
+    .status_phase_leading(4, |p, _| {
+        p.units.iter().filter(|u| u.is_army_unit()).count()
     })
     .build()
 }
@@ -72,7 +72,7 @@ pub(crate) fn standing_army() -> Objective {
         Cannot be completed together with Military Might.",
     )
     .contradicting_status_phase_objective("Military Might")
-    .status_phase_check(|_game, player| {
+    .status_phase_count(4, |_game, player| {
         player
             .cities
             .iter()
@@ -83,7 +83,6 @@ pub(crate) fn standing_army() -> Objective {
                     .any(|u| u.is_army_unit())
             })
             .count()
-            >= 4
     })
     .build()
 }
@@ -114,6 +113,20 @@ pub(crate) fn colony() -> Objective {
         }
         false
     })
+    .status_phase_progress(|game, player| {
+        let home = capital_city_position(game, player);
+        let distance = player
+            .cities
+            .iter()
+            .map(|c| c.position.distance(home))
+            .max()
+            .unwrap_or(0);
+        if distance >= 5 {
+            "You completed City Founder in the last round".to_string()
+        } else {
+            format!("Your farthest city is {distance} of 5 spaces away")
+        }
+    })
     .build()
 }
 
@@ -122,7 +135,7 @@ pub(crate) fn threat() -> Objective {
         "Threat",
         "At least 4 of your army units are adjacent to another human player's city.",
     )
-    .status_phase_check(|game, player| {
+    .status_phase_count(4, |game, player| {
         let enemy_cities = game
             .players
             .iter()
@@ -141,7 +154,6 @@ pub(crate) fn threat() -> Objective {
                         .any(|n| enemy_cities.contains(n))
             })
             .count()
-            >= 4
     })
     .build()
 }
@@ -151,7 +163,7 @@ pub(crate) fn outpost() -> Objective {
         "Outpost",
         "You have army units on at least 3 spaces outside, and not adjacent to cities",
     )
-    .status_phase_check(|_game, player| {
+    .status_phase_count(3, |_game, player| {
         player
             .units
             .iter()
@@ -165,7 +177,6 @@ pub(crate) fn outpost() -> Objective {
             })
             .unique()
             .count()
-            >= 3
     })
     .build()
 }
@@ -175,7 +186,7 @@ pub(crate) fn migration() -> Objective {
         "Migration",
         "You have settlers on at least 3 spaces outside, and not adjacent to cities",
     )
-    .status_phase_check(|_game, player| {
+    .status_phase_count(3, |_game, player| {
         player
             .units
             .iter()
@@ -189,7 +200,6 @@ pub(crate) fn migration() -> Objective {
             })
             .unique()
             .count()
-            >= 3
     })
     .build()
 }
@@ -201,8 +211,8 @@ pub(crate) fn military_might() -> Objective {
         Cannot be completed together with Standing Army.",
     )
     .contradicting_status_phase_objective("Standing Army")
-    .status_phase_check(|_game, player| {
-        player.units.iter().filter(|u| u.is_military()).count() >= 12
+    .status_phase_count(12, |_game, player| {
+        player.units.iter().filter(|u| u.is_military()).count()
     })
     .build()
 }
@@ -214,7 +224,9 @@ pub(crate) fn trade_power() -> Objective {
         Cannot be completed together with Shipping Routes.",
     )
     .contradicting_status_phase_objective("Shipping Routes")
-    .status_phase_check(|game, player| find_trade_routes(game, player, false).len() >= 3)
+    .status_phase_count(3, |game, player| {
+        find_trade_routes(game, player, false).len()
+    })
     .build()
 }
 
@@ -225,7 +237,9 @@ pub(crate) fn shipping_routes() -> Objective {
         Cannot be completed together with Trade Power.",
     )
     .contradicting_status_phase_objective("Trade Power")
-    .status_phase_check(|game, player| find_trade_routes(game, player, true).len() >= 2)
+    .status_phase_count(2, |game, player| {
+        find_trade_routes(game, player, true).len()
+    })
     .build()
 }
 
@@ -245,7 +259,7 @@ pub(crate) fn unit_versatility(objective: &str, unit_type: UnitType) -> Objectiv
             unit_type.non_leader_name()
         ),
     )
-    .status_phase_check(move |_game, player| {
+    .status_phase_count(3, move |_game, player| {
         player
             .units
             .iter()
@@ -253,7 +267,6 @@ pub(crate) fn unit_versatility(objective: &str, unit_type: UnitType) -> Objectiv
             .map(|u| u.position)
             .unique()
             .count()
-            >= 3
     })
     .build()
 }
@@ -264,6 +277,8 @@ pub(crate) fn versatility() -> Objective {
         "You have at least 1 of each unit \
         (ship, infantry, cavalry, elephant, leader, settler)",
     )
-    .status_phase_check(|_game, player| player.units.iter().unique_by(|u| u.unit_type).count() >= 6)
+    .status_phase_count(6, |_game, player| {
+        player.units.iter().unique_by(|u| u.unit_type).count()
+    })
     .build()
 }
@@ -10,6 +10,7 @@ use crate::combat_stats::CombatStats;
 use crate::construct::ConstructInfo;
 use crate::content::custom_actions::CustomActionActivation;
 use crate::cultural_influence::InfluenceCultureBoostInfo;
+use crate::draft::DraftInfo;
 use crate::events::EventOrigin;
 use crate::explore::ExploreResolutionState;
 use crate::game::Game;
@@ -26,6 +27,7 @@ use crate::recruit::Recruit;
 use crate::resource_pile::ResourcePile;
 use crate::status_phase::{ChangeGovernment, StatusPhaseState};
 use crate::structure::Structure;
+use crate::trade::TradeInfo;
 use crate::unit::UnitType;
 use crate::wonder::{DrawWonderCard, WonderCardInfo};
 use itertools::Itertools;
@@ -109,6 +111,8 @@ pub enum PersistentEventType {
     CombatRoundEnd(CombatRoundEnd),
     CombatEnd(CombatStats),
     StatusPhase(StatusPhaseState),
+    Draft(DraftInfo),
+    Trade(TradeInfo),
     TurnStart,
     PayAction(ActionPayment),
     Advance(OnAdvanceInfo),
@@ -0,0 +1,107 @@
+use crate::advance::Advance;
+use crate::city::MoodState;
+use crate::city_pieces::Building;
+use crate::game::GameOptions;
+use crate::map::Terrain::{Barren, Fertile, Forest, Mountain, Water};
+use crate::resource_pile::ResourcePile;
+use crate::scenario::{Scenario, ScenarioPlayer, VictoryCondition};
+use crate::unit::UnitType;
+use crate::wonder::Wonder;
+
+pub const FALL_OF_ROME: &str = "Fall of Rome";
+pub const AGE_OF_WONDERS: &str = "Age of Wonders";
+
+#[must_use]
+pub fn get_all_uncached() -> Vec<Scenario> {
+    vec![fall_of_rome(), age_of_wonders()]
+}
+
+// the free block positions of the 2 player map, which don't overlap
+fn two_player_map(scenario: Scenario) -> Scenario {
+    scenario
+        .add_block("B2", 0, [Fertile, Forest, Mountain, Barren])
+        .add_block("F2", 0, [Fertile, Mountain, Forest, Water])
+        .add_block("D3", 0, [Fertile, Barren, Forest, Mountain])
+        .add_block("B4", 0, [Water, Water, Fertile, Forest])
+        .add_block("F4", 0, [Mountain, Fertile, Water, Water])
+        .add_block("D5", 0, [Fertile, Forest, Barren, Mountain])
+        .add_block("B6", 0, [Forest, Water, Mountain, Fertile])
+        .add_block("F6", 0, [Barren, Fertile, Water, Forest])
+        .add_block("D7", 0, [Fertile, Mountain, Forest, Barren])
+}
+
+fn fall_of_rome() -> Scenario {
+    two_player_map(Scenario::new(
+        FALL_OF_ROME,
+        "The Roman Empire is at its peak, but the Vikings are raiding its borders. \
+        Whoever captures the capital of the other side wins.",
+    ))
+    .options(GameOptions {
+        ages: Some(3),
+        ..GameOptions::default()
+    })
+    .add_player(
+        ScenarioPlayer::new(
+            "Rome",
+            ResourcePile::food(2) + ResourcePile::wood(2) + ResourcePile::gold(2),
+        )
+        .advances(&[Advance::Bartering, Advance::Tactics, Advance::Myths])
+        .add_city(
+            "B2",
+            MoodState::Happy,
+            &[Building::Temple, Building::Market],
+        )
+        .add_city("F2", MoodState::Neutral, &[Building::Fortress])
+        .add_city("D3", MoodState::Neutral, &[])
+        .add_units("B2", &[UnitType::Infantry, UnitType::Infantry])
+        .add_units("F2", &[UnitType::Infantry]),
+    )
+    .add_player(
+        ScenarioPlayer::new(
+            "Vikings",
+            ResourcePile::food(2) + ResourcePile::wood(3) + ResourcePile::ore(3),
+        )
+        .advances(&[Advance::Tactics, Advance::Husbandry])
+        .add_city("D7", MoodState::Happy, &[])
+        .add_units(
+            "D7",
+            &[
+                UnitType::Infantry,
+                UnitType::Infantry,
+                UnitType::Cavalry,
+                UnitType::Settler,
+            ],
+        ),
+    )
+    .remove_wonders(&[Wonder::GreatWall])
+    .add_victory_condition(VictoryCondition::CaptureCapital)
+}
+
+fn age_of_wonders() -> Scenario {
+    two_player_map(Scenario::new(
+        AGE_OF_WONDERS,
+        "A teaching setup for wonders: \
+        Both civilizations start with the advances to build their first wonders.",
+    ))
+    .add_player(
+        ScenarioPlayer::new(
+            "Egypt",
+            ResourcePile::food(2) + ResourcePile::wood(3) + ResourcePile::culture_tokens(3),
+        )
+        .advances(&[Advance::Irrigation, Advance::Arts, Advance::Monuments])
+        .add_city("B2", MoodState::Happy, &[Building::Obelisk])
+        .add_city("D3", MoodState::Happy, &[])
+        .add_units("B2", &[UnitType::Settler]),
+    )
+    .add_player(
+        ScenarioPlayer::new(
+            "Greece",
+            ResourcePile::food(2) + ResourcePile::ore(3) + ResourcePile::culture_tokens(3),
+        )
+        .advances(&[Advance::Writing, Advance::Philosophy, Advance::Myths])
+        .add_city("D7", MoodState::Happy, &[Building::Academy])
+        .add_city("D5", MoodState::Happy, &[])
+        .add_units("D7", &[UnitType::Settler]),
+    )
+    .add_victory_condition(VictoryCondition::HoldWonders(3))
+}
@@ -5,6 +5,7 @@ use crate::combat::{Combat, update_combat_strength};
 use crate::combat_listeners::{CombatResult, CombatRoundStart, CombatStrength, kill_combat_units};
 use crate::content::persistent_events::{PaymentRequest, PositionRequest, UnitsRequest};
 use crate::game::Game;
+use crate::localization::Message;
 use crate::position::Position;
 use crate::resource_pile::ResourcePile;
 use crate::tactics_card::{
@@ -29,13 +30,21 @@ pub(crate) fn peltasts(id: u8) -> TacticsCard {
         for _ in &combat.fighting_units(game, player) {
             let roll = game.next_dice_roll().value;
             if roll >= 5 {
-                s.roll_log
-                    .push(format!("Peltasts rolls a {roll} and ignored a hit",));
+                s.roll_log.push(
+                    Message::new(
+                        "log.combat_value.peltasts",
+                        "Peltasts rolls a {roll} and ignored a hit",
+                    )
+                    .param("roll", roll),
+                );
                 s.hit_cancels += 1;
                 return;
             }
         }
-        s.roll_log.push("Pelts rolls no 5 or 6".to_string());
+        s.roll_log.push(Message::new(
+            "log.combat_value.pelts_rolls_no_5_or_6",
+            "Pelts rolls no 5 or 6",
+        ));
     })
     .build()
 }
@@ -58,22 +67,40 @@ pub(crate) fn encircled(id: u8) -> TacticsCard {
         let opponent_losses = e.losses(opponent_role);
         if opponent_losses >= player_losses {
             if opponent_losses == combat.fighting_units(game, opponent).len() as u8 {
-                p.log(game, "Cannot do damage - all units already die");
+                p.log_message(
+                    game,
+                    Message::new(
+                        "log.tactics_card.cannot_do_damage_all_units_already",
+                        "Cannot do damage - all units already die",
+                    ),
+                );
                 return;
             }
 
             let roll = game.next_dice_roll().value;
             if roll >= 5 {
-                p.log(
+                p.log_message(
                     game,
-                    "rolls a 5 or 6 and added a hit that cannot be ignored",
+                    Message::new(
+                        "log.tactics_card.rolls_a_5_or_6_and",
+                        "rolls a 5 or 6 and added a hit that cannot be ignored",
+                    ),
                 );
                 e.update_hits(role, true, |h| h.extra_hits += 1);
             } else {
-                p.log(game, "rolls no 5 or 6");
+                p.log_message(
+                    game,
+                    Message::new("log.tactics_card.rolls_no_5_or_6", "rolls no 5 or 6"),
+                );
             }
         } else {
-            p.log(game, "Cannot do damage - opponent has fewer losses");
+            p.log_message(
+                game,
+                Message::new(
+                    "log.tactics_card.cannot_do_damage_opponent_has_fewer",
+                    "Cannot do damage - opponent has fewer losses",
+                ),
+            );
         }
     })
     .build()
@@ -90,8 +117,13 @@ pub(crate) fn wedge_formation(id: u8) -> TacticsCard {
     .add_reveal_listener(10, |_player, game, c, s| {
         let v = c.fighting_units(game, c.defender()).len() as i8;
         s.extra_combat_value += v;
-        s.roll_log
-            .push(format!("Wedge Formation added {v} combat value",));
+        s.roll_log.push(
+            Message::new(
+                "log.combat_value.wedge_formation",
+                "Wedge Formation added {value} combat value",
+            )
+            .param("value", v),
+        );
     })
     .build()
 }
@@ -100,8 +132,10 @@ pub(crate) fn high_morale(id: u8) -> TacticsCard {
     TacticsCard::builder(id, "High Morale", "Gain 2 combat value.")
         .add_reveal_listener(5, |_player, _game, _c, s| {
             s.extra_combat_value += 2;
-            s.roll_log
-                .push("High Morale added 2 combat value".to_string());
+            s.roll_log.push(Message::new(
+                "log.combat_value.high_morale_added_2_combat_value",
+                "High Morale added 2 combat value",
+            ));
         })
         .build()
 }
@@ -117,9 +151,13 @@ pub(crate) fn heavy_resistance(id: u8) -> TacticsCard {
     .add_reveal_listener(3, |player, game, c, s| {
         let v = c.fighting_units(game, player).len() as i8;
         s.extra_combat_value -= v;
-        s.roll_log.push(format!(
-            "Heavy resistance added -{v} to combat value for each unit"
-        ));
+        s.roll_log.push(
+            Message::new(
+                "log.combat_value.heavy_resistance",
+                "Heavy resistance added -{value} to combat value for each unit",
+            )
+            .param("value", v),
+        );
     })
     .build()
 }
@@ -136,8 +174,10 @@ pub(crate) fn high_ground(id: u8) -> TacticsCard {
     })
     .target(TacticsCardTarget::Opponent)
     .add_reveal_listener(4, |_player, _game, _combat, s| {
-        s.roll_log
-            .push("High Ground prevents opponent from using combat abilities".to_string());
+        s.roll_log.push(Message::new(
+            "log.combat_value.high_ground_prevents_opponent_from_using",
+            "High Ground prevents opponent from using combat abilities",
+        ));
         s.deny_combat_abilities = true;
     })
     .build()
@@ -151,12 +191,20 @@ pub(crate) fn surprise(id: u8) -> TacticsCard {
     )
     .add_reveal_listener(9, |_player, _game, _c, s| {
         s.extra_combat_value += 1;
-        s.roll_log
-            .push("Surprise added 1 to combat value".to_string());
+        s.roll_log.push(Message::new(
+            "log.combat_value.surprise_added_1_to_combat_value",
+            "Surprise added 1 to combat value",
+        ));
     })
     .add_resolve_listener(1, |p, game, e| {
         if e.hits(e.role(p.index)) > 0 {
-            p.log(game, "Draw 1 action card for Surprise tactics");
+            p.log_message(
+                game,
+                Message::new(
+                    "log.tactics_card.draw_1_action_card_for_surprise",
+                    "Draw 1 action card for Surprise tactics",
+                ),
+            );
             gain_action_card_from_pile(game, p);
         }
     })
@@ -175,7 +223,10 @@ pub(crate) fn siege(id: u8) -> TacticsCard {
     .location_requirement(CombatLocation::City)
     .add_reveal_listener(8, |_player, _game, _combat, s| {
         s.extra_combat_value += 1;
-        s.roll_log.push("Siege added 1 to combat value".to_string());
+        s.roll_log.push(Message::new(
+            "log.combat_value.siege_added_1_to_combat_value",
+            "Siege added 1 to combat value",
+        ));
     })
     .add_payment_request_listener(
         |event| &mut event.combat_round_start_tactics,
@@ -199,7 +250,13 @@ pub(crate) fn siege(id: u8) -> TacticsCard {
             if pile.is_empty() {
                 apply_siege(game, r, s.player_index);
             } else {
-                s.log(game, "Can use combat abilities this round");
+                s.log_message(
+                    game,
+                    Message::new(
+                        "log.tactics_card.can_use_combat_abilities_this_round",
+                        "Can use combat abilities this round",
+                    ),
+                );
             }
         },
     )
@@ -212,8 +269,10 @@ fn apply_siege(game: &mut Game, r: &mut CombatRoundStart, player: usize) {
         player,
         r,
         |_game, _combat, s: &mut CombatStrength, _role| {
-            s.roll_log
-                .push("Siege prevents opponent from using combat abilities".to_string());
+            s.roll_log.push(Message::new(
+                "log.combat_value.siege_prevents_opponent_from_using_combat",
+                "Siege prevents opponent from using combat abilities",
+            ));
             s.deny_combat_abilities = true;
         },
     );
@@ -225,8 +284,10 @@ pub(crate) fn for_the_people(id: u8) -> TacticsCard {
         .location_requirement(CombatLocation::City)
         .add_reveal_listener(2, |_player, _game, _c, s| {
             s.extra_dies += 1;
-            s.roll_log
-                .push("For The People added 1 extra die".to_string());
+            s.roll_log.push(Message::new(
+                "log.combat_value.for_the_people_added_1_extra",
+                "For The People added 1 extra die",
+            ));
         })
         .build()
 }
@@ -237,8 +298,10 @@ pub(crate) fn improved_defenses(id: u8) -> TacticsCard {
         .location_requirement(CombatLocation::City)
         .add_reveal_listener(6, |_player, _game, _c, s| {
             s.hit_cancels += 1;
-            s.roll_log
-                .push("Improved Defenses ignored 1 hit.".to_string());
+            s.roll_log.push(Message::new(
+                "log.combat_value.improved_defenses_ignored_1_hit",
+                "Improved Defenses ignored 1 hit.",
+            ));
         })
         .build()
 }
@@ -267,9 +330,11 @@ pub(crate) fn tactical_retreat(id: u8) -> TacticsCard {
         move |game, s, r| {
             r.final_result = Some(CombatResult::AttackerWins);
             let to = s.choice[0];
-            s.log(
+            s.log_message(
                 game,
-                &format!("{} withdraws to {}", game.player_name(s.player_index), to),
+                Message::new("log.tactics.withdraw", "{player} withdraws to {position}")
+                    .param("player", game.player_name(s.player_index))
+                    .param("position", to),
             );
             for unit in game
                 .player_mut(s.player_index)
@@ -299,8 +364,10 @@ pub(crate) fn defensive_formation(id: u8) -> TacticsCard {
         .role_requirement(CombatRole::Defender)
         .add_reveal_listener(0, |_player, _game, _c, s| {
             s.extra_dies += 1;
-            s.roll_log
-                .push("Defensive Formation added 1 extra die.".to_string());
+            s.roll_log.push(Message::new(
+                "log.combat_value.defensive_formation_added_1_extra_die",
+                "Defensive Formation added 1 extra die.",
+            ));
         })
         .build()
 }
@@ -322,10 +389,22 @@ pub(crate) fn scout(id: u8) -> TacticsCard {
                     s,
                     |game, _combat, st, _role| {
                         if let Some(tactics_card) = st.tactics_card.take() {
-                            p.log(game, "Ignore the enemy tactics");
+                            p.log_message(
+                                game,
+                                Message::new(
+                                    "log.tactics_card.ignore_the_enemy_tactics",
+                                    "Ignore the enemy tactics",
+                                ),
+                            );
                             gain_action_card(game, p, tactics_card, HandCardLocation::DiscardPile);
                         } else {
-                            p.log(game, "Cannot use - opponent didn't play a tactics card");
+                            p.log_message(
+                                game,
+                                Message::new(
+                                    "log.tactics_card.cannot_use_opponent_didn_t_play",
+                                    "Cannot use - opponent didn't play a tactics card",
+                                ),
+                            );
                         }
                     },
                 );
@@ -356,19 +435,20 @@ pub(crate) fn martyr(id: u8) -> TacticsCard {
         },
         move |game, s, r| {
             let unit = s.choice[0];
-            s.log(
+            s.log_message(
                 game,
-                &format!(
-                    "{} sacrifices {}",
-                    game.player_name(s.player_index),
-                    a_or_an(
-                        &game
-                            .player(s.player_index)
-                            .get_unit(unit)
-                            .unit_type
-                            .name(game)
-                    )
-                ),
+                Message::new("log.tactics.sacrifice", "{player} sacrifices {unit}")
+                    .param("player", game.player_name(s.player_index))
+                    .param(
+                        "unit",
+                        a_or_an(
+                            &game
+                                .player(s.player_index)
+                                .get_unit(unit)
+                                .unit_type
+                                .name(game),
+                        ),
+                    ),
             );
             kill_combat_units(game, &mut r.combat, s.player_index, &[unit]);
         },
@@ -379,7 +459,13 @@ pub(crate) fn martyr(id: u8) -> TacticsCard {
         move |game, p, s| {
             if s.is_active(p.index, id, TacticsCardTarget::Opponent) {
                 update_combat_strength(game, p.index, s, |game, _combat, st, _role| {
-                    p.log(game, "Cannot use tactics card (but it is still discarded)");
+                    p.log_message(
+                        game,
+                        Message::new(
+                            "log.tactics_card.cannot_use_tactics_card_but_it",
+                            "Cannot use tactics card (but it is still discarded)",
+                        ),
+                    );
                     st.tactics_card = None;
                 });
             }
@@ -405,9 +491,20 @@ pub(crate) fn archers(id: u8) -> TacticsCard {
 
             let roll = game.next_dice_roll().value;
             if roll >= 5 {
-                p.log(game, &format!("rolls a {roll} and scored a hit"));
+                p.log_message(
+                    game,
+                    Message::new("log.tactics.hit", "rolls a {roll} and scored a hit")
+                        .param("roll", roll),
+                );
             } else {
-                p.log(game, &format!("rolls a {roll} and did not score a hit"));
+                p.log_message(
+                    game,
+                    Message::new(
+                        "log.tactics.no_hit",
+                        "rolls a {roll} and did not score a hit",
+                    )
+                    .param("roll", roll),
+                );
                 return None;
             }
 
@@ -420,18 +517,22 @@ pub(crate) fn archers(id: u8) -> TacticsCard {
         },
         move |game, s, r| {
             let unit = s.choice[0];
-            s.log(
+            s.log_message(
                 game,
-                &format!(
-                    "{} sacrifices {} for Archers",
-                    game.player_name(s.player_index),
+                Message::new(
+                    "log.tactics.sacrifice_for_archers",
+                    "{player} sacrifices {unit} for Archers",
+                )
+                .param("player", game.player_name(s.player_index))
+                .param(
+                    "unit",
                     a_or_an(
                         &game
                             .player(s.player_index)
                             .get_unit(unit)
                             .unit_type
-                            .name(game)
-                    )
+                            .name(game),
+                    ),
                 ),
             );
             kill_combat_units(game, &mut r.combat, s.player_index, &[unit]);
@@ -445,7 +546,10 @@ pub(crate) fn flanking(id: u8) -> TacticsCard {
         .role_requirement(CombatRole::Attacker)
         .add_reveal_listener(1, |_player, _game, _c, s| {
             s.extra_dies += 1;
-            s.roll_log.push("Flanking added 1 extra die".to_string());
+            s.roll_log.push(Message::new(
+                "log.combat_value.flanking_added_1_extra_die",
+                "Flanking added 1 extra die",
+            ));
         })
         .build()
 }
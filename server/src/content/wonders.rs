@@ -12,6 +12,7 @@ use crate::content::persistent_events::{
 };
 use crate::game::Game;
 use crate::incident::draw_and_discard_incident_card_from_pile;
+use crate::localization::Message;
 use crate::map::Terrain;
 use crate::map::Terrain::Fertile;
 use crate::objective_card::{discard_objective_card, gain_objective_card_from_pile};
@@ -61,9 +62,10 @@ fn great_wall() -> WonderInfo {
                     .is_some_and(|c| c.mood_state == MoodState::Happy)
             {
                 s.defender_strength.extra_combat_value -= 2;
-                s.defender_strength
-                    .roll_log
-                    .push("Great Wall gives -2 combat value in the first round".to_string());
+                s.defender_strength.roll_log.push(Message::new(
+                    "log.combat_value.great_wall_gives_2_combat_value",
+                    "Great Wall gives -2 combat value in the first round",
+                ));
             }
         },
     )
@@ -152,12 +154,13 @@ pub(crate) fn use_great_mausoleum() -> Ability {
                         .action_cards_discarded
                         .pop()
                         .expect("action card not found in discard pile");
-                    s.log(
+                    s.log_message(
                         game,
-                        &format!(
-                            "Draw {} from the discard pile",
-                            game.cache.get_action_card(card).name()
-                        ),
+                        Message::new(
+                            "log.wonder.draw_action_card",
+                            "Draw {card} from the discard pile",
+                        )
+                        .param("card", game.cache.get_action_card(card).name()),
                     );
                     game.player_mut(s.player_index).action_cards.push(card);
                     log_card_transfer(
@@ -192,12 +195,13 @@ pub(crate) fn use_great_mausoleum() -> Ability {
                         .incidents_discarded
                         .pop()
                         .expect("action card not found in discard pile");
-                    s.log(
+                    s.log_message(
                         game,
-                        &format!(
-                            "Drew {} from the discard pile",
-                            game.cache.get_incident(card).name
-                        ),
+                        Message::new(
+                            "log.wonder.draw_incident",
+                            "Drew {card} from the discard pile",
+                        )
+                        .param("card", &game.cache.get_incident(card).name),
                     );
                     i.incident_id = card;
                 } else {
@@ -309,7 +313,11 @@ fn use_great_library(b: AbilityBuilder) -> AbilityBuilder {
         },
         |game, s, _| {
             let advance = s.choice;
-            s.log(game, &format!("Use {} for the turn", advance.name(game)));
+            s.log_message(
+                game,
+                Message::new("log.wonder.use_advance", "Use {advance} for the turn")
+                    .param("advance", advance.name(game)),
+            );
             game.player_mut(s.player_index).great_library_advance = Some(advance);
             init_great_library(game, s.player_index);
         },
@@ -380,12 +388,14 @@ fn colosseum() -> WonderInfo {
             }
 
             if !apply_colosseum(e, p.index, false) {
-                p.log(
+                p.log_message(
                     game,
-                    &format!(
-                        "Combat value is already at maximum, cannot increase combat value for {}",
-                        p.name(game)
-                    ),
+                    Message::new(
+                        "log.wonder.max_combat_value",
+                        "Combat value is already at maximum, \
+                        cannot increase combat value for {player}",
+                    )
+                    .param("player", p.name(game)),
                 );
                 return None;
             }
@@ -398,7 +408,13 @@ fn colosseum() -> WonderInfo {
         |game, s, e| {
             let pile = &s.choice[0];
             if !pile.is_empty() {
-                s.log(game, "Increase combat value by 1, scoring an extra hit");
+                s.log_message(
+                    game,
+                    Message::new(
+                        "log.wonder.increase_combat_value_by_1_scoring",
+                        "Increase combat value by 1, scoring an extra hit",
+                    ),
+                );
                 apply_colosseum(e, s.player_index, true);
             }
         },
@@ -0,0 +1,272 @@
+use crate::action_card::ActionCard;
+use crate::civilization::Civilization;
+use crate::content::{
+    action_cards, civilizations, incidents, objective_cards, objectives, wonders,
+};
+use crate::incident::Incident;
+use crate::objective_card::{Objective, ObjectiveCard};
+use crate::wonder::{Wonder, WonderInfo};
+use itertools::Itertools;
+use std::collections::HashSet;
+use std::sync::RwLock;
+
+static REGISTRY: RwLock<Vec<ContentPack>> = RwLock::new(Vec::new());
+
+///
+/// Additional rules content (e.g. house rules) that can be registered into a
+/// [`crate::cache::Cache`] without changing the server crate.
+///
+/// A game uses the packs listed in [`crate::game::GameOptions::content_packs`] -
+/// they have to be registered with [`register_content_pack`] before such a game
+/// is created or loaded.
+///
+/// A pack can add new action cards, incidents, objective cards, wonders and civilizations.
+/// Their ids and names must not collide with the base game or other packs.
+///
+/// New wonders, special advances and leaders use the reserved `Custom` ids of
+/// [`Wonder`], [`crate::special_advance::SpecialAdvance`] and [`crate::leader::Leader`].
+/// A reserved id should only be used by one of the registered packs.
+#[derive(Clone, Default)]
+pub struct ContentPack {
+    pub name: String,
+    action_cards: Vec<ActionCard>,
+    incidents: Vec<Incident>,
+    objective_cards: Vec<ObjectiveCard>,
+    wonders: Vec<WonderInfo>,
+    wonder_names: Vec<(Wonder, String)>,
+    civilizations: Vec<Civilization>,
+}
+
+impl ContentPack {
+    #[must_use]
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            ..Self::default()
+        }
+    }
+
+    #[must_use]
+    pub fn add_action_card(mut self, action_card: ActionCard) -> Self {
+        self.action_cards.push(action_card);
+        self
+    }
+
+    #[must_use]
+    pub fn add_incident(mut self, incident: Incident) -> Self {
+        self.incidents.push(incident);
+        self
+    }
+
+    #[must_use]
+    pub fn add_objective_card(mut self, objective_card: ObjectiveCard) -> Self {
+        self.objective_cards.push(objective_card);
+        self
+    }
+
+    ///
+    /// Replaces the definition of an existing wonder.
+    #[must_use]
+    pub fn add_wonder(mut self, wonder: WonderInfo) -> Self {
+        self.wonders.push(wonder);
+        self
+    }
+
+    ///
+    /// Adds a new wonder with a reserved id, e.g. [`Wonder::Custom1`].
+    #[must_use]
+    pub fn add_new_wonder(mut self, name: &str, wonder: WonderInfo) -> Self {
+        self.wonder_names.push((wonder.wonder, name.to_string()));
+        self.wonders.push(wonder);
+        self
+    }
+
+    ///
+    /// Adds a civilization - new special advances and leaders use the reserved ids.
+    #[must_use]
+    pub fn add_civilization(mut self, civilization: Civilization) -> Self {
+        self.civilizations.push(civilization);
+        self
+    }
+}
+
+///
+/// Makes the pack available for all games that list its name in the options.
+/// A pack with the same name is replaced.
+///
+/// # Panics
+///
+/// Panics if another thread panicked while registering a pack
+pub fn register_content_pack(pack: ContentPack) {
+    let mut packs = REGISTRY.write().expect("content pack registry is poisoned");
+    packs.retain(|p| p.name != pack.name);
+    packs.push(pack);
+}
+
+pub(crate) fn custom_wonder_name(wonder: Wonder) -> Option<String> {
+    REGISTRY.read().ok()?.iter().find_map(|p| {
+        p.wonder_names
+            .iter()
+            .find_map(|(w, name)| (*w == wonder).then(|| name.clone()))
+    })
+}
+
+pub(crate) fn registered_content_packs(names: &[String]) -> Result<Vec<ContentPack>, String> {
+    let packs = REGISTRY.read().expect("content pack registry is poisoned");
+    names
+        .iter()
+        .map(|name| {
+            packs
+                .iter()
+                .find(|p| &p.name == name)
+                .cloned()
+                .ok_or_else(|| format!("content pack {name} is not registered"))
+        })
+        .collect()
+}
+
+///
+/// The base content merged with all content packs.
+pub(crate) struct ContentLists {
+    pub(crate) action_cards: Vec<ActionCard>,
+    pub(crate) incidents: Vec<Incident>,
+    pub(crate) objective_cards: Vec<ObjectiveCard>,
+    pub(crate) objectives: Vec<Objective>,
+    pub(crate) wonders: Vec<WonderInfo>,
+    pub(crate) civilizations: Vec<Civilization>,
+}
+
+impl ContentLists {
+    pub(crate) fn new(packs: &[ContentPack]) -> Result<Self, String> {
+        let mut lists = ContentLists {
+            action_cards: action_cards::get_all_uncached(),
+            incidents: incidents::get_all_uncached(),
+            objective_cards: objective_cards::get_all_uncached(),
+            objectives: objectives::get_all_uncached(),
+            wonders: wonders::get_all_uncached(),
+            civilizations: civilizations::get_all_uncached(),
+        };
+        let mut pack_wonders: HashSet<Wonder> = HashSet::new();
+        for pack in packs {
+            lists
+                .add_pack(pack, &mut pack_wonders)
+                .map_err(|e| format!("content pack {}: {e}", pack.name))?;
+        }
+        Ok(lists)
+    }
+
+    fn add_pack(
+        &mut self,
+        pack: &ContentPack,
+        pack_wonders: &mut HashSet<Wonder>,
+    ) -> Result<(), String> {
+        let mut action_card_ids = self.action_card_ids();
+        for card in pack.action_cards.iter().chain(
+            pack.incidents
+                .iter()
+                .filter_map(|incident| incident.action_card.as_ref()),
+        ) {
+            if !action_card_ids.insert(card.id) {
+                return Err(format!("action card id {} is already used", card.id));
+            }
+        }
+        self.action_cards.extend(pack.action_cards.iter().cloned());
+
+        for incident in &pack.incidents {
+            if self.incidents.iter().any(|i| i.id == incident.id) {
+                return Err(format!("incident id {} is already used", incident.id));
+            }
+            self.incidents.push(incident.clone());
+        }
+
+        for card in &pack.objective_cards {
+            if self.objective_cards.iter().any(|c| c.id == card.id) {
+                return Err(format!("objective card id {} is already used", card.id));
+            }
+            for objective in &card.objectives {
+                if self.objectives.iter().any(|o| o.name == objective.name) {
+                    return Err(format!("objective {} already exists", objective.name));
+                }
+            }
+            self.objective_cards.push(card.clone());
+        }
+        // like in the base game, the same objective may be on several cards
+        self.objectives = self
+            .objectives
+            .drain(..)
+            .chain(
+                pack.objective_cards
+                    .iter()
+                    .flat_map(|c| c.objectives.clone())
+                    .unique_by(|o| o.name.clone()),
+            )
+            .sorted_by_key(|o| o.name.clone())
+            .collect();
+
+        for wonder in &pack.wonders {
+            let w = wonder.wonder;
+            let new = pack.wonder_names.iter().any(|(n, _)| *n == w);
+            if w.is_custom() != new {
+                return Err(if new {
+                    format!("new wonder {w:?} must use a reserved id")
+                } else {
+                    format!("wonder {w:?} must be added as a new wonder")
+                });
+            }
+            if !pack_wonders.insert(w) {
+                return Err(format!("wonder {w:?} is already defined by another pack"));
+            }
+            self.wonders.retain(|info| info.wonder != w);
+            self.wonders.push(wonder.clone());
+        }
+
+        for civilization in &pack.civilizations {
+            self.add_civilization(civilization)?;
+        }
+        Ok(())
+    }
+
+    fn action_card_ids(&self) -> HashSet<u8> {
+        self.action_cards
+            .iter()
+            .chain(
+                self.incidents
+                    .iter()
+                    .filter_map(|incident| incident.action_card.as_ref()),
+            )
+            .map(|card| card.id)
+            .collect()
+    }
+
+    fn add_civilization(&mut self, civilization: &Civilization) -> Result<(), String> {
+        if self
+            .civilizations
+            .iter()
+            .any(|c| c.name == civilization.name)
+        {
+            return Err(format!("civilization {} already exists", civilization.name));
+        }
+        for s in &civilization.special_advances {
+            if self
+                .civilizations
+                .iter()
+                .flat_map(|c| &c.special_advances)
+                .any(|o| o.advance == s.advance)
+            {
+                return Err(format!("special advance {:?} is already used", s.advance));
+            }
+        }
+        for l in &civilization.leaders {
+            if self
+                .civilizations
+                .iter()
+                .flat_map(|c| &c.leaders)
+                .any(|o| o.leader == l.leader)
+            {
+                return Err(format!("leader {:?} is already used", l.leader));
+            }
+        }
+        self.civilizations.push(civilization.clone());
+        Ok(())
+    }
+}
@@ -8,6 +8,7 @@ use crate::content::custom_actions::custom_action_modifier_event_origin;
 use crate::content::persistent_events::{PaymentRequest, PersistentEventType, SelectedStructure};
 use crate::events::{EventOrigin, EventPlayer};
 use crate::game::Game;
+use crate::localization::Message;
 use crate::log::{ActionLogEntry, current_turn_log_without_redo};
 use crate::payment::PaymentOptions;
 use crate::player::Player;
@@ -217,12 +218,24 @@ fn roll_boost_paid(
 
     let player = info.player(player_index);
     if payment.is_empty() {
-        player.log(game, "Declined to pay to increase the dice roll");
+        player.log_message(
+            game,
+            Message::new(
+                "log.influence.declined_to_pay_to_increase_the",
+                "Declined to pay to increase the dice roll",
+            ),
+        );
         attempt_failed(game, player_index, attempt.selected_structure.position);
         return;
     }
 
-    player.log(game, "pays to increase the dice roll");
+    player.log_message(
+        game,
+        Message::new(
+            "log.influence.pays_to_increase_the_dice_roll",
+            "pays to increase the dice roll",
+        ),
+    );
     influence_culture(game, player_index, info);
 }
 
@@ -238,9 +251,13 @@ fn roll_boost_payment(
 
     let roll = info.roll;
     if !p.get(game).can_afford(cost) {
-        p.log(
+        p.log_message(
             game,
-            &format!("rolls a {roll} and does not have enough resources to increase the roll"),
+            Message::new(
+                "log.influence.cannot_boost",
+                "rolls a {roll} and does not have enough resources to increase the roll",
+            )
+            .param("roll", roll),
         );
         info.attempt.info.execute(game);
         attempt_failed(game, p.index, info.attempt.position);
@@ -248,12 +265,15 @@ fn roll_boost_payment(
     }
 
     info.attempt.info.execute(game);
-    p.log(
+    p.log_message(
         game,
-        &format!(
+        Message::new(
+            "log.influence.may_boost",
             "rolls a {roll} and now has the option to pay {cost} to \
             increase the dice roll and proceed with the cultural influence",
-        ),
+        )
+        .param("roll", roll)
+        .param("cost", cost),
     );
 
     Some(vec![PaymentRequest::optional(
@@ -274,9 +294,13 @@ fn range_boost_cost(
     info.roll = roll;
     let success = roll >= INFLUENCE_MIN_ROLL;
     if success {
-        p.log(
+        p.log_message(
             game,
-            &format!("Cultural influence succeeded (rolls {roll})"),
+            Message::new(
+                "log.influence.succeeded",
+                "Cultural influence succeeded (rolls {roll})",
+            )
+            .param("roll", roll),
         );
         a.info.execute(game);
         influence_culture(game, player_index, a);
@@ -284,7 +308,14 @@ fn range_boost_cost(
     }
 
     if (a.starting_city_position == a.position) || a.prevent_boost {
-        p.log(game, &format!("Cultural influence failed (rolls {roll})"));
+        p.log_message(
+            game,
+            Message::new(
+                "log.influence.failed",
+                "Cultural influence failed (rolls {roll})",
+            )
+            .param("roll", roll),
+        );
         a.info.execute(game);
         attempt_failed(game, player_index, a.position);
         return PaymentOptions::free();
@@ -0,0 +1,170 @@
+use crate::ability_initializer::AbilityInitializerSetup;
+use crate::action_card::gain_action_card;
+use crate::card::{HandCard, HandCardLocation};
+use crate::content::ability::Ability;
+use crate::content::persistent_events::{
+    HandCardsRequest, PersistentEventType, TriggerPersistentEventParams,
+    trigger_persistent_event_with_listener,
+};
+use crate::game::{Game, GameState};
+use crate::log::{TurnType, add_start_turn_action_if_needed, add_turn_log};
+use crate::objective_card::{gain_objective_card, log_gain_objective_card};
+use crate::utils::Shuffle;
+use serde::{Deserialize, Serialize};
+
+pub const DRAFT: &str = "Card Draft";
+
+// action cards and objective cards in each packet
+const DRAFT_PACKET_SIZE: usize = 3;
+// every player keeps 1 action card in the first round and 1 objective card in the second round -
+// the same hand as without the draft
+const DRAFT_ROUNDS: u8 = 2;
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct DraftInfo {
+    pub round: u8,
+    // the packet each human player currently picks from (indexed by player)
+    pub packets: Vec<Vec<HandCard>>,
+}
+
+impl DraftInfo {
+    #[must_use]
+    pub fn new(packets: Vec<Vec<HandCard>>) -> Self {
+        Self { round: 1, packets }
+    }
+
+    pub fn strip_secret(&mut self, player_index: Option<usize>) {
+        for (i, packet) in self.packets.iter_mut().enumerate() {
+            if player_index != Some(i) {
+                for card in packet {
+                    *card = match card {
+                        HandCard::ActionCard(_) => HandCard::ActionCard(0),
+                        HandCard::ObjectiveCard(_) => HandCard::ObjectiveCard(0),
+                        HandCard::Wonder(w) => HandCard::Wonder(*w),
+                    };
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn start_draft(game: &mut Game) {
+    game.state = GameState::Draft;
+    // human players come first, so the packets can be indexed by player
+    let packets = (0..game.human_players_count())
+        .map(|_| {
+            let mut packet = Vec::new();
+            for _ in 0..DRAFT_PACKET_SIZE {
+                if !game.action_cards_left.is_empty() {
+                    packet.push(HandCard::ActionCard(game.action_cards_left.remove(0)));
+                }
+            }
+            for _ in 0..DRAFT_PACKET_SIZE {
+                if !game.objective_cards_left.is_empty() {
+                    packet.push(HandCard::ObjectiveCard(game.objective_cards_left.remove(0)));
+                }
+            }
+            packet
+        })
+        .collect();
+    play_draft(game, DraftInfo::new(packets), false);
+}
+
+pub(crate) fn draft_response(game: &mut Game, info: DraftInfo) {
+    play_draft(game, info, true);
+}
+
+fn play_draft(game: &mut Game, mut info: DraftInfo, response: bool) {
+    loop {
+        if game.events.is_empty() {
+            add_turn_log(game, TurnType::Draft(info.round));
+            if response {
+                add_start_turn_action_if_needed(game, game.active_player());
+            }
+            game.player_changed();
+        }
+        info = match trigger_persistent_event_with_listener(
+            game,
+            &game.human_players_sorted(game.starting_player_index),
+            |events| &mut events.draft,
+            &game.cache.draft_handler().listeners.clone(),
+            info,
+            PersistentEventType::Draft,
+            TriggerPersistentEventParams::default(),
+        ) {
+            Some(i) => i,
+            None => return,
+        };
+
+        if info.round >= DRAFT_ROUNDS {
+            end_draft(game, info);
+            return;
+        }
+        info.round += 1;
+        // pass the remaining cards to the next player
+        info.packets.rotate_right(1);
+    }
+}
+
+fn end_draft(game: &mut Game, info: DraftInfo) {
+    for card in info.packets.into_iter().flatten() {
+        match card {
+            HandCard::ActionCard(id) => game.action_cards_discarded.push(id),
+            // objective cards have no discard pile
+            HandCard::ObjectiveCard(id) => game.objective_cards_left.push(id),
+            HandCard::Wonder(_) => panic!("wonders can't be drafted"),
+        }
+    }
+    game.objective_cards_left.shuffle(&mut game.rng);
+    game.state = GameState::Playing;
+    game.next_age();
+}
+
+pub(crate) fn draft_handler() -> Ability {
+    Ability::builder(DRAFT, "Keep 1 action card and 1 objective card")
+        .add_hand_card_request(
+            |event| &mut event.draft,
+            0,
+            |_game, p, info| {
+                let round = info.round;
+                Some(HandCardsRequest::new(
+                    info.packets[p.index]
+                        .iter()
+                        .filter(|c| is_draft_pick(c, round))
+                        .cloned()
+                        .collect(),
+                    1..=1,
+                    if round == 1 {
+                        "Select 1 action card to keep"
+                    } else {
+                        "Select 1 objective card to keep"
+                    },
+                ))
+            },
+            |game, s, info| {
+                let player = s.player();
+                info.packets[s.player_index].retain(|c| !s.choice.contains(c));
+                for c in &s.choice {
+                    match c {
+                        HandCard::ActionCard(id) => {
+                            gain_action_card(game, &player, *id, HandCardLocation::Draft);
+                        }
+                        HandCard::ObjectiveCard(id) => {
+                            gain_objective_card(game, s.player_index, *id);
+                            log_gain_objective_card(game, &player, *id, HandCardLocation::Draft);
+                        }
+                        HandCard::Wonder(_) => panic!("wonders can't be drafted"),
+                    }
+                }
+            },
+        )
+        .build()
+}
+
+fn is_draft_pick(card: &HandCard, round: u8) -> bool {
+    match card {
+        HandCard::ActionCard(_) => round == 1,
+        HandCard::ObjectiveCard(_) => round == 2,
+        HandCard::Wonder(_) => false,
+    }
+}
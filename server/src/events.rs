@@ -1,5 +1,6 @@
 use crate::advance::Advance;
 use crate::game::Game;
+use crate::localization::Message;
 use crate::log::{ActionLogBalance, ActionLogEntry, add_action_log_item};
 use crate::payment::{PaymentOptionsBuilder, RewardBuilder};
 use crate::player::{CostTrigger, Player};
@@ -40,6 +41,24 @@ impl EventOrigin {
         }
     }
 
+    ///
+    /// Stable prefix for the localization keys of this origin, e.g. `advance.Farming`
+    #[must_use]
+    pub fn message_key(&self) -> String {
+        let kind = match self {
+            EventOrigin::Advance(_) => "advance",
+            EventOrigin::SpecialAdvance(_) => "special_advance",
+            EventOrigin::LeaderAbility(_) => "leader_ability",
+            EventOrigin::Wonder(_) => "wonder",
+            EventOrigin::Ability(_) => "ability",
+            EventOrigin::Incident(_) => "incident",
+            EventOrigin::CivilCard(_) => "civil_card",
+            EventOrigin::TacticsCard(_) => "tactics_card",
+            EventOrigin::Objective(_) => "objective",
+        };
+        format!("{kind}.{}", self.id())
+    }
+
     #[must_use]
     pub fn name(&self, game: &Game) -> String {
         let cache = &game.cache;
@@ -64,7 +83,7 @@ pub fn check_event_origin() -> EventOrigin {
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
-pub(crate) struct EventPlayer {
+pub struct EventPlayer {
     pub index: usize,
     pub origin: EventOrigin,
 }
@@ -126,8 +145,8 @@ impl EventPlayer {
         RewardBuilder::new(self.origin.clone())
     }
 
-    pub fn log(&self, game: &mut Game, message: &str) {
-        game.log(self.index, &self.origin, message);
+    pub fn log_message(&self, game: &mut Game, message: Message) {
+        game.log_message(self.index, &self.origin, message);
     }
 
     #[must_use]
@@ -140,7 +159,6 @@ impl EventPlayer {
     }
 }
 
-#[derive(Clone)]
 struct Listener<T, U, V, W> {
     #[allow(clippy::type_complexity)]
     callback: Arc<dyn Fn(&mut T, &U, &V, &mut W, &EventPlayer) + Sync + Send>,
@@ -148,6 +166,17 @@ struct Listener<T, U, V, W> {
     player: EventPlayer,
 }
 
+// not derived, because the event parameters don't need to be cloneable
+impl<T, U, V, W> Clone for Listener<T, U, V, W> {
+    fn clone(&self) -> Self {
+        Self {
+            callback: Arc::clone(&self.callback),
+            priority: self.priority,
+            player: self.player.clone(),
+        }
+    }
+}
+
 impl<T, U, V, W> Listener<T, U, V, W> {
     fn new<F>(callback: F, priority: i32, player: EventPlayer) -> Self
     where
@@ -161,10 +190,20 @@ impl<T, U, V, W> Listener<T, U, V, W> {
     }
 }
 
-#[derive(Clone)]
 pub struct Event<T, U = (), V = (), W = ()> {
     pub(crate) name: String, // for debugging
-    listeners: Vec<Listener<T, U, V, W>>,
+    // copy on write, so cloned games share the listeners until they change
+    listeners: Arc<Vec<Listener<T, U, V, W>>>,
+}
+
+// the listeners are shared, so cloning an event is cheap
+impl<T, U, V, W> Clone for Event<T, U, V, W> {
+    fn clone(&self) -> Self {
+        Self {
+            name: self.name.clone(),
+            listeners: Arc::clone(&self.listeners),
+        }
+    }
 }
 
 impl<T, U, V, W> Event<T, U, V, W>
@@ -175,7 +214,7 @@ where
     pub(crate) fn new(name: &str) -> Self {
         Self {
             name: name.to_string(),
-            listeners: Vec::new(),
+            listeners: Arc::new(Vec::new()),
         }
     }
 
@@ -200,16 +239,17 @@ where
                 self.name, old.player.origin
             )
         }
-        self.listeners
-            .push(Listener::new(new_listener, priority, player));
+        let listeners = Arc::make_mut(&mut self.listeners);
+        listeners.push(Listener::new(new_listener, priority, player));
 
-        self.listeners.sort_by_key(|l| l.priority);
-        self.listeners.reverse();
+        listeners.sort_by_key(|l| l.priority);
+        listeners.reverse();
     }
 
     pub(crate) fn remove_listener_mut_by_key(&mut self, key: &EventOrigin) {
-        let _ = self.listeners.remove(
-            self.listeners
+        let listeners = Arc::make_mut(&mut self.listeners);
+        let _ = listeners.remove(
+            listeners
                 .iter()
                 .position(|l| &l.player.origin == key)
                 .unwrap_or_else(|| panic!("Listeners should include the key {key:?} to remove")),
@@ -227,7 +267,7 @@ where
     ) -> Vec<EventOrigin> {
         if trigger == CostTrigger::WithModifiers {
             let mut modifiers = Vec::new();
-            for l in &self.listeners {
+            for l in self.listeners.iter() {
                 let previous_value = value.clone();
                 let previous_extra_value = extra_value.clone();
                 (l.callback)(value, info, details, extra_value, &l.player);
@@ -243,7 +283,7 @@ where
     }
 
     pub(crate) fn trigger(&self, value: &mut T, info: &U, details: &V, extra_value: &mut W) {
-        for l in &self.listeners {
+        for l in self.listeners.iter() {
             (l.callback)(value, info, details, extra_value, &l.player);
         }
     }
@@ -6,6 +6,7 @@ use crate::content::persistent_events::{
 };
 use crate::events::EventPlayer;
 use crate::game::Game;
+use crate::localization::Message;
 use crate::log::ActionLogEntry;
 use crate::map::{Block, BlockPosition, Map, Rotation, UnexploredBlock};
 use crate::movement::{move_units, stop_current_move};
@@ -170,13 +171,27 @@ fn move_to_explored_tile(
         if ship_can_teleport || used_navigation {
             for (p, t) in block.block.tiles(&block.position, rotation) {
                 if t.is_water() {
-                    player.log(game, &format!("Teleported ship from {destination} to {p}"));
+                    player.log_message(
+                        game,
+                        Message::new(
+                            "log.explore.teleport",
+                            "Teleported ship from {from} to {to}",
+                        )
+                        .param("from", destination)
+                        .param("to", p),
+                    );
                     move_units(game, player.index, units, p, None);
                     return;
                 }
             }
         }
-        player.log(game, "Ship can't move to the explored tile");
+        player.log_message(
+            game,
+            Message::new(
+                "log.explore.ship_can_t_move_to_the",
+                "Ship can't move to the explored tile",
+            ),
+        );
         return;
     }
     move_units(game, player.index, units, destination, None);
@@ -277,7 +292,11 @@ pub(crate) fn explore_resolution() -> Ability {
                 panic!("Invalid action");
             };
 
-            p.log(game, &format!("Chose rotation {rotation}"));
+            p.log_message(
+                game,
+                Message::new("log.explore.rotation", "Chose rotation {rotation}")
+                    .param("rotation", rotation),
+            );
             let unexplored_block = &r.block;
             let rotate_by =
                 ((rotation as i8) - (unexplored_block.position.rotation as i8)).rem_euclid(6);
@@ -1,7 +1,7 @@
 use crate::action::lose_action;
 use crate::cache::Cache;
 use crate::combat_roll::{COMBAT_DIE_SIDES, CombatDieRoll};
-use crate::consts::ACTIONS;
+use crate::consts::{ACTIONS, AGES};
 use crate::content::custom_actions::{SpecialActionExecution, SpecialActionInfo};
 use crate::content::effects::PermanentEffect;
 use crate::content::persistent_events::{
@@ -10,6 +10,8 @@ use crate::content::persistent_events::{
 };
 use crate::events::{Event, EventOrigin, EventPlayer};
 use crate::game_data::GameData;
+use crate::incident::IncidentCategory;
+use crate::localization::Message;
 use crate::log::{
     ActionLogAge, ActionLogEntry, ActionLogItem, TurnType, add_round_log,
     add_start_turn_action_if_needed, add_turn_log, current_action_log_mut, current_turn_log,
@@ -22,6 +24,7 @@ use crate::player_events::{
     PersistentEvent, PersistentEvents, TransientEvents, trigger_event_with_game_value,
 };
 use crate::resource::check_for_waste;
+use crate::solo::end_solo_game;
 use crate::status_phase::enter_status_phase;
 use crate::utils::Rng;
 use crate::victory_points::compare_score;
@@ -45,7 +48,46 @@ impl CivSetupOption {
     }
 }
 
-#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Default)]
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Default, Debug)]
+pub enum CardSetupOption {
+    // each player draws 1 action card and 1 objective card
+    #[default]
+    Deal,
+    // the starting action and objective cards are drafted
+    Draft,
+}
+
+impl CardSetupOption {
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        self == &CardSetupOption::Deal
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Default, Debug)]
+pub enum TeamOption {
+    #[default]
+    FreeForAll,
+    // players 1 and 3 play against players 2 and 4
+    TwoVersusTwo,
+}
+
+impl TeamOption {
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        self == &TeamOption::FreeForAll
+    }
+
+    #[must_use]
+    pub fn teammate(&self, player_index: usize) -> Option<usize> {
+        match self {
+            TeamOption::FreeForAll => None,
+            TeamOption::TwoVersusTwo => (player_index < 4).then_some((player_index + 2) % 4),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Default, Debug)]
 pub enum PatchOption {
     #[default]
     Standard,
@@ -75,6 +117,38 @@ impl UndoOption {
     }
 }
 
+#[derive(Serialize, Deserialize, PartialEq, Eq, Clone, Default, Debug)]
+pub struct IncidentOptions {
+    // barbarians don't spawn or move (the barbarian player still exists)
+    #[serde(default)]
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub no_barbarians: bool,
+    // pirates don't spawn (the pirate player still exists)
+    #[serde(default)]
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub no_pirates: bool,
+    // incident tokens are not used - so no events are triggered by advances
+    #[serde(default)]
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub no_incident_tokens: bool,
+    // number of copies of each card of a category in the event deck (default 1, 0 removes it)
+    #[serde(default)]
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub category_weights: HashMap<IncidentCategory, u8>,
+}
+
+impl IncidentOptions {
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        self == &IncidentOptions::default()
+    }
+
+    #[must_use]
+    pub fn weight(&self, category: IncidentCategory) -> u8 {
+        self.category_weights.get(&category).copied().unwrap_or(1)
+    }
+}
+
 #[derive(Serialize, Deserialize, PartialEq, Clone, Default)]
 pub struct GameOptions {
     #[serde(default)]
@@ -84,8 +158,37 @@ pub struct GameOptions {
     #[serde(skip_serializing_if = "CivSetupOption::is_default")]
     pub civilization: CivSetupOption,
     #[serde(default)]
+    #[serde(skip_serializing_if = "CardSetupOption::is_default")]
+    pub cards: CardSetupOption,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "TeamOption::is_default")]
+    pub teams: TeamOption,
+    #[serde(default)]
     #[serde(skip_serializing_if = "PatchOption::is_default")]
     pub patch: PatchOption,
+    // number of ages (6 by default)
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ages: Option<u32>,
+    // the game ends as soon as a player reaches this many victory points
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub victory_points_target: Option<u32>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "IncidentOptions::is_default")]
+    pub incidents: IncidentOptions,
+    // players may trade resources and action cards (once per turn)
+    #[serde(default)]
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub trading: bool,
+    // name of the scenario the game was set up from
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scenario: Option<String>,
+    // names of the registered content packs - see [`crate::content_pack::register_content_pack`]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub content_packs: Vec<String>,
 }
 
 impl GameOptions {
@@ -93,6 +196,11 @@ impl GameOptions {
     pub fn is_default(&self) -> bool {
         self == &GameOptions::default()
     }
+
+    #[must_use]
+    pub fn ages(&self) -> u32 {
+        self.ages.unwrap_or(AGES)
+    }
 }
 
 #[derive(Clone, PartialEq, Eq, Debug)]
@@ -122,7 +230,7 @@ pub struct Game {
     pub successful_cultural_influence: bool,
     pub round: u32, // starts at 1
     pub age: u32,   // starts at 1
-    pub messages: Vec<String>,
+    pub messages: Vec<Message>,
     pub seed: String,
     pub rng: Rng,
     pub dice_roll_outcomes: Vec<u8>, // for testing
@@ -140,10 +248,65 @@ pub struct Game {
 
 impl Clone for Game {
     fn clone(&self) -> Self {
-        Self::from_data(self.cloned_data(), self.cache.clone(), self.context.clone())
+        self.clone_with_context(self.context.clone())
     }
 }
 
+impl Game {
+    // Clones the game without a serde round-trip - the event listeners are shared.
+    fn clone_with_context(&self, context: GameContext) -> Self {
+        Self {
+            cache: self.cache.clone(),
+            options: self.options.clone(),
+            version: self.version,
+            state: self.state.clone(),
+            events: self.events.clone(),
+            players: self.players.clone(),
+            map: self.map.clone(),
+            starting_player_index: self.starting_player_index,
+            current_player_index: self.current_player_index,
+            log: if context == GameContext::AI {
+                last_round_log(&self.log)
+            } else {
+                self.log.clone()
+            },
+            log_index: self.log_index,
+            undo_limit: self.undo_limit,
+            actions_left: self.actions_left,
+            successful_cultural_influence: self.successful_cultural_influence,
+            round: self.round,
+            age: self.age,
+            messages: self.messages.clone(),
+            seed: self.seed.clone(),
+            rng: self.rng.clone(),
+            dice_roll_outcomes: self.dice_roll_outcomes.clone(),
+            dice_roll_log: self.dice_roll_log.clone(),
+            dropped_players: self.dropped_players.clone(),
+            wonders_left: self.wonders_left.clone(),
+            action_cards_left: self.action_cards_left.clone(),
+            action_cards_discarded: self.action_cards_discarded.clone(),
+            objective_cards_left: self.objective_cards_left.clone(),
+            incidents_left: self.incidents_left.clone(),
+            incidents_discarded: self.incidents_discarded.clone(),
+            permanent_effects: self.permanent_effects.clone(),
+            custom_ui_elements: self.custom_ui_elements.clone(),
+            context,
+        }
+    }
+}
+
+// the rules only look at the current round - older rounds are only needed to show the log
+fn last_round_log(log: &[ActionLogAge]) -> Vec<ActionLogAge> {
+    log.last()
+        .map(|age| {
+            let mut last = ActionLogAge::new(age.age);
+            last.rounds.extend(age.rounds.last().cloned());
+            last
+        })
+        .into_iter()
+        .collect()
+}
+
 impl PartialEq for Game {
     fn eq(&self, other: &Self) -> bool {
         self.cloned_data() == other.cloned_data()
@@ -161,6 +324,13 @@ impl Game {
         game_data::data(self)
     }
 
+    ///
+    /// Returns a copy of the game for AI simulations - the log only contains the current round.
+    #[must_use]
+    pub fn ai_clone(&self) -> Game {
+        self.clone_with_context(GameContext::AI)
+    }
+
     #[must_use]
     pub fn cloned_data(&self) -> GameData {
         game_data::cloned_data(self)
@@ -328,23 +498,40 @@ impl Game {
         false
     }
 
+    #[must_use]
+    pub fn teammate(&self, player_index: usize) -> Option<usize> {
+        self.options.teams.teammate(player_index)
+    }
+
+    ///
+    /// Teammates can share a tile - in that case, the player with military units defends first.
     #[must_use]
     pub fn enemy_player(&self, player_index: usize, position: Position) -> Option<usize> {
-        self.players.iter().position(|player| {
-            player.index != player_index
-                && (!player.get_units(position).is_empty()
-                    || player.try_get_city(position).is_some())
-        })
+        let enemies = self
+            .players
+            .iter()
+            .filter(|player| {
+                player.index != player_index
+                    && self.teammate(player_index) != Some(player.index)
+                    && (!player.get_units(position).is_empty()
+                        || player.try_get_city(position).is_some())
+            })
+            .collect_vec();
+        enemies
+            .iter()
+            .find(|player| player.get_units(position).iter().any(|u| u.is_military()))
+            .or(enemies.first())
+            .map(|player| player.index)
     }
 
     pub fn add_log_item(&mut self, item: ActionLogItem) {
         current_action_log_mut(self).items.push(item);
     }
 
-    pub fn log(&mut self, player: usize, origin: &EventOrigin, message: &str) {
+    pub fn log_message(&mut self, player: usize, origin: &EventOrigin, message: Message) {
         self.add_log_item(ActionLogItem::new(
             player,
-            ActionLogEntry::message(message.to_string()),
+            ActionLogEntry::Message(message),
             origin.clone(),
             vec![],
         ));
@@ -429,6 +616,13 @@ impl Game {
         all
     }
 
+    ///
+    /// A solo game has only one human player, who plays against the barbarians and pirates.
+    #[must_use]
+    pub fn is_solo(&self) -> bool {
+        self.players.iter().filter(|p| p.is_human()).count() == 1
+    }
+
     #[must_use]
     pub fn human_player_ids(&self) -> Vec<usize> {
         self.players
@@ -459,6 +653,10 @@ impl Game {
         } else {
             self.start_turn();
         }
+        if self.is_solo() {
+            // the turn is not handed over to another player, but it still can't be undone
+            self.player_changed();
+        }
     }
 
     fn next_round(&mut self) {
@@ -476,12 +674,24 @@ impl Game {
         self.age += 1;
         self.round = 0;
         self.current_player_index = self.starting_player_index;
-        self.add_message(&format!("Age {} has started", self.age));
+        self.add_message(
+            Message::new("message.age_started", "Age {age} has started").param("age", self.age),
+        );
         self.log.push(ActionLogAge::new(self.age));
         self.next_round();
     }
 
     pub(crate) fn end_game(&mut self) {
+        if self.is_solo() {
+            self.add_message(Message::new(
+                "message.solo_game_ended",
+                "The solo game has ended",
+            ));
+            add_start_turn_action_if_needed(self, self.current_player_index);
+            end_solo_game(self);
+            self.state = GameState::Finished;
+            return;
+        }
         let winner_player_index = self
             .players
             .iter()
@@ -489,14 +699,38 @@ impl Game {
             .max_by(|(_, player), (_, other)| compare_score(player, other, self))
             .expect("there should be at least one player in the game")
             .0;
+        self.end_game_with_winner(winner_player_index, "having the most points");
+    }
+
+    pub(crate) fn end_game_with_winner(&mut self, winner_player_index: usize, reason: &str) {
         let winner_name = self.player_name(winner_player_index);
-        self.add_message(&format!("The game has ended. {winner_name} has won"));
+        if let Some(teammate) = self.teammate(winner_player_index) {
+            let teammate_name = self.player_name(teammate);
+            self.add_message(
+                Message::new(
+                    "message.team_won",
+                    "The game has ended. {winner} and {teammate} have won",
+                )
+                .param("winner", winner_name)
+                .param("teammate", teammate_name),
+            );
+        } else {
+            self.add_message(
+                Message::new("message.player_won", "The game has ended. {winner} has won")
+                    .param("winner", winner_name),
+            );
+        }
         add_start_turn_action_if_needed(self, 0);
-        EventPlayer::new(
-            winner_player_index,
-            EventOrigin::Ability("having the most points".to_string()),
-        )
-        .log(self, "wins the game");
+        for winner in [
+            Some(winner_player_index),
+            self.teammate(winner_player_index),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            EventPlayer::new(winner, EventOrigin::Ability(reason.to_string()))
+                .log_message(self, Message::new("log.game.won", "wins the game"));
+        }
         self.state = GameState::Finished;
     }
 
@@ -514,16 +748,16 @@ impl Game {
         COMBAT_DIE_SIDES[dice_roll as usize].clone()
     }
 
-    fn add_message(&mut self, message: &str) {
-        self.messages.push(message.to_string());
+    fn add_message(&mut self, message: Message) {
+        self.messages.push(message);
     }
 
     pub fn drop_player(&mut self, player_index: usize) {
         self.dropped_players.push(player_index);
-        self.add_message(&format!(
-            "{} has left the game",
-            self.player_name(player_index)
-        ));
+        self.add_message(
+            Message::new("message.player_left", "{player} has left the game")
+                .param("player", self.player_name(player_index)),
+        );
         if self.current_player_index != player_index {
             return;
         }
@@ -562,6 +796,7 @@ impl Game {
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 pub enum GameState {
     ChooseCivilization,
+    Draft,
     Playing,
     Movement(MoveState),
     Finished,
@@ -1,11 +1,15 @@
 use super::player::Player;
 use crate::action::execute_action;
+use crate::ai::AI;
+use crate::ai_profile::AiProfile;
 use crate::card::{HandCard, HandCardLocation};
+use crate::combat_report::{CombatReport, combat_reports};
+use crate::content::catalog::{CatalogChange, ContentCatalog, content_catalog, diff_catalogs};
 use crate::content::effects::PermanentEffect;
 use crate::content::persistent_events::{
     EventResponse, PersistentEventRequest, PersistentEventType,
 };
-use crate::game::GameOptions;
+use crate::game::{GameOptions, PatchOption};
 use crate::game_setup::{GameSetupBuilder, setup_game};
 use crate::log::{ActionLogAction, ActionLogEntry, linear_action_log};
 use crate::utils::Shuffle;
@@ -19,6 +23,7 @@ use crate::{
 };
 use std::cmp::Ordering::*;
 use std::mem;
+use std::time::Duration;
 // Game API methods, see https://docs.boardgamers.space/guide/engine-api.html#required-methods
 
 #[must_use]
@@ -36,6 +41,25 @@ pub fn execute(game: Game, action: Action, player_index: usize) -> Game {
     execute_action(game, action, player_index)
 }
 
+///
+/// Returns the action that an AI player in the seat would choose.
+///
+/// # Panics
+///
+/// Panics if it's not the player's turn
+#[must_use]
+pub fn ai_move(game: &Game, player_index: usize, thinking_time: Duration) -> Action {
+    AI::new(
+        1.,
+        thinking_time,
+        false,
+        game,
+        player_index,
+        AiProfile::default(),
+    )
+    .next_action(game)
+}
+
 #[must_use]
 pub fn ended(game: &Game) -> bool {
     matches!(game.state, Finished)
@@ -85,7 +109,12 @@ pub fn rankings(game: &Game) -> Vec<u32> {
             continue;
         }
         let mut rank = 1;
-        for other in &game.players {
+        // in team play, each team is counted once
+        for other in game
+            .players
+            .iter()
+            .filter(|p| game.teammate(p.index).is_none_or(|t| t > p.index))
+        {
             if compare_score(other, player, game) == Greater {
                 rank += 1;
             }
@@ -102,6 +131,24 @@ pub fn round(game: &Game) -> u32 {
     (game.age) * 10 + game.round
 }
 
+#[must_use]
+pub fn combat_report(game: &Game) -> Vec<CombatReport> {
+    combat_reports(game)
+}
+
+#[must_use]
+pub fn content(patch: PatchOption) -> ContentCatalog {
+    content_catalog(patch)
+}
+
+#[must_use]
+pub fn content_diff() -> Vec<CatalogChange> {
+    diff_catalogs(
+        &content_catalog(PatchOption::Standard),
+        &content_catalog(PatchOption::BalancePatch),
+    )
+}
+
 #[must_use]
 pub fn civilizations(game: Game) -> Vec<String> {
     game.players
@@ -128,8 +175,10 @@ pub fn strip_secret(mut game: Game, player_index: Option<usize>) -> Game {
     game.seed = String::new();
     game.rng = Rng::default();
     let mut players = mem::take(&mut game.players);
+    // teammates share their hand cards
+    let teammate = player_index.and_then(|p| game.teammate(p));
     for (i, player) in players.iter_mut().enumerate() {
-        if player_index != Some(i) {
+        if player_index != Some(i) && teammate != Some(i) {
             player.strip_secret(&game);
         }
     }
@@ -187,6 +236,7 @@ fn is_visible_card_info(
 }
 
 fn strip_events(game: &mut Game, player_index: Option<usize>) {
+    let teammate = player_index.and_then(|p| game.teammate(p));
     for s in &mut game.events {
         match &mut s.event_type {
             PersistentEventType::CombatRoundStart(r) => {
@@ -195,7 +245,14 @@ fn strip_events(game: &mut Game, player_index: Option<usize>) {
                     r.attacker_strength.tactics_card = Some(0);
                 }
             }
-            PersistentEventType::SelectObjectives(o) if Some(s.player.index) != player_index => {
+            PersistentEventType::Draft(d) => {
+                // player shouldn't see other player's draft packets
+                d.strip_secret(player_index);
+            }
+            PersistentEventType::Trade(t) => t.strip_secret(player_index),
+            PersistentEventType::SelectObjectives(o)
+                if Some(s.player.index) != player_index && Some(s.player.index) != teammate =>
+            {
                 // player shouldn't see other player's objectives
                 o.strip_secret();
                 if let Some(handler) = &mut s.player.handler
@@ -208,6 +265,7 @@ fn strip_events(game: &mut Game, player_index: Option<usize>) {
         }
         let current_event_player = &mut s.player;
         if player_index != Some(current_event_player.index)
+            && teammate != Some(current_event_player.index)
             && let Some(handler) = &mut current_event_player.handler
         {
             if let PersistentEventRequest::SelectHandCards(c) = &mut handler.request {
@@ -2,12 +2,13 @@
 
 extern crate console_error_panic_hook;
 use crate::cache::Cache;
-use crate::game::{GameContext, GameOptions};
+use crate::game::{GameContext, GameOptions, PatchOption};
 use crate::game_data::GameData;
 use crate::replay::ReplayGameData;
 use crate::{game::Game, game_api, replay};
 use serde::{Deserialize, Serialize};
 use std::mem;
+use std::time::Duration;
 use wasm_bindgen::prelude::*;
 
 #[derive(Serialize, Deserialize)]
@@ -48,6 +49,17 @@ pub fn execute_move(game: String, move_data: String, player_index: usize) -> Str
     from_game(game)
 }
 
+#[wasm_bindgen(js_name = "aiMove")]
+pub fn ai_move(game: String, player_index: usize, thinking_time_ms: u32) -> String {
+    let game = get_game(game);
+    let action = game_api::ai_move(
+        &game,
+        player_index,
+        Duration::from_millis(u64::from(thinking_time_ms)),
+    );
+    serde_json::to_string(&action).expect("move should be serializable")
+}
+
 #[wasm_bindgen]
 pub fn ended(game: String) -> JsValue {
     let game = get_game(game);
@@ -136,6 +148,27 @@ pub fn factions(game: String) -> JsValue {
     serde_wasm_bindgen::to_value(&factions).expect("faction list should be serializable")
 }
 
+#[wasm_bindgen(js_name = "combatReport")]
+pub fn combat_report(game: String) -> JsValue {
+    let game = get_game(game);
+    let reports = game_api::combat_report(&game);
+    serde_wasm_bindgen::to_value(&reports).expect("combat reports should be serializable")
+}
+
+#[wasm_bindgen(js_name = "contentCatalog")]
+pub fn content_catalog(patch: JsValue) -> JsValue {
+    let patch: PatchOption =
+        serde_wasm_bindgen::from_value(patch).expect("patch option should be deserializable");
+    let catalog = game_api::content(patch);
+    serde_wasm_bindgen::to_value(&catalog).expect("content catalog should be serializable")
+}
+
+#[wasm_bindgen(js_name = "contentDiff")]
+pub fn content_diff() -> JsValue {
+    let diff = game_api::content_diff();
+    serde_wasm_bindgen::to_value(&diff).expect("content diff should be serializable")
+}
+
 #[wasm_bindgen(js_name = "stripSecret")]
 pub fn strip_secret(game: String, player_index: Option<usize>) -> String {
     let game = get_game(game);
@@ -146,7 +179,12 @@ pub fn strip_secret(game: String, player_index: Option<usize>) -> String {
 #[wasm_bindgen]
 pub fn messages(game: String) -> JsValue {
     let mut game = get_game(game);
-    let messages = Messages::new(mem::take(&mut game.messages), from_game(game));
+    // the host posts the messages in English
+    let messages = mem::take(&mut game.messages)
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+    let messages = Messages::new(messages, from_game(game));
     serde_wasm_bindgen::to_value(&messages).expect("messages should be serializable")
 }
 
@@ -10,6 +10,7 @@ use crate::content::effects::PermanentEffect;
 use crate::content::persistent_events::PersistentEventState;
 use crate::game::{Game, GameContext, GameOptions, GameState, UIElement};
 use crate::leader::Leader;
+use crate::localization::Message;
 use crate::log::ActionLogAge;
 use crate::map::{Map, MapData};
 use crate::objective_card::{CompletedObjective, init_objective_card};
@@ -58,7 +59,7 @@ pub struct GameData {
     successful_cultural_influence: bool,
     round: u32,
     age: u32,
-    messages: Vec<String>,
+    messages: Vec<Message>,
     #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
     dice_roll_outcomes: Vec<u8>, // for testing purposes
@@ -184,6 +185,16 @@ pub fn data(game: Game) -> GameData {
 
 #[must_use]
 pub fn cloned_data(game: &Game) -> GameData {
+    cloned_data_with_log(game, game.log.clone())
+}
+
+// the log is not part of the undo patch, so it doesn't need to be copied
+#[must_use]
+pub(crate) fn undo_data(game: &Game) -> GameData {
+    cloned_data_with_log(game, Vec::new())
+}
+
+fn cloned_data_with_log(game: &Game, log: Vec<ActionLogAge>) -> GameData {
     GameData {
         options: game.options.clone(),
         version: game.version,
@@ -193,7 +204,7 @@ pub fn cloned_data(game: &Game) -> GameData {
         map: game.map.cloned_data(),
         starting_player_index: game.starting_player_index,
         current_player_index: game.current_player_index,
-        log: game.log.clone(),
+        log,
         log_index: game.log_index,
         undo_limit: game.undo_limit,
         actions_left: game.actions_left,
@@ -5,11 +5,13 @@ use crate::cache::Cache;
 use crate::city;
 use crate::city::{City, MoodState, set_city_mood};
 use crate::civilization::Civilization;
-use crate::consts::{ACTIONS, JSON_SCHEMA_VERSION};
+use crate::consts::{ACTIONS, AGES, JSON_SCHEMA_VERSION};
 use crate::content::ability;
 use crate::content::civilizations::{BARBARIANS, CHOOSE_CIV, PIRATES};
+use crate::draft::start_draft;
 use crate::events::{EventOrigin, EventPlayer};
-use crate::game::{CivSetupOption, Game, GameContext, GameOptions, GameState};
+use crate::game::{CardSetupOption, CivSetupOption, Game, GameContext, GameOptions, GameState};
+use crate::incident::incident_deck;
 use crate::leader::Leader;
 use crate::log::{
     ActionLogAge, ActionLogRound, SetupTurnType, TurnType, add_start_turn_action_if_needed,
@@ -19,6 +21,8 @@ use crate::map::{Map, MapSetup, get_map_setup};
 use crate::objective_card::gain_objective_card_from_pile;
 use crate::player::{Player, gain_unit};
 use crate::resource_pile::ResourcePile;
+use crate::scenario::{Scenario, remove_scenario_cards, setup_scenario_player};
+use crate::solo::INCIDENT_TOKENS;
 use crate::unit::UnitType;
 use crate::utils::{Rng, Shuffle};
 use city::gain_city;
@@ -74,6 +78,14 @@ impl GameSetupBuilder {
         self
     }
 
+    ///
+    /// Sets up the game from a scenario (see [`crate::content::scenarios`]),
+    /// which replaces the map, the civilizations and the options.
+    pub fn scenario(mut self, name: &str) -> Self {
+        self.options.scenario = Some(name.to_string());
+        self
+    }
+
     pub fn build(self) -> GameSetup {
         GameSetup {
             player_amount: self.player_amount,
@@ -89,7 +101,7 @@ impl GameSetupBuilder {
 ///
 /// # Panics
 ///
-/// Panics only if there is an internal bug
+/// Panics if the scenario does not exist or is for a different number of players
 #[must_use]
 pub fn setup_game(setup: &GameSetup) -> Game {
     setup_game_with_cache(setup, Cache::new(&setup.options))
@@ -99,9 +111,35 @@ pub fn setup_game(setup: &GameSetup) -> Game {
 ///
 /// # Panics
 ///
-/// Panics only if there is an internal bug
+/// Panics if the number of ages is not between 1 and [`AGES`],
+/// if team play is used without 4 players, if the category weights remove all events,
+/// if the scenario does not exist or is for a different number of players
+/// or if there is an internal bug
 #[must_use]
 pub fn setup_game_with_cache(setup: &GameSetup, cache: Cache) -> Game {
+    // the game looks up its scenario in the cache as well
+    let scenarios = cache.clone();
+    let scenario = setup
+        .options
+        .scenario
+        .as_ref()
+        .map(|name| scenarios.get_scenario(name));
+    let scenario_setup = scenario.map(|s| scenario_setup(setup, s));
+    let setup = scenario_setup.as_ref().unwrap_or(setup);
+    let ages = setup.options.ages();
+    assert!(
+        (1..=AGES).contains(&ages),
+        "number of ages {ages} is not between 1 and {AGES}"
+    );
+    assert!(
+        setup.options.teams.is_default() || setup.player_amount == 4,
+        "team play needs 4 players"
+    );
+    let incidents = incident_deck(&cache, &setup.options);
+    assert!(
+        !incidents.is_empty(),
+        "the category weights must keep at least one event"
+    );
     let mut rng = init_rng(setup.seed.clone());
 
     let mut players = create_human_players(setup, &mut rng, &cache);
@@ -114,13 +152,7 @@ pub fn setup_game_with_cache(setup: &GameSetup, cache: Cache) -> Game {
     ));
     players.push(Player::new(cache.get_civilization(PIRATES), players.len()));
 
-    let (map_setup, map) = if setup.random_map {
-        let setup = get_map_setup(setup.player_amount);
-        let map = Map::random_map(&mut rng, &setup);
-        (Some(setup), map)
-    } else {
-        (None, Map::new(HashMap::new()))
-    };
+    let (map_setup, map) = create_map(setup, scenario, &mut rng);
 
     let wonders_left = cache
         .get_wonders()
@@ -140,12 +172,7 @@ pub fn setup_game_with_cache(setup: &GameSetup, cache: Cache) -> Game {
         .map(|a| a.id)
         .collect_vec()
         .shuffled(&mut rng);
-    let incidents_left = cache
-        .get_incidents()
-        .iter()
-        .map(|i| i.id)
-        .collect_vec()
-        .shuffled(&mut rng);
+    let incidents_left = incidents.shuffled(&mut rng);
     let all = &cache.get_abilities().clone();
     let choose_civ = setup.options.civilization == CivSetupOption::ChooseCivilization;
     let mut game = Game {
@@ -188,18 +215,65 @@ pub fn setup_game_with_cache(setup: &GameSetup, cache: Cache) -> Game {
     for i in 0..game.players.len() {
         ability::init_player(&mut game, i, all);
     }
+    if let Some(s) = scenario {
+        remove_scenario_cards(&mut game, s);
+    }
 
-    execute_setup_round(setup, &mut game, map_setup.as_ref(), choose_civ);
+    execute_setup_round(setup, &mut game, map_setup.as_ref(), scenario, choose_civ);
     if !choose_civ {
-        game.next_age();
+        start_game(&mut game);
     }
     game
 }
 
+fn create_map(
+    setup: &GameSetup,
+    scenario: Option<&Scenario>,
+    rng: &mut Rng,
+) -> (Option<MapSetup>, Map) {
+    if let Some(s) = scenario {
+        (None, Map::new(s.map.iter().cloned().collect()))
+    } else if setup.random_map {
+        let setup = get_map_setup(setup.player_amount);
+        let map = Map::random_map(rng, &setup);
+        (Some(setup), map)
+    } else {
+        (None, Map::new(HashMap::new()))
+    }
+}
+
+// the scenario replaces the map, the civilizations and the options
+fn scenario_setup(setup: &GameSetup, scenario: &Scenario) -> GameSetup {
+    assert_eq!(
+        scenario.players.len(),
+        setup.player_amount,
+        "scenario {} is for {} players",
+        scenario.name,
+        scenario.players.len()
+    );
+    GameSetup {
+        player_amount: setup.player_amount,
+        seed: setup.seed.clone(),
+        random_map: false,
+        options: scenario.game_options(),
+        assigned_civilizations: scenario.civilizations(),
+    }
+}
+
+fn start_game(game: &mut Game) {
+    if game.options.cards == CardSetupOption::Draft {
+        start_draft(game);
+    } else {
+        game.state = GameState::Playing;
+        game.next_age();
+    }
+}
+
 fn execute_setup_round(
     setup: &GameSetup,
     game: &mut Game,
     map_setup: Option<&MapSetup>,
+    scenario: Option<&Scenario>,
     choose_civ: bool,
 ) {
     let mut age = ActionLogAge::new(0);
@@ -221,12 +295,18 @@ fn execute_setup_round(
         add_start_turn_action_if_needed(game, player_index);
         let origin = setup_event_origin();
         let player = &EventPlayer::new(player_index, origin.clone());
-        player.gain_resources(game, ResourcePile::food(2));
-        do_advance(game, Advance::Farming, player, false);
-        do_advance(game, Advance::Mining, player, false);
+        if let Some(s) = scenario {
+            setup_scenario_player(game, s, player_index);
+        } else {
+            player.gain_resources(game, ResourcePile::food(2));
+            do_advance(game, Advance::Farming, player, false);
+            do_advance(game, Advance::Mining, player, false);
+        }
 
-        gain_action_card_from_pile(game, player);
-        gain_objective_card_from_pile(game, player);
+        if game.options.cards == CardSetupOption::Deal {
+            gain_action_card_from_pile(game, player);
+            gain_objective_card_from_pile(game, player);
+        }
         if let Some(m) = &map_setup {
             let h = &m.home_positions[player_index];
             place_home_tiles(game, player);
@@ -281,7 +361,7 @@ fn create_human_players(setup: &GameSetup, rng: &mut Rng, cache: &Cache) -> Vec<
         let civilization = player_civ(setup, rng, cache, &mut civilizations, player_index);
         let mut player = Player::new(civilization, player_index);
         player.resource_limit = ResourcePile::new(2, 7, 7, 7, 7, 0, 0);
-        player.incident_tokens = 3;
+        player.incident_tokens = INCIDENT_TOKENS;
         players.push(player);
     }
     players
@@ -326,8 +406,7 @@ pub(crate) fn execute_choose_civ(
 
     game.increment_player_index();
     if game.players.iter().all(|p| !p.civilization.is_choose_civ()) {
-        game.state = GameState::Playing;
-        game.next_age();
+        start_game(game);
     }
     Ok(())
 }
@@ -6,6 +6,7 @@ use crate::ability_initializer::{
 use crate::action_card::ActionCard;
 use crate::advance::Advance;
 use crate::barbarians::{barbarians_move, barbarians_spawn};
+use crate::cache::Cache;
 use crate::card::{HandCard, discard_card, draw_card_from_pile};
 use crate::city::{MoodState, decrease_city_mood, is_valid_city_terrain, set_city_mood};
 use crate::content::incidents::great_persons::GREAT_PERSON_OFFSET;
@@ -15,7 +16,8 @@ use crate::content::persistent_events::{
     UnitsRequest, trigger_persistent_event_with_listener,
 };
 use crate::events::{EventOrigin, EventPlayer};
-use crate::game::Game;
+use crate::game::{Game, GameOptions};
+use crate::localization::Message;
 use crate::log::{ActionLogEntry, ActionLogItem};
 use crate::map::Terrain;
 use crate::payment::{PaymentConversion, PaymentConversionType};
@@ -43,13 +45,14 @@ pub struct Incident {
     protection_advance: Option<Advance>,
     protection_special_advance: Option<SpecialAdvance>,
     pub base_effect: IncidentBaseEffect,
+    pub category: IncidentCategory,
     pub listeners: AbilityListeners,
     pub(crate) action_card: Option<ActionCard>,
 }
 
 impl Incident {
     #[must_use]
-    pub(crate) fn builder(
+    pub fn builder(
         id: u8,
         name: &str,
         description: &str,
@@ -59,23 +62,60 @@ impl Incident {
     }
 
     #[must_use]
-    pub fn description(&self, game: &Game) -> Vec<String> {
+    pub fn description(&self, game: &Game) -> Vec<Message> {
         let mut h = vec![];
 
         if matches!(self.base_effect, IncidentBaseEffect::None) {
-            h.push(self.base_effect.to_string());
+            h.push(self.base_effect.message());
+            if game.is_solo() {
+                h.push(Message::new(
+                    "incident.base_effect.solo_barbarians_move",
+                    "Solo game: Barbarians move.",
+                ));
+            }
         }
-        if let Some(p) = &self.protection_advance {
-            h.push(format!("Protection advance: {}", p.name(game)));
+        // the advance may have been replaced in the balance patch
+        if let Some(p) = self
+            .protection_advance
+            .filter(|p| game.cache.get_advances().contains_key(p))
+        {
+            h.push(protection_advance_message(p.name(game)));
         }
         if let Some(p) = &self.protection_special_advance {
-            h.push(format!("Protection advance: {}", p.name(game)));
+            h.push(protection_advance_message(p.name(game)));
         }
-        h.push(self.description.clone());
+        h.push(Message::new(
+            &format!("incident.{}.description", self.id),
+            &self.description,
+        ));
         h
     }
 }
 
+// in solo games, the barbarians march towards the player when there is no other base effect
+fn solo_barbarians_move(mut builder: IncidentBuilder) -> IncidentBuilder {
+    builder.solo_only = true;
+    let mut builder = barbarians_move(builder);
+    builder.solo_only = false;
+    builder
+}
+
+fn protection_advance_message(advance: &str) -> Message {
+    Message::new(
+        "incident.protection_advance",
+        "Protection advance: {advance}",
+    )
+    .param("advance", advance)
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum IncidentCategory {
+    Disaster,
+    GreatPerson,
+    Trade,
+    Other,
+}
+
 #[derive(Clone)]
 pub enum IncidentBaseEffect {
     None,
@@ -86,6 +126,21 @@ pub enum IncidentBaseEffect {
     GoldDeposits,
 }
 
+impl IncidentBaseEffect {
+    #[must_use]
+    pub fn message(&self) -> Message {
+        let key = match self {
+            IncidentBaseEffect::None => "none",
+            IncidentBaseEffect::BarbariansSpawn => "barbarians_spawn",
+            IncidentBaseEffect::BarbariansMove => "barbarians_move",
+            IncidentBaseEffect::PiratesSpawnAndRaid => "pirates_spawn",
+            IncidentBaseEffect::ExhaustedLand => "exhausted_land",
+            IncidentBaseEffect::GoldDeposits => "gold_deposits",
+        };
+        Message::new(&format!("incident.base_effect.{key}"), &self.to_string())
+    }
+}
+
 impl std::fmt::Display for IncidentBaseEffect {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -111,6 +166,7 @@ pub(crate) struct IncidentFilter {
     priority: i32,
     protection_advance: Option<Advance>,
     protection_special_advance: Option<SpecialAdvance>,
+    solo_only: bool,
 }
 
 impl IncidentFilter {
@@ -125,11 +181,15 @@ impl IncidentFilter {
             priority,
             protection_advance,
             protection_special_advance,
+            solo_only: false,
         }
     }
 
     #[must_use]
     pub fn is_active(&self, game: &Game, i: &IncidentInfo, player: usize) -> bool {
+        if self.solo_only && !game.is_solo() {
+            return false;
+        }
         is_active(
             &self.protection_advance,
             &self.protection_special_advance,
@@ -171,7 +231,7 @@ impl DecreaseMood {
     }
 }
 
-pub(crate) struct IncidentBuilder {
+pub struct IncidentBuilder {
     id: u8,
     pub name: String,
     description: String,
@@ -179,6 +239,8 @@ pub(crate) struct IncidentBuilder {
     protection_advance: Option<Advance>,
     protection_special_advance: Option<SpecialAdvance>,
     action_card: Option<ActionCard>,
+    // the listeners that are added while this is set are only active in solo games
+    solo_only: bool,
     builder: AbilityInitializerBuilder,
 }
 
@@ -194,13 +256,14 @@ impl IncidentBuilder {
             protection_advance: None,
             protection_special_advance: None,
             action_card: None,
+            solo_only: false,
         }
     }
 
     #[must_use]
     pub fn build(self) -> Incident {
         Self::new_incident(match self.base_effect {
-            IncidentBaseEffect::None => self,
+            IncidentBaseEffect::None => solo_barbarians_move(self),
             IncidentBaseEffect::BarbariansSpawn => barbarians_spawn(self),
             IncidentBaseEffect::BarbariansMove => barbarians_move(self),
             IncidentBaseEffect::PiratesSpawnAndRaid => pirates_spawn_and_raid(self),
@@ -215,6 +278,7 @@ impl IncidentBuilder {
             name: builder.name,
             description: builder.description,
             base_effect: builder.base_effect,
+            category: IncidentCategory::Other,
             listeners: builder.builder.build(),
             protection_advance: builder.protection_advance,
             protection_special_advance: builder.protection_special_advance,
@@ -391,12 +455,14 @@ impl IncidentBuilder {
     }
 
     fn new_filter(&self, role: IncidentTarget, priority: i32) -> IncidentFilter {
-        IncidentFilter::new(
+        let mut filter = IncidentFilter::new(
             role,
             priority,
             self.protection_advance,
             self.protection_special_advance,
-        )
+        );
+        filter.solo_only = self.solo_only;
+        filter
     }
 
     #[must_use]
@@ -567,7 +633,10 @@ impl IncidentBuilder {
                 i.player.myths_payment = pile.amount();
                 s.player()
                     .with_origin(EventOrigin::Advance(Advance::Myths))
-                    .log(game, "Avoid mood change");
+                    .log_message(
+                        game,
+                        Message::new("log.incident.avoid_mood_change", "Avoid mood change"),
+                    );
             },
         )
     }
@@ -659,10 +728,10 @@ pub(crate) fn on_trigger_incident(game: &mut Game, mut info: IncidentInfo) {
     loop {
         let log: Option<ActionLogItem> = play_base_effect(&info).then_some(ActionLogItem::new(
             info.active_player,
-            ActionLogEntry::message(format!(
-                "triggers the event {}",
-                game.cache.get_incident(info.incident_id).name
-            )),
+            ActionLogEntry::Message(
+                Message::new("log.incident.trigger", "triggers the event {incident}")
+                    .param("incident", &game.cache.get_incident(info.incident_id).name),
+            ),
             info.cause.clone(),
             vec![],
         ));
@@ -705,7 +774,7 @@ pub(crate) fn draw_and_discard_incident_card_from_pile(
         player,
         "Events",
         |g| &mut g.incidents_left,
-        |g| g.cache.get_incidents().iter().map(|i| i.id).collect_vec(),
+        |g| incident_deck(&g.cache, &g.options),
         |p| {
             p.action_cards
                 .iter()
@@ -729,12 +798,36 @@ pub(crate) fn play_base_effect(i: &IncidentInfo) -> bool {
     i.passed.is_none()
 }
 
+fn base_effect_enabled(game: &Game, i: &IncidentInfo) -> bool {
+    let options = &game.options.incidents;
+    match game.cache.get_incident(i.incident_id).base_effect {
+        // in solo games, the barbarians move when there is no other base effect
+        IncidentBaseEffect::None
+        | IncidentBaseEffect::BarbariansSpawn
+        | IncidentBaseEffect::BarbariansMove => !options.no_barbarians,
+        IncidentBaseEffect::PiratesSpawnAndRaid => !options.no_pirates,
+        IncidentBaseEffect::ExhaustedLand | IncidentBaseEffect::GoldDeposits => true,
+    }
+}
+
+///
+/// The event deck - taking the category weights of the game options into account.
+#[must_use]
+pub(crate) fn incident_deck(cache: &Cache, options: &GameOptions) -> Vec<u8> {
+    cache
+        .get_incidents()
+        .iter()
+        .flat_map(|i| vec![i.id; options.incidents.weight(i.category) as usize])
+        .collect()
+}
+
 fn passed_to_player(game: &mut Game, i: &mut IncidentInfo) -> bool {
     if let Some(PassedIncident::NewPlayer(p)) = i.passed {
-        game.log(
+        game.log_message(
             i.active_player,
             &i.origin(),
-            &format!("Pass the event to {}", game.player_name(p)),
+            Message::new("log.incident.pass", "Pass the event to {player}")
+                .param("player", game.player_name(p)),
         );
         i.passed = Some(PassedIncident::AlreadyPassed);
         i.active_player = p;
@@ -757,7 +850,7 @@ pub fn is_active(
         return false;
     }
     if priority >= BASE_EFFECT_PRIORITY {
-        return play_base_effect(i);
+        return play_base_effect(i) && base_effect_enabled(game, i);
     }
     // protection advance does not protect against base effects
     if let Some(advance) = protection_advance
@@ -799,7 +892,14 @@ fn exhausted_land(builder: IncidentBuilder) -> IncidentBuilder {
         },
         |game, s, _| {
             let pos = s.choice[0];
-            s.log(game, &format!("Exhausted the land in position {pos}",));
+            s.log_message(
+                game,
+                Message::new(
+                    "log.incident.exhausted_land",
+                    "Exhausted the land in position {position}",
+                )
+                .param("position", pos),
+            );
             let t = game.map.tiles.get_mut(&pos).expect("tile should exist");
             *t = Terrain::Exhausted(Box::new(t.clone()));
         },
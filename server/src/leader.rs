@@ -26,6 +26,24 @@ pub enum Leader {
     Ragnar,
     Erik,
     Knut,
+
+    // Egypt
+    Ramesses,
+    Hatshepsut,
+    Cleopatra,
+
+    // Persia
+    Cyrus,
+    Darius,
+    Xerxes,
+
+    // reserved for content packs
+    Custom1,
+    Custom2,
+    Custom3,
+    Custom4,
+    Custom5,
+    Custom6,
 }
 
 impl Leader {
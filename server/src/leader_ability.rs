@@ -10,6 +10,7 @@ use crate::content::persistent_events::AdvanceRequest;
 use crate::events::{EventOrigin, EventPlayer};
 use crate::game::Game;
 use crate::leader::leader_position;
+use crate::localization::Message;
 use crate::player::Player;
 use crate::wonder::{Wonder, force_draw_wonder_from_anywhere};
 use itertools::Itertools;
@@ -79,10 +80,14 @@ impl LeaderAbility {
             0,
             move |i, w, game, p| {
                 if w.wonder == wonder {
-                    i.cost.default.culture_tokens -= 2;
+                    i.cost.default.culture_tokens = i.cost.default.culture_tokens.saturating_sub(2);
                     i.info.add_log(
                         p,
-                        &format!("Reduce the cost of {wonder} by 2 culture tokens"),
+                        Message::new(
+                            "log.leader.wonder_cost",
+                            "Reduce the cost of {wonder} by 2 culture tokens",
+                        )
+                        .param("wonder", wonder),
                     );
                 }
 
@@ -90,7 +95,11 @@ impl LeaderAbility {
                     i.ignore_action_cost = true;
                     i.info.add_log(
                         p,
-                        &format!("{wonder} can be built in the leader city as a free action"),
+                        Message::new(
+                            "log.leader.wonder_free_action",
+                            "{wonder} can be built in the leader city as a free action",
+                        )
+                        .param("wonder", wonder),
                     );
                 }
             },
@@ -14,16 +14,17 @@ extern crate core;
 pub mod ability_initializer;
 pub mod action;
 pub mod action_card;
-mod action_cost;
+pub mod action_cost;
 pub mod advance;
-#[cfg(not(target_arch = "wasm32"))]
 pub mod ai;
-#[cfg(not(target_arch = "wasm32"))]
 pub mod ai_actions;
-#[cfg(not(target_arch = "wasm32"))]
 pub mod ai_collect;
-#[cfg(not(target_arch = "wasm32"))]
+pub mod ai_evaluation;
 pub mod ai_missions;
+pub mod ai_opening_book;
+pub mod ai_profile;
+mod ai_runtime;
+pub mod ai_suggestion;
 pub mod barbarians;
 pub mod cache;
 pub mod card;
@@ -33,12 +34,15 @@ pub mod civilization;
 pub mod collect;
 pub mod combat;
 mod combat_listeners;
+pub mod combat_report;
 pub mod combat_roll;
 pub mod combat_stats;
 pub mod construct;
 pub mod consts;
 pub mod content;
+pub mod content_pack;
 pub mod cultural_influence;
+pub mod draft;
 pub mod events;
 mod explore;
 pub mod game;
@@ -49,7 +53,8 @@ pub mod game_setup;
 pub mod happiness;
 pub mod incident;
 pub mod leader;
-mod leader_ability;
+pub mod leader_ability;
+pub mod localization;
 pub mod log;
 pub mod map;
 mod move_routes;
@@ -66,10 +71,15 @@ pub mod recruit;
 pub mod replay;
 pub mod resource;
 pub mod resource_pile;
-mod special_advance;
+pub mod rules_inspection;
+pub mod scenario;
+pub mod solo;
+pub mod special_advance;
 pub mod status_phase;
 pub mod structure;
 pub mod tactics_card;
+pub mod team;
+pub mod trade;
 mod undo;
 pub mod unit;
 pub mod utils;
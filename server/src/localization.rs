@@ -0,0 +1,286 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Display;
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default, Hash)]
+pub enum Language {
+    #[default]
+    English,
+    German,
+}
+
+impl Language {
+    #[must_use]
+    pub fn all() -> Vec<Language> {
+        vec![Language::English, Language::German]
+    }
+
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::German => "de",
+        }
+    }
+
+    #[must_use]
+    pub fn from_code(code: &str) -> Option<Language> {
+        Language::all()
+            .into_iter()
+            .find(|l| code.to_lowercase().starts_with(l.code()))
+    }
+
+    fn catalog_source(self) -> Option<&'static str> {
+        match self {
+            Language::English => None,
+            Language::German => Some(include_str!("../locales/de.json")),
+        }
+    }
+}
+
+///
+/// A player-facing text with a stable key.
+///
+/// The English text is the template for the message and is used as fallback
+/// if the key is not present in the catalog of the chosen language.
+/// Parameters are referenced as `{name}` in the template.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug, Hash)]
+#[serde(from = "MessageData")]
+pub struct Message {
+    pub key: String,
+    pub text: String,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub params: Vec<(String, String)>,
+}
+
+// older games stored plain English texts
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum MessageData {
+    Text(String),
+    Message {
+        key: String,
+        text: String,
+        #[serde(default)]
+        params: Vec<(String, String)>,
+    },
+}
+
+impl From<MessageData> for Message {
+    fn from(data: MessageData) -> Self {
+        match data {
+            MessageData::Text(text) => Self::new(&text, &text),
+            MessageData::Message { key, text, params } => Self { key, text, params },
+        }
+    }
+}
+
+impl Message {
+    #[must_use]
+    pub fn new(key: &str, text: &str) -> Self {
+        Self {
+            key: key.to_string(),
+            text: text.to_string(),
+            params: vec![],
+        }
+    }
+
+    #[must_use]
+    pub fn param(mut self, name: &str, value: impl Display) -> Self {
+        self.params.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    // substitutes all parameters in a single pass - so a value that contains `{name}`
+    // is not substituted again
+    fn format(&self, template: &str) -> String {
+        let mut text = String::with_capacity(template.len());
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            text.push_str(&rest[..start]);
+            let after = &rest[start + 1..];
+            let value = after.find('}').and_then(|end| {
+                let name = &after[..end];
+                self.params
+                    .iter()
+                    .find(|(n, _)| n == name)
+                    .map(|(_, value)| (value, end))
+            });
+            if let Some((value, end)) = value {
+                text.push_str(value);
+                rest = &after[end + 1..];
+            } else {
+                text.push('{');
+                rest = after;
+            }
+        }
+        text.push_str(rest);
+        text
+    }
+}
+
+impl Display for Message {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.format(&self.text))
+    }
+}
+
+///
+/// Translations for one language - keys that are missing fall back to English.
+#[derive(Clone, Debug, Default)]
+pub struct Catalog {
+    pub language: Language,
+    messages: HashMap<String, String>,
+}
+
+impl Catalog {
+    ///
+    /// # Panics
+    ///
+    /// Panics if the bundled catalog is not valid JSON
+    #[must_use]
+    pub fn new(language: Language) -> Self {
+        let messages = language.catalog_source().map_or_else(HashMap::new, |s| {
+            serde_json::from_str(s)
+                .unwrap_or_else(|e| panic!("invalid catalog for {language:?}: {e}"))
+        });
+        Self { language, messages }
+    }
+
+    #[must_use]
+    pub fn contains(&self, key: &str) -> bool {
+        self.messages.contains_key(key)
+    }
+
+    #[must_use]
+    pub fn translate(&self, message: &Message) -> String {
+        message.format(self.messages.get(&message.key).unwrap_or(&message.text))
+    }
+
+    ///
+    /// Translates a plain text (e.g. an error message) by matching it against the
+    /// templates in [`ERRORS`] and [`PROMPTS`] - other texts are shown in English.
+    #[must_use]
+    pub fn translate_text(&self, text: &str) -> String {
+        ERRORS
+            .iter()
+            .chain(PROMPTS)
+            .find_map(|(key, template)| match_template(key, template, text))
+            .map_or_else(|| text.to_string(), |m| self.translate(&m))
+    }
+}
+
+///
+/// Error messages are plain strings, so they are keyed by their English template.
+pub const ERRORS: &[(&str, &str)] = &[
+    ("error.not_enough_resources", "Not enough resources"),
+    ("error.not_enough_resources", "not enough resources"),
+    ("error.no_actions_left", "No actions left"),
+    ("error.not_enough_actions_left", "Not enough actions left"),
+    ("error.building_already_exists", "Building already exists"),
+    (
+        "error.all_buildings_built",
+        "All non-destroyed buildings are built",
+    ),
+    ("error.missing_advance", "Missing advance: {advance}"),
+    ("error.missing_advance", "Advance missing: {advance}"),
+    ("error.missing_building", "Missing building: {building}"),
+    ("error.city_is_angry", "City is angry"),
+    ("error.city_is_not_happy", "City is not happy"),
+    ("error.cannot_activate", "Can't activate"),
+    ("error.cannot_activate", "City cannot be activated"),
+    ("error.not_your_city", "Not your city"),
+    ("error.maximum_city_size", "City already has maximum size"),
+    ("error.need_more_cities", "Need more cities"),
+    ("error.cannot_found_city", "Cannot found city"),
+    ("error.invalid_replacement", "Invalid replacement"),
+    (
+        "error.units_available",
+        "Only have {available} {unit} - not {amount}",
+    ),
+    ("error.too_many_units", "Too many units"),
+    ("error.too_many_units_in_stack", "Too many units in stack"),
+    (
+        "error.leader_not_available",
+        "Leader {leader} not available",
+    ),
+    ("error.wonder_card_not_owned", "Wonder card not owned"),
+    ("error.engineering_missing", "Engineering advance missing"),
+    (
+        "error.placement_requirement",
+        "Placement requirement not met",
+    ),
+    ("error.no_movement_actions_left", "no movement actions left"),
+    ("error.stack_limit_exceeded", "stack limit exceeded"),
+    (
+        "error.carrier_capacity_exceeded",
+        "carrier capacity exceeded",
+    ),
+    (
+        "error.army_movement_advance_missing",
+        "army movement advance missing",
+    ),
+    (
+        "error.battle_movement_restriction",
+        "battle movement restriction",
+    ),
+    ("error.no_army_units_to_attack", "no army units to attack"),
+    ("error.units_already_moved", "some units already moved"),
+    (
+        "error.mountain_movement_restriction",
+        "mountain movement restriction",
+    ),
+    (
+        "error.forest_movement_restriction",
+        "forest movement attack restriction",
+    ),
+];
+
+///
+/// Request descriptions are plain strings as well.
+pub const PROMPTS: &[(&str, &str)] = &[
+    (
+        "prompt.trade.accept_offer",
+        "{player} offers {offer}. Do you want to accept the offer?",
+    ),
+    (
+        "prompt.trade.accept_counter_offer",
+        "Do you want to give {offer} to {player} for {counter_offer}?",
+    ),
+    (
+        "prompt.trade.counter_offer",
+        "Do you want to make a counter-offer? Otherwise the trade is declined.",
+    ),
+    (
+        "prompt.trade.select_player",
+        "Select a player to trade with",
+    ),
+    ("prompt.trade.select_resource", "Select {resource} to offer"),
+    (
+        "prompt.trade.select_action_cards",
+        "Select action cards to offer",
+    ),
+];
+
+// the parameters are separated by the literal parts of the template
+fn match_template(key: &str, template: &str, text: &str) -> Option<Message> {
+    let mut message = Message::new(key, template);
+    let mut template = template;
+    let mut text = text;
+    while let Some(start) = template.find('{') {
+        let end = template[start..].find('}')? + start;
+        text = text.strip_prefix(&template[..start])?;
+        let name = &template[start + 1..end];
+        template = &template[end + 1..];
+        let literal = template.split('{').next().unwrap_or_default();
+        let value_end = if literal.is_empty() {
+            text.len()
+        } else {
+            text.find(literal)?
+        };
+        message = message.param(name, &text[..value_end]);
+        text = &text[value_end..];
+    }
+    (text == template).then_some(message)
+}
@@ -1,10 +1,12 @@
 use crate::advance::Advance;
 use crate::card::{HandCard, HandCardLocation};
 use crate::city::MoodState;
+use crate::combat::CombatModifier;
 use crate::combat_roll::UnitCombatRoll;
 use crate::combat_stats::CombatStats;
 use crate::cultural_influence::InfluenceCultureAttemptInfo;
 use crate::events::EventOrigin;
+use crate::localization::Message;
 use crate::map::Terrain;
 use crate::movement::{MoveUnits, move_event_origin};
 use crate::player::Player;
@@ -63,6 +65,7 @@ pub enum TurnType {
     Player(usize),
     Setup(SetupTurnType),
     StatusPhase(StatusPhaseStateType),
+    Draft(u8),
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq)]
@@ -179,8 +182,14 @@ pub struct ActionLogEntryCombatRoll {
     pub combat_value: u8,
     pub hits: u8,
     #[serde(default)]
+    #[serde(skip_serializing_if = "u8::is_zero")]
+    pub hit_cancels: u8,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub combat_modifiers: Vec<Message>,
+    #[serde(default)]
     #[serde(skip_serializing_if = "Vec::is_empty")]
-    pub combat_modifiers: Vec<String>,
+    pub modifiers: Vec<CombatModifier>,
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
@@ -192,7 +201,9 @@ pub struct ActionLogEntryAdvance {
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 pub enum ActionLogEntry {
-    Text(String),
+    // a text with a stable key, so it can be translated - older games stored plain texts
+    #[serde(alias = "Text")]
+    Message(Message),
     Action {
         balance: ActionLogBalance,
         #[serde(default)]
@@ -225,6 +236,10 @@ pub enum ActionLogEntry {
     },
     CombatRound(ActionLogEntryCombatRound),
     CombatRoll(ActionLogEntryCombatRoll),
+    // the attacker's decision after a combat round
+    CombatRetreat {
+        retreat: bool,
+    },
     InfluenceCultureAttempt(InfluenceCultureAttemptInfo),
 }
 
@@ -241,9 +256,14 @@ impl ActionLogEntry {
         }
     }
 
+    ///
+    /// The English text of a message entry
     #[must_use]
-    pub fn message(text: String) -> Self {
-        Self::Text(text)
+    pub fn text(&self) -> Option<String> {
+        match self {
+            ActionLogEntry::Message(m) => Some(m.to_string()),
+            _ => None,
+        }
     }
 
     #[must_use]
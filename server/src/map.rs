@@ -465,6 +465,22 @@ impl MapSetup {
 #[must_use]
 pub(crate) fn get_map_setup(player_count: usize) -> MapSetup {
     let setup = vec![
+        // solo
+        MapSetup::new(
+            vec![MapHomePosition::new(
+                Position::from_offset("D1"),
+                3,
+                STARTING_BLOCKS[1].clone(),
+            )],
+            vec![
+                BlockPosition::new(Position::from_offset("B2"), 0),
+                BlockPosition::new(Position::from_offset("F2"), 0),
+                BlockPosition::new(Position::from_offset("D3"), 0),
+                BlockPosition::new(Position::from_offset("B4"), 0),
+                BlockPosition::new(Position::from_offset("F4"), 0),
+                BlockPosition::new(Position::from_offset("D5"), 0),
+            ],
+        ),
         // 2 players
         MapSetup::new(
             vec![
@@ -537,9 +553,56 @@ pub(crate) fn get_map_setup(player_count: usize) -> MapSetup {
 }
 
 pub(crate) fn capital_city_position(game: &Game, player: &Player) -> Position {
+    if let Some(name) = &game.options.scenario {
+        return game.cache.get_scenario(name).players[player.index].capital();
+    }
+    home(game, player).0
+}
+
+///
+/// Returns the position as seen from the capital of the player, as if the home block was not
+/// rotated - so that the positions of different players can be compared.
+pub(crate) fn position_relative_to_home(
+    game: &Game,
+    player: &Player,
+    position: Position,
+) -> Position {
+    let (capital, rotation) = home(game, player);
+    let rotated = position
+        .coordinate()
+        .rotate_around(capital.coordinate(), Angle::from_int(-(rotation as i32)));
+    let rotated = Position::from_coordinate(rotated);
+    Position::new(rotated.q - capital.q, rotated.r - capital.r)
+}
+
+///
+/// Returns true if the map was set up with the standard map setup - i.e. not from a scenario or
+/// a custom map - so that [`position_relative_to_home`] can be used.
+pub(crate) fn is_standard_map(game: &Game) -> bool {
+    if game.options.scenario.is_some() {
+        return false;
+    }
+    let setup = get_map_setup(human_players_including_dropped(game));
+    let blocks = setup
+        .home_positions
+        .iter()
+        .map(|h| &h.position)
+        .chain(&setup.free_positions)
+        .collect_vec();
+    game.map.tiles.len() == blocks.len() * UNEXPLORED_BLOCK.terrain.len()
+        && blocks
+            .into_iter()
+            .flat_map(block_tiles)
+            .all(|p| game.map.tiles.contains_key(&p))
+}
+
+fn home(game: &Game, player: &Player) -> (Position, Rotation) {
     let setup = get_map_setup(human_players_including_dropped(game));
     let h = &setup.home_positions[player.index];
-    h.block.tiles(&h.position, h.position.rotation)[0].0
+    (
+        h.block.tiles(&h.position, h.position.rotation)[0].0,
+        h.position.rotation,
+    )
 }
 
 pub(crate) fn block_tiles(p1: &BlockPosition) -> Vec<Position> {
@@ -7,7 +7,7 @@ use crate::game::{Game, GameState};
 use crate::map::Map;
 use crate::movement::{CurrentMove, move_event_origin};
 use crate::payment::PaymentOptions;
-use crate::player::Player;
+use crate::player::{Player, team_army_units};
 use crate::position::Position;
 use crate::resource_pile::ResourcePile;
 use crate::special_advance::SpecialAdvance;
@@ -231,11 +231,7 @@ fn next_road_step(
     from.neighbors()
         .into_iter()
         .filter(|to| {
-            let on_target = player
-                .get_units(*to)
-                .iter()
-                .filter(|unit| unit.is_army_unit())
-                .count();
+            let on_target = team_army_units(game, player.index, *to);
             game.map.is_land(*to)
                 && (allow_enemy(to) || game.enemy_player(player.index, *to).is_none())
                 && on_target + stack_size <= STACK_LIMIT
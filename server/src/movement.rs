@@ -1,3 +1,4 @@
+use crate::localization::Message;
 use crate::map::Map;
 use crate::map::Terrain::{Fertile, Forest, Mountain, Unexplored};
 use crate::resource_pile::ResourcePile;
@@ -17,7 +18,7 @@ use crate::log::ActionLogEntry;
 use crate::move_routes::{MoveRoute, move_routes};
 use crate::movement::MovementAction::{Move, Stop};
 use crate::payment::PaymentOptions;
-use crate::player::Player;
+use crate::player::{Player, team_army_units};
 use crate::player_events::MoveInfo;
 use crate::position::Position;
 use crate::resource::pay_cost;
@@ -169,10 +170,11 @@ pub(crate) fn move_units(
     }
 
     if !to_ship.is_empty() {
-        game.log(
+        game.log_message(
             player_index,
             &EventOrigin::SpecialAdvance(SpecialAdvance::ShipConstruction),
-            &format!("Convert {} to ships", to_ship.to_string(None)),
+            Message::new("log.ship_construction.convert", "Convert {units} to ships")
+                .param("units", to_ship.to_string(None)),
         );
         if let Movement(move_state) = &mut game.state {
             move_state.current_move = CurrentMove::Embark {
@@ -289,12 +291,24 @@ pub(crate) fn execute_movement_action(
     let player = EventPlayer::new(player_index, move_event_origin());
     match &action {
         Move(m) if m.units.is_empty() => {
-            player.log(game, "Used a movement actions but moved no units");
+            player.log_message(
+                game,
+                Message::new(
+                    "log.movement.used_a_movement_actions_but_moved",
+                    "Used a movement actions but moved no units",
+                ),
+            );
         }
         Move(m) => {
             player.add_log_entry(game, ActionLogEntry::move_units(player.get(game), m));
         }
-        Stop => player.log(game, "End the movement action"),
+        Stop => player.log_message(
+            game,
+            Message::new(
+                "log.movement.end_the_movement_action",
+                "End the movement action",
+            ),
+        ),
     }
 
     let p = &EventPlayer::new(player_index, move_event_origin());
@@ -508,13 +522,7 @@ fn move_route_result(
 
     let dest = route.destination;
     if game.map.is_land(start)
-        && player
-            .get_units(dest)
-            .iter()
-            .filter(|unit| unit.is_army_unit() && !unit.is_transported())
-            .count()
-            + stack_size
-            > STACK_LIMIT
+        && team_army_units(game, player.index, dest) + stack_size > STACK_LIMIT
     {
         return Err("stack limit exceeded".to_string());
     }
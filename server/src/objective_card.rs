@@ -5,9 +5,11 @@ use crate::card::{HandCard, HandCardLocation, draw_card_from_pile, log_card_tran
 use crate::content::ability::{Ability, AbilityBuilder};
 use crate::content::effects::PermanentEffect;
 use crate::content::incidents::great_persons::find_great_seer;
+use crate::content::objectives::city_objectives::{leading_player, leading_player_progress};
 use crate::content::persistent_events::{HandCardsRequest, PersistentEventType};
 use crate::events::{EventOrigin, EventPlayer};
 use crate::game::Game;
+use crate::localization::Message;
 use crate::player::Player;
 use crate::utils::{remove_element, remove_element_by};
 use itertools::Itertools;
@@ -18,6 +20,8 @@ type StatusPhaseCheck = Arc<dyn Fn(&Game, &Player) -> bool + Sync + Send>;
 
 type StatusPhaseUpdate = Arc<dyn Fn(&mut Game, &EventPlayer) + Sync + Send>;
 
+type StatusPhaseProgress = Arc<dyn Fn(&Game, &Player) -> String + Sync + Send>;
+
 pub enum ObjectiveType {
     Instant,
     StatusPhase,
@@ -30,6 +34,7 @@ pub struct Objective {
     pub(crate) listeners: AbilityListeners,
     pub(crate) status_phase_check: Option<StatusPhaseCheck>,
     pub(crate) status_phase_update: Option<StatusPhaseUpdate>,
+    pub(crate) status_phase_progress: Option<StatusPhaseProgress>,
     pub(crate) contradicting_status_phase_objective: Option<String>,
 }
 
@@ -46,6 +51,13 @@ impl Objective {
         i32::from(self.status_phase_update.is_none())
     }
 
+    ///
+    /// Explains how far the player is from the status phase requirement, e.g. "You have 5 of 6"
+    #[must_use]
+    pub fn status_phase_progress(&self, game: &Game, player: &Player) -> Option<String> {
+        self.status_phase_progress.as_ref().map(|p| p(game, player))
+    }
+
     #[must_use]
     pub fn get_type(&self) -> ObjectiveType {
         if self.status_phase_check.is_some() {
@@ -87,6 +99,7 @@ pub struct ObjectiveBuilder {
     description: String,
     status_phase_check: Option<StatusPhaseCheck>,
     status_phase_update: Option<StatusPhaseUpdate>,
+    status_phase_progress: Option<StatusPhaseProgress>,
     contradicting_status_phase_objective: Option<String>,
     builder: AbilityInitializerBuilder,
 }
@@ -99,13 +112,14 @@ impl ObjectiveBuilder {
             description: description.to_string(),
             status_phase_check: None,
             status_phase_update: None,
+            status_phase_progress: None,
             contradicting_status_phase_objective: None,
             builder: AbilityInitializerBuilder::new(),
         }
     }
 
     #[must_use]
-    pub(crate) fn status_phase_check<F>(mut self, f: F) -> Self
+    pub fn status_phase_check<F>(mut self, f: F) -> Self
     where
         F: Fn(&Game, &Player) -> bool + 'static + Sync + Send,
     {
@@ -114,7 +128,7 @@ impl ObjectiveBuilder {
     }
 
     #[must_use]
-    pub(crate) fn status_phase_update<F>(mut self, f: F) -> Self
+    pub fn status_phase_update<F>(mut self, f: F) -> Self
     where
         F: Fn(&mut Game, &EventPlayer) + 'static + Sync + Send,
     {
@@ -123,19 +137,62 @@ impl ObjectiveBuilder {
     }
 
     #[must_use]
-    pub(crate) fn contradicting_status_phase_objective(mut self, name: &str) -> Self {
+    pub fn status_phase_progress<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&Game, &Player) -> String + 'static + Sync + Send,
+    {
+        self.status_phase_progress = Some(Arc::new(f));
+        self
+    }
+
+    ///
+    /// The requirement is met if the player has at least `needed` of something.
+    #[must_use]
+    pub fn status_phase_count<F>(self, needed: usize, count: F) -> Self
+    where
+        F: Fn(&Game, &Player) -> usize + 'static + Sync + Send,
+    {
+        let count = Arc::new(count);
+        let c = count.clone();
+        self.status_phase_check(move |game, player| c(game, player) >= needed)
+            .status_phase_progress(move |game, player| {
+                format!("You have {} of {needed}", count(game, player))
+            })
+    }
+
+    ///
+    /// The requirement is met if the player has at least `margin` more of something
+    /// than any other player.
+    #[must_use]
+    pub fn status_phase_leading<F>(self, margin: usize, value: F) -> Self
+    where
+        F: Fn(&Player, &Game) -> usize + 'static + Sync + Send,
+    {
+        let value = Arc::new(value);
+        let v = value.clone();
+        self.status_phase_check(move |game, player| {
+            leading_player(game, player, margin, |p, g| v(p, g))
+        })
+        .status_phase_progress(move |game, player| {
+            leading_player_progress(game, player, |p, g| value(p, g))
+        })
+    }
+
+    #[must_use]
+    pub fn contradicting_status_phase_objective(mut self, name: &str) -> Self {
         self.contradicting_status_phase_objective = Some(name.to_string());
         self
     }
 
     #[must_use]
-    pub(crate) fn build(self) -> Objective {
+    pub fn build(self) -> Objective {
         Objective {
             name: self.name,
             description: self.description,
             listeners: self.builder.build(),
             status_phase_check: self.status_phase_check,
             status_phase_update: self.status_phase_update,
+            status_phase_progress: self.status_phase_progress,
             contradicting_status_phase_objective: self.contradicting_status_phase_objective,
         }
     }
@@ -273,7 +330,10 @@ fn add_select_objective_card_to_complete(b: AbilityBuilder, priority: i32) -> Ab
         |game, s, i| {
             let card_id = match s.choice.first() {
                 None => {
-                    s.log(game, "No card selected");
+                    s.log_message(
+                        game,
+                        Message::new("log.objective.no_card_selected", "No card selected"),
+                    );
                     return;
                 }
                 Some(HandCard::ObjectiveCard(card_id)) => card_id,
@@ -3,8 +3,8 @@ use crate::events::{EventOrigin, EventPlayer, check_event_origin};
 use crate::player::Player;
 use crate::resource::ResourceType;
 use crate::resource_pile::ResourcePile;
-use itertools::Itertools;
 use serde::{Deserialize, Serialize};
+use std::collections::{HashSet, VecDeque};
 use std::fmt::Display;
 use std::ops::RangeInclusive;
 
@@ -70,6 +70,16 @@ impl PaymentConversion {
             payment_conversion_type,
         }
     }
+
+    // how often the conversion can be used - for all alternatives together
+    fn limit(&self) -> Option<u8> {
+        match self.payment_conversion_type {
+            PaymentConversionType::Unlimited => None,
+            PaymentConversionType::MayOverpay(i) | PaymentConversionType::MayNotOverpay(i) => {
+                Some(i)
+            }
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug, Hash)]
@@ -100,52 +110,38 @@ impl PaymentOptions {
         }
     }
 
+    ///
+    /// Returns the valid payment that needs the fewest conversions.
     #[must_use]
     pub fn first_valid_payment(&self, available: &ResourcePile) -> Option<ResourcePile> {
-        let discount_left = self
-            .conversions
-            .iter()
-            .filter_map(|c| {
-                if c.to.is_empty() {
-                    match c.payment_conversion_type {
-                        PaymentConversionType::Unlimited => None,
-                        PaymentConversionType::MayOverpay(i)
-                        | PaymentConversionType::MayNotOverpay(i) => Some(i),
-                    }
-                } else {
-                    None
-                }
-            })
-            .sum::<u8>();
-        if discount_left == 0 && available.has_at_least(&self.default) {
+        if self.discount_left() == 0 && available.has_at_least(&self.default) {
             return Some(self.default.clone());
         }
-        let may_overpay = self.conversions.iter().any(|c| {
-            matches!(
-                c.payment_conversion_type,
-                PaymentConversionType::MayOverpay(_)
-            )
-        });
+        self.solve(available, true).into_iter().next()
+    }
 
-        self.conversions
-            .iter()
-            .permutations(self.conversions.len())
-            .find_map(|conversions| {
-                can_convert(
-                    available,
-                    &self.default,
-                    &conversions,
-                    0,
-                    discount_left,
-                    may_overpay,
-                )
-            })
+    ///
+    /// Returns all valid payments that can be paid with the available resources -
+    /// ordered by the number of conversions needed.
+    #[must_use]
+    pub fn valid_payments(&self, available: &ResourcePile) -> Vec<ResourcePile> {
+        self.solve(available, false)
+    }
+
+    ///
+    /// Returns the valid payments where no other valid payment uses a subset of the resources.
+    #[must_use]
+    pub fn minimal_payments(&self, available: &ResourcePile) -> Vec<ResourcePile> {
+        let all = self.valid_payments(available);
+        all.iter()
+            .filter(|p| !all.iter().any(|q| q != *p && p.has_at_least(q)))
+            .cloned()
+            .collect()
     }
 
     #[must_use]
     pub fn is_valid_payment(&self, payment: &ResourcePile) -> bool {
-        self.first_valid_payment(payment)
-            .is_some_and(|p| &p == payment)
+        self.valid_payments(payment).contains(payment)
     }
 
     #[must_use]
@@ -256,6 +252,81 @@ impl PaymentOptions {
     pub fn default_payment(&self) -> ResourcePile {
         self.default.clone()
     }
+
+    fn discount_left(&self) -> u8 {
+        self.conversions
+            .iter()
+            .filter(|c| c.to.is_empty())
+            .filter_map(PaymentConversion::limit)
+            .sum()
+    }
+
+    ///
+    /// Breadth-first search over the conversion states (the converted pile and how often each
+    /// limited conversion was used).
+    ///
+    /// The number of states is polynomial in the cost, because conversions never increase
+    /// the amount of resources and the limits are small.
+    fn solve(&self, available: &ResourcePile, first_only: bool) -> Vec<ResourcePile> {
+        let discount_left = self.discount_left();
+        let may_overpay = self.conversions.iter().any(|c| {
+            matches!(
+                c.payment_conversion_type,
+                PaymentConversionType::MayOverpay(_)
+            )
+        });
+        let max_amount = self.default.amount();
+
+        let start = (self.default.clone(), vec![0_u8; self.conversions.len()]);
+        let mut visited = HashSet::from([start.clone()]);
+        let mut queue = VecDeque::from([start]);
+        let mut result: Vec<ResourcePile> = Vec::new();
+        while let Some((pile, used)) = queue.pop_front() {
+            let discount_used: u8 = self
+                .conversions
+                .iter()
+                .zip(&used)
+                .filter(|(c, _)| c.to.is_empty())
+                .map(|(_, u)| *u)
+                .sum();
+            if available.has_at_least(&pile)
+                && (discount_used == discount_left || may_overpay)
+                && !result.contains(&pile)
+            {
+                if first_only {
+                    return vec![pile];
+                }
+                result.push(pile.clone());
+            }
+
+            for (i, conversion) in self.conversions.iter().enumerate() {
+                let limit = conversion.limit();
+                if limit.is_some_and(|l| used[i] >= l) {
+                    continue;
+                }
+                for from in &conversion.from {
+                    if !pile.has_at_least(from) {
+                        continue;
+                    }
+                    let mut next = pile.clone();
+                    next -= from.clone();
+                    next += conversion.to.clone();
+                    if next.amount() > max_amount {
+                        continue;
+                    }
+                    let mut next_used = used.clone();
+                    if limit.is_some() {
+                        next_used[i] += 1;
+                    }
+                    let state = (next, next_used);
+                    if visited.insert(state.clone()) {
+                        queue.push_back(state);
+                    }
+                }
+            }
+        }
+        result
+    }
 }
 
 impl Display for PaymentOptions {
@@ -317,7 +388,7 @@ impl ResourceReward {
     }
 }
 
-pub(crate) struct PaymentOptionsBuilder {
+pub struct PaymentOptionsBuilder {
     pub origin: EventOrigin,
 }
 
@@ -358,7 +429,7 @@ impl PaymentOptionsBuilder {
     }
 }
 
-pub(crate) struct RewardBuilder {
+pub struct RewardBuilder {
     pub origin: EventOrigin,
 }
 
@@ -379,80 +450,6 @@ impl RewardBuilder {
     }
 }
 
-#[must_use]
-pub fn can_convert(
-    available: &ResourcePile,
-    current: &ResourcePile,
-    conversions: &[&PaymentConversion],
-    skip_from: usize,
-    discount_left: u8,
-    may_overpay: bool,
-) -> Option<ResourcePile> {
-    if available.has_at_least(current) && (discount_left == 0 || may_overpay) {
-        return Some(current.clone());
-    }
-
-    if conversions.is_empty() {
-        return None;
-    }
-    let conversion = &conversions[0];
-    if skip_from >= conversion.from.len() {
-        return can_convert(
-            available,
-            current,
-            &conversions[1..],
-            0,
-            discount_left,
-            may_overpay,
-        );
-    }
-    let from = &conversion.from[skip_from];
-
-    let upper_limit = match conversion.payment_conversion_type {
-        PaymentConversionType::Unlimited => u8::MAX,
-        PaymentConversionType::MayOverpay(i) | PaymentConversionType::MayNotOverpay(i) => i,
-    };
-
-    for amount in 1..=upper_limit {
-        if !current.has_at_least_times(from, amount)
-            || (conversion.to.is_empty() && amount > discount_left)
-        {
-            return can_convert(
-                available,
-                current,
-                conversions,
-                skip_from + 1,
-                discount_left,
-                may_overpay,
-            );
-        }
-
-        let mut current = current.clone();
-        for _ in 0..amount {
-            current -= from.clone();
-            current += conversion.to.clone();
-        }
-        let new_discount_left = if conversion.to.is_empty() {
-            discount_left - amount
-        } else {
-            discount_left
-        };
-
-        let can = can_convert(
-            available,
-            &current,
-            conversions,
-            skip_from + 1,
-            new_discount_left,
-            may_overpay,
-        );
-        if can.is_some() {
-            return can;
-        }
-    }
-    None
-}
-
 pub(crate) fn payment_options_sum(
     cost: u8,
     types_by_preference: &[ResourceType],
@@ -592,6 +589,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_valid_payments() {
+        let cost = PaymentOptions::resources_with_gold_conversion(
+            ResourcePile::food(1) + ResourcePile::wood(1),
+            check_event_origin(),
+        );
+        let available = ResourcePile::food(1) + ResourcePile::wood(1) + ResourcePile::gold(2);
+        assert_eq!(
+            cost.valid_payments(&available),
+            vec![
+                ResourcePile::food(1) + ResourcePile::wood(1),
+                ResourcePile::wood(1) + ResourcePile::gold(1),
+                ResourcePile::food(1) + ResourcePile::gold(1),
+                ResourcePile::gold(2),
+            ]
+        );
+        assert!(
+            cost.valid_payments(&ResourcePile::gold(1)).is_empty(),
+            "not enough gold"
+        );
+    }
+
+    #[test]
+    fn test_minimal_payments() {
+        let cost = PaymentOptions::new(
+            ResourcePile::food(3),
+            vec![
+                PaymentConversion::resource_options(
+                    vec![ResourcePile::food(1)],
+                    ResourcePile::empty(),
+                    PaymentConversionType::MayOverpay(2),
+                ),
+                PaymentConversion::limited(ResourcePile::food(1), ResourcePile::wood(1), 1),
+            ],
+            vec![],
+            check_event_origin(),
+        );
+        let available = ResourcePile::food(3) + ResourcePile::wood(1);
+        assert_eq!(cost.valid_payments(&available).len(), 6);
+        assert_eq!(
+            cost.minimal_payments(&available),
+            vec![ResourcePile::food(1), ResourcePile::wood(1)]
+        );
+    }
+
     #[test]
     #[allow(clippy::too_many_lines)]
     fn test_is_valid_payment() {
@@ -8,6 +8,7 @@ use crate::content::persistent_events::{
 use crate::events::{EventOrigin, EventPlayer};
 use crate::game::Game;
 use crate::incident::{BASE_EFFECT_PRIORITY, IncidentBuilder};
+use crate::localization::Message;
 use crate::player::{Player, gain_unit, remove_unit};
 use crate::player_events::IncidentTarget;
 use crate::position::Position;
@@ -73,7 +74,13 @@ pub(crate) fn pirates_spawn_and_raid(mut builder: IncidentBuilder) -> IncidentBu
                 }
 
                 if player.resources.amount() > 0 {
-                    p.log(game, "Must pay 1 resource or token to bribe the pirates");
+                    p.log_message(
+                        game,
+                        Message::new(
+                            "log.pirates.must_pay_1_resource_or_token",
+                            "Must pay 1 resource or token to bribe the pirates",
+                        ),
+                    );
                     Some(vec![PaymentRequest::mandatory(
                         p.payment_options()
                             .sum(p.get(game), 1, &ResourceType::all()),
@@ -86,7 +93,11 @@ pub(crate) fn pirates_spawn_and_raid(mut builder: IncidentBuilder) -> IncidentBu
                 }
             },
             |game, s, _| {
-                s.log(game, &format!("Pirates took {}", s.choice[0]));
+                s.log_message(
+                    game,
+                    Message::new("log.pirates.took", "Pirates took {choice}")
+                        .param("choice", &s.choice[0]),
+                );
             },
         )
         .add_incident_position_request(
@@ -106,7 +117,13 @@ pub(crate) fn pirates_spawn_and_raid(mut builder: IncidentBuilder) -> IncidentBu
                     return None;
                 }
 
-                p.log(game, "Must reduce Mood in a city adjacent to pirates");
+                p.log_message(
+                    game,
+                    Message::new(
+                        "log.pirates.must_reduce_mood_in_a_city",
+                        "Must reduce Mood in a city adjacent to pirates",
+                    ),
+                );
                 let needed = 1..=1;
                 Some(PositionRequest::new(
                     choices,
@@ -147,14 +164,18 @@ fn remove_pirate_ships(builder: IncidentBuilder) -> IncidentBuilder {
         },
         |game, s, _| {
             let pirates = get_pirates_player(game).index;
-            s.log(
+            s.log_message(
                 game,
-                &format!(
-                    "Removed a Pirate Ships at {}",
+                Message::new(
+                    "log.pirates.removed",
+                    "Removed a Pirate Ships at {positions}",
+                )
+                .param(
+                    "positions",
                     s.choice
                         .iter()
                         .map(|u| game.player(pirates).get_unit(*u).position.to_string())
-                        .join(", ")
+                        .join(", "),
                 ),
             );
             for unit in &s.choice {
@@ -198,7 +219,13 @@ fn place_pirate_ship(builder: IncidentBuilder, priority: i32, blockade: bool) ->
 
             if sea_spaces.is_empty() && blockade {
                 // don't log this twice (blockade is only for first call)
-                p.log(game, "No valid positions for Pirate Ship");
+                p.log_message(
+                    game,
+                    Message::new(
+                        "log.pirates.no_valid_positions_for_pirate_ship",
+                        "No valid positions for Pirate Ship",
+                    ),
+                );
             }
 
             let needed = 1..=1;
@@ -43,6 +43,7 @@ pub enum PlayerType {
     Barbarian,
 }
 
+#[derive(Clone)]
 pub struct Player {
     pub(crate) name: Option<String>,
     pub index: usize,
@@ -610,12 +611,23 @@ pub fn end_turn(game: &mut Game, player: usize) {
     }
 }
 
-pub(crate) fn can_add_army_unit(p: &Player, position: Position) -> bool {
-    p.get_units(position)
-        .iter()
-        .filter(|u| u.is_army_unit())
-        .count()
-        < STACK_LIMIT
+pub(crate) fn can_add_army_unit(game: &Game, p: &Player, position: Position) -> bool {
+    team_army_units(game, p.index, position) < STACK_LIMIT
+}
+
+///
+/// Teammates can share a tile, so the stack limit applies to the army units of both.
+pub(crate) fn team_army_units(game: &Game, player_index: usize, position: Position) -> usize {
+    std::iter::once(player_index)
+        .chain(game.teammate(player_index))
+        .map(|p| {
+            game.player(p)
+                .get_units(position)
+                .iter()
+                .filter(|u| u.is_army_unit() && !u.is_transported())
+                .count()
+        })
+        .sum()
 }
 
 #[derive(Serialize, Deserialize, PartialEq, Clone)]
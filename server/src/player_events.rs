@@ -11,10 +11,12 @@ use crate::content::persistent_events::{KilledUnits, PaymentRequest};
 use crate::cultural_influence::{
     InfluenceCultureAttemptInfo, InfluenceCultureBoostInfo, InfluenceCultureOutcome,
 };
+use crate::draft::DraftInfo;
 use crate::events::{Event, EventOrigin, EventPlayer};
 use crate::explore::ExploreResolutionState;
 use crate::game::Game;
 use crate::incident::PassedIncident;
+use crate::localization::Message;
 use crate::map::Terrain;
 use crate::objective_card::SelectObjectivesInfo;
 use crate::payment::{PaymentConversion, PaymentOptions};
@@ -22,6 +24,7 @@ use crate::playing_actions::{ActionPayment, PlayingActionType};
 use crate::recruit::Recruit;
 use crate::resource::pay_cost;
 use crate::status_phase::StatusPhaseState;
+use crate::trade::TradeInfo;
 use crate::unit::Units;
 use crate::victory_points::SpecialVictoryPoints;
 use crate::wonder::{DrawWonderCard, WonderBuildInfo, WonderCardInfo};
@@ -36,6 +39,7 @@ use std::collections::{HashMap, HashSet};
 
 pub(crate) type PersistentEvent<V = ()> = Event<Game, (), (), V>;
 
+#[derive(Clone)]
 pub(crate) struct PlayerEvents {
     pub persistent: PersistentEvents,
     pub transient: TransientEvents,
@@ -50,7 +54,8 @@ impl PlayerEvents {
     }
 }
 
-pub(crate) struct TransientEvents {
+#[derive(Clone)]
+pub struct TransientEvents {
     pub on_influence_culture_attempt:
         Event<Result<InfluenceCultureAttemptInfo, String>, City, Game>,
     pub on_influence_culture_resolve: Event<Game, InfluenceCultureOutcome>,
@@ -74,6 +79,7 @@ pub(crate) struct TransientEvents {
 }
 
 impl TransientEvents {
+    #[must_use]
     pub fn new() -> TransientEvents {
         TransientEvents {
             on_influence_culture_attempt: Event::new("on_influence_culture_attempt"),
@@ -99,7 +105,14 @@ impl TransientEvents {
     }
 }
 
-pub(crate) struct PersistentEvents {
+impl Default for TransientEvents {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone)]
+pub struct PersistentEvents {
     pub collect: PersistentEvent<CollectInfo>,
     pub construct: PersistentEvent<ConstructInfo>,
     pub draw_wonder_card: PersistentEvent<DrawWonderCard>,
@@ -113,6 +126,8 @@ pub(crate) struct PersistentEvents {
     pub play_wonder_card: PersistentEvent<WonderCardInfo>,
 
     pub status_phase: PersistentEvent<StatusPhaseState>,
+    pub draft: PersistentEvent<DraftInfo>,
+    pub trade: PersistentEvent<TradeInfo>,
     pub turn_start: PersistentEvent,
     pub incident: PersistentEvent<IncidentInfo>,
     pub stop_barbarian_movement: PersistentEvent<Vec<Position>>,
@@ -150,6 +165,8 @@ impl PersistentEvents {
             play_wonder_card: Event::new("play_wonder_card"),
 
             status_phase: Event::new("status_phase"),
+            draft: Event::new("draft"),
+            trade: Event::new("trade"),
             turn_start: Event::new("turn_start"),
             incident: Event::new("incident"),
             stop_barbarian_movement: Event::new("stop_barbarian_movement"),
@@ -173,12 +190,18 @@ impl PersistentEvents {
     }
 }
 
+impl Default for PersistentEvents {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
 pub(crate) struct ActionInfo {
     pub(crate) player: usize,
     pub(crate) origin: EventOrigin,
     pub(crate) info: HashMap<String, String>,
-    pub(crate) log: Vec<(EventOrigin, String)>,
+    pub(crate) log: Vec<(EventOrigin, Message)>,
 }
 
 impl ActionInfo {
@@ -193,7 +216,7 @@ impl ActionInfo {
 
     pub(crate) fn execute(&self, game: &mut Game) {
         for (o, l) in self.log.iter().unique() {
-            game.log(self.player, o, l);
+            game.log_message(self.player, o, l.clone());
         }
         let player = game.player_mut(self.player);
         for (k, v) in self.info.clone() {
@@ -201,8 +224,8 @@ impl ActionInfo {
         }
     }
 
-    pub(crate) fn add_log(&mut self, p: &EventPlayer, message: &str) {
-        self.log.push((p.origin.clone(), message.to_string()));
+    pub(crate) fn add_log(&mut self, p: &EventPlayer, message: Message) {
+        self.log.push((p.origin.clone(), message));
     }
 }
 
@@ -335,7 +358,10 @@ impl CostInfo {
 
     pub(crate) fn set_zero_resources(&mut self, p: &EventPlayer) {
         self.cost.default = ResourcePile::empty();
-        self.info.add_log(p, "Reduce the cost to 0");
+        self.info.add_log(
+            p,
+            Message::new("log.cost.reduce_the_cost_to_0", "Reduce the cost to 0"),
+        );
     }
 
     pub(crate) fn pay(&self, game: &mut Game, payment: &ResourcePile) {
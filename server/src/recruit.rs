@@ -7,7 +7,7 @@ use crate::content::persistent_events::PersistentEventType;
 use crate::game::Game;
 use crate::map::capital_city_position;
 use crate::payment::PaymentOptions;
-use crate::player::{CostTrigger, Player, gain_units};
+use crate::player::{CostTrigger, Player, gain_units, team_army_units};
 use crate::player_events::CostInfo;
 use crate::position::Position;
 use crate::resource_pile::ResourcePile;
@@ -186,10 +186,10 @@ pub fn recruit_cost_without_replaced(
         && (units.elephants > 0
             || (units.cavalry > 0 && !is_cavalry_province_city(player, city_position, game)))
     {
-        return Err("Mising building: market".to_string());
+        return Err("Missing building: market".to_string());
     }
     if units.ships > 0 && city.pieces.port.is_none() {
-        return Err("Mising building: port".to_string());
+        return Err("Missing building: port".to_string());
     }
 
     for (t, a) in units.clone() {
@@ -221,12 +221,7 @@ pub fn recruit_cost_without_replaced(
     if units.amount() > city.mood_modified_size(player) as u8 {
         return Err("Too many units".to_string());
     }
-    if player
-        .get_units(city_position)
-        .iter()
-        .filter(|unit| unit.is_army_unit())
-        .count() as u8
-        + units.amount()
+    if team_army_units(game, player.index, city_position) as u8 + units.amount()
         - units.settlers
         - units.ships
         > STACK_LIMIT as u8
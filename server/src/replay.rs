@@ -57,13 +57,76 @@ pub struct ReplayPlayerData {
     civilization: String,
 }
 
+impl ReplayGameData {
+    ///
+    /// Returns the data needed to replay the game, e.g. to archive AI games.
+    #[must_use]
+    pub fn from_game(game: &Game) -> Self {
+        Self {
+            options: game.options.clone(),
+            seed: game.seed.clone(),
+            action_log: game
+                .log
+                .iter()
+                .map(|age| ReplayActionLogAge {
+                    rounds: age
+                        .rounds
+                        .iter()
+                        .map(|round| ReplayActionLogRound {
+                            players: round
+                                .turns
+                                .iter()
+                                .filter_map(|turn| {
+                                    Some(ReplayActionLogPlayer {
+                                        index: turn.actions.first()?.player,
+                                        actions: turn
+                                            .actions
+                                            .iter()
+                                            .map(|a| ReplayActionLogAction {
+                                                action: a.action.clone(),
+                                            })
+                                            .collect(),
+                                    })
+                                })
+                                .collect(),
+                        })
+                        .collect(),
+                })
+                .collect(),
+            players: game
+                .players
+                .iter()
+                .map(|p| ReplayPlayerData {
+                    id: p.index,
+                    name: p.name.clone(),
+                    civilization: p.civilization.name.clone(),
+                })
+                .collect(),
+            dropped_players: game.dropped_players.clone(),
+        }
+    }
+}
+
 /// replay is used to store the game data for replay
 ///
 /// # Panics
 ///
 /// Panics if the game data cannot be replayed
 #[must_use]
-pub fn replay(mut data: ReplayGameData, to: Option<usize>) -> Game {
+pub fn replay(data: ReplayGameData, to: Option<usize>) -> Game {
+    replay_and_observe(data, to, |_, _| {})
+}
+
+/// Replays the game and calls `observe` before each action is executed.
+///
+/// # Panics
+///
+/// Panics if the game data cannot be replayed
+pub fn replay_and_observe(
+    mut data: ReplayGameData,
+    to: Option<usize>,
+    mut observe: impl FnMut(&Game, &Action),
+) -> Game {
     let log = linear_action_log(mem::take(&mut data.action_log));
     let to = to.unwrap_or(log.len() - 1);
     let random = data.options.civilization == CivSetupOption::Random;
@@ -97,6 +160,7 @@ pub fn replay(mut data: ReplayGameData, to: Option<usize>) -> Game {
         }
         println!("Executing action {i} {id}: {a:?}");
 
+        observe(&game, &a);
         let player_index = game.active_player();
         match try_execute_action(game, a.clone(), player_index) {
             Ok(g) => game = g,
@@ -0,0 +1,183 @@
+use crate::city::City;
+use crate::content::persistent_events::PersistentEventType;
+use crate::game::Game;
+use crate::objective_card::{Objective, ObjectiveType};
+use crate::player::{CostTrigger, Player};
+use crate::position::Position;
+use crate::wonder::{Wonder, WonderInfo, can_place_wonder, wonder_cost};
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+///
+/// Which objectives and wonders could be completed in the current position -
+/// used by the rules team to validate the rules against real games.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct RulesInspection {
+    pub objectives: Vec<ObjectiveInspection>,
+    pub wonders: Vec<WonderInspection>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct ObjectiveInspection {
+    pub name: String,
+    pub description: String,
+    pub instant: bool,
+    // the objective cards that have this objective
+    pub cards: Vec<u8>,
+    pub players: Vec<ObjectiveStatus>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct ObjectiveStatus {
+    pub player: usize,
+    pub completable: bool,
+    pub has_card: bool,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub reasons: Vec<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct WonderInspection {
+    pub wonder: Wonder,
+    pub name: String,
+    pub cities: Vec<WonderPlacement>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct WonderPlacement {
+    pub player: usize,
+    pub city: Position,
+    pub completable: bool,
+    pub has_card: bool,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub reasons: Vec<String>,
+}
+
+///
+/// Evaluates all objectives and wonder placements for all human players.
+///
+/// Resources, actions and owning the card are not taken into account,
+/// because the rules logic is checked - not the current options of a player.
+#[must_use]
+pub fn inspect_rules(game: &Game) -> RulesInspection {
+    let players = game
+        .human_player_ids()
+        .into_iter()
+        .map(|p| game.player(p))
+        .collect_vec();
+    RulesInspection {
+        objectives: game
+            .cache
+            .get_objectives()
+            .iter()
+            .map(|o| inspect_objective(game, &players, o))
+            .collect(),
+        wonders: game
+            .cache
+            .get_wonders()
+            .iter()
+            .map(|w| inspect_wonder(game, &players, w))
+            .collect(),
+    }
+}
+
+fn inspect_objective(
+    game: &Game,
+    players: &[&Player],
+    objective: &Objective,
+) -> ObjectiveInspection {
+    let cards = game
+        .cache
+        .get_objective_cards()
+        .iter()
+        .filter(|c| c.objectives.iter().any(|o| o.name == objective.name))
+        .map(|c| c.id)
+        .collect_vec();
+    let instant = matches!(objective.get_type(), ObjectiveType::Instant);
+    ObjectiveInspection {
+        name: objective.name.clone(),
+        description: objective.description.clone(),
+        instant,
+        players: players
+            .iter()
+            .map(|p| {
+                let reasons = objective_reasons(game, p, objective);
+                ObjectiveStatus {
+                    player: p.index,
+                    completable: reasons.is_empty(),
+                    has_card: p.objective_cards.iter().any(|c| cards.contains(c)),
+                    reasons,
+                }
+            })
+            .collect(),
+        cards,
+    }
+}
+
+fn objective_reasons(game: &Game, player: &Player, objective: &Objective) -> Vec<String> {
+    match &objective.status_phase_check {
+        Some(check) if check(game, player) => vec![],
+        Some(_) => vec![
+            objective
+                .status_phase_progress(game, player)
+                .unwrap_or_else(|| format!("Requirement not met: {}", objective.description)),
+        ],
+        None if instant_objective_ready(game, player, &objective.name) => vec![],
+        None => vec![
+            "Instant objective: the triggering event did not happen in the current turn"
+                .to_string(),
+        ],
+    }
+}
+
+// the opportunities are collected in the player until the objective cards are presented
+fn instant_objective_ready(game: &Game, player: &Player, name: &str) -> bool {
+    if player.objective_opportunities.iter().any(|o| o == name) {
+        return true;
+    }
+    game.events.iter().any(|e| {
+        e.player.index == player.index
+            && matches!(&e.event_type, PersistentEventType::SelectObjectives(i)
+                if i.shown_objective.as_deref() == Some(name)
+                    || i.objective_opportunities.iter().any(|o| o == name))
+    })
+}
+
+fn inspect_wonder(game: &Game, players: &[&Player], info: &WonderInfo) -> WonderInspection {
+    WonderInspection {
+        wonder: info.wonder,
+        name: info.name(),
+        cities: players
+            .iter()
+            .flat_map(|p| {
+                p.cities.iter().map(|city| {
+                    let reasons = wonder_reasons(game, p, city, info);
+                    WonderPlacement {
+                        player: p.index,
+                        city: city.position,
+                        completable: reasons.is_empty(),
+                        has_card: p.wonder_cards.contains(&info.wonder),
+                        reasons,
+                    }
+                })
+            })
+            .collect(),
+    }
+}
+
+fn wonder_reasons(game: &Game, player: &Player, city: &City, info: &WonderInfo) -> Vec<String> {
+    let cost = wonder_cost(game, player, info.wonder);
+    can_place_wonder(
+        city,
+        info.wonder,
+        player,
+        game,
+        cost,
+        CostTrigger::NoModifiers,
+    )
+    .err()
+    .into_iter()
+    .collect()
+}
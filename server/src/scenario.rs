@@ -0,0 +1,277 @@
+use crate::advance::{Advance, do_advance};
+use crate::city::{City, MoodState, gain_city, set_city_mood};
+use crate::city_pieces::{Building, gain_building};
+use crate::events::{EventOrigin, EventPlayer};
+use crate::game::{CivSetupOption, Game, GameOptions, GameState};
+use crate::map::{Block, BlockPosition, Rotation, Terrain};
+use crate::player::gain_unit;
+use crate::position::Position;
+use crate::resource_pile::ResourcePile;
+use crate::unit::UnitType;
+use crate::wonder::Wonder;
+use serde::{Deserialize, Serialize};
+
+///
+/// A predefined starting position with special rules, e.g. a historical scenario
+/// or a teaching setup.
+///
+/// A scenario replaces the random map and the usual starting city of each player.
+#[derive(Clone)]
+pub struct Scenario {
+    pub name: String,
+    pub description: String,
+    pub options: GameOptions,
+    pub map: Vec<(Position, Terrain)>,
+    pub players: Vec<ScenarioPlayer>,
+    pub removed_action_cards: Vec<u8>,
+    pub removed_objective_cards: Vec<u8>,
+    pub removed_wonders: Vec<Wonder>,
+    pub victory_conditions: Vec<VictoryCondition>,
+}
+
+impl Scenario {
+    #[must_use]
+    pub fn new(name: &str, description: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            description: description.to_string(),
+            options: GameOptions::default(),
+            map: Vec::new(),
+            players: Vec::new(),
+            removed_action_cards: Vec::new(),
+            removed_objective_cards: Vec::new(),
+            removed_wonders: Vec::new(),
+            victory_conditions: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn options(mut self, options: GameOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    #[must_use]
+    pub fn add_block(mut self, top_tile: &str, rotation: Rotation, terrain: [Terrain; 4]) -> Self {
+        let position = BlockPosition::new(Position::from_offset(top_tile), rotation);
+        self.map
+            .extend(Block::new(terrain).tiles(&position, rotation));
+        self
+    }
+
+    #[must_use]
+    pub fn add_player(mut self, player: ScenarioPlayer) -> Self {
+        self.players.push(player);
+        self
+    }
+
+    #[must_use]
+    pub fn remove_action_cards(mut self, ids: &[u8]) -> Self {
+        self.removed_action_cards.extend(ids);
+        self
+    }
+
+    #[must_use]
+    pub fn remove_objective_cards(mut self, ids: &[u8]) -> Self {
+        self.removed_objective_cards.extend(ids);
+        self
+    }
+
+    #[must_use]
+    pub fn remove_wonders(mut self, wonders: &[Wonder]) -> Self {
+        self.removed_wonders.extend(wonders);
+        self
+    }
+
+    #[must_use]
+    pub fn add_victory_condition(mut self, condition: VictoryCondition) -> Self {
+        self.victory_conditions.push(condition);
+        self
+    }
+
+    ///
+    /// The options of a game that is set up from this scenario.
+    #[must_use]
+    pub fn game_options(&self) -> GameOptions {
+        GameOptions {
+            scenario: Some(self.name.clone()),
+            // the civilizations are given by the scenario
+            civilization: CivSetupOption::Random,
+            ..self.options.clone()
+        }
+    }
+
+    #[must_use]
+    pub fn civilizations(&self) -> Vec<String> {
+        self.players
+            .iter()
+            .map(|p| p.civilization.clone())
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ScenarioPlayer {
+    pub civilization: String,
+    pub resources: ResourcePile,
+    pub advances: Vec<Advance>,
+    // the first city is the capital
+    pub cities: Vec<ScenarioCity>,
+    pub units: Vec<(Position, UnitType)>,
+}
+
+impl ScenarioPlayer {
+    #[must_use]
+    pub fn new(civilization: &str, resources: ResourcePile) -> Self {
+        Self {
+            civilization: civilization.to_string(),
+            resources,
+            advances: vec![Advance::Farming, Advance::Mining],
+            cities: Vec::new(),
+            units: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn advances(mut self, advances: &[Advance]) -> Self {
+        self.advances.extend(advances);
+        self
+    }
+
+    #[must_use]
+    pub fn add_city(
+        mut self,
+        position: &str,
+        mood_state: MoodState,
+        buildings: &[Building],
+    ) -> Self {
+        self.cities.push(ScenarioCity {
+            position: Position::from_offset(position),
+            mood_state,
+            buildings: buildings.to_vec(),
+        });
+        self
+    }
+
+    #[must_use]
+    pub fn add_units(mut self, position: &str, units: &[UnitType]) -> Self {
+        let position = Position::from_offset(position);
+        self.units.extend(units.iter().map(|u| (position, *u)));
+        self
+    }
+
+    ///
+    /// # Panics
+    ///
+    /// Panics if the player has no cities
+    #[must_use]
+    pub fn capital(&self) -> Position {
+        self.cities
+            .first()
+            .expect("scenario player should have a capital")
+            .position
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct ScenarioCity {
+    pub position: Position,
+    pub mood_state: MoodState,
+    pub buildings: Vec<Building>,
+}
+
+///
+/// A player who fulfills a victory condition wins the game immediately.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VictoryCondition {
+    // have this many wonders in own cities
+    HoldWonders(usize),
+    // own the capital of another player
+    CaptureCapital,
+}
+
+impl VictoryCondition {
+    #[must_use]
+    pub fn description(&self) -> String {
+        match self {
+            VictoryCondition::HoldWonders(n) => format!("Hold {n} wonders in your cities"),
+            VictoryCondition::CaptureCapital => "Capture the capital of another player".to_string(),
+        }
+    }
+
+    fn is_fulfilled(self, game: &Game, scenario: &Scenario, player: usize) -> bool {
+        match self {
+            VictoryCondition::HoldWonders(n) => {
+                game.player(player)
+                    .cities
+                    .iter()
+                    .map(|c| c.pieces.wonders.len())
+                    .sum::<usize>()
+                    >= n
+            }
+            VictoryCondition::CaptureCapital => {
+                scenario.players.iter().enumerate().any(|(owner, p)| {
+                    owner != player
+                        && game.teammate(player) != Some(owner)
+                        && game
+                            .try_get_any_city(p.capital())
+                            .is_some_and(|c| c.player_index == player)
+                })
+            }
+        }
+    }
+}
+
+pub(crate) fn scenario_origin(scenario: &Scenario) -> EventOrigin {
+    EventOrigin::Ability(scenario.name.clone())
+}
+
+pub(crate) fn setup_scenario_player(game: &mut Game, scenario: &Scenario, player_index: usize) {
+    let s = &scenario.players[player_index];
+    let origin = scenario_origin(scenario);
+    let player = &EventPlayer::new(player_index, origin.clone());
+    player.gain_resources(game, s.resources.clone());
+    for advance in &s.advances {
+        do_advance(game, *advance, player, false);
+    }
+    for city in &s.cities {
+        gain_city(game, player, City::new(player_index, city.position));
+        set_city_mood(game, city.position, &origin, city.mood_state.clone());
+        for building in &city.buildings {
+            gain_building(game, player, *building, city.position);
+        }
+    }
+    for (position, unit_type) in &s.units {
+        gain_unit(game, player, *position, *unit_type);
+    }
+}
+
+pub(crate) fn remove_scenario_cards(game: &mut Game, scenario: &Scenario) {
+    game.action_cards_left
+        .retain(|id| !scenario.removed_action_cards.contains(id));
+    game.objective_cards_left
+        .retain(|id| !scenario.removed_objective_cards.contains(id));
+    game.wonders_left
+        .retain(|w| !scenario.removed_wonders.contains(w));
+}
+
+pub(crate) fn check_scenario_victory(game: &mut Game) {
+    let Some(name) = &game.options.scenario else {
+        return;
+    };
+    // don't end the game in the middle of an event
+    if !game.events.is_empty() || matches!(game.state, GameState::Finished) {
+        return;
+    }
+    let scenario = game.cache.get_scenario(name);
+    let winner = game.human_player_ids().into_iter().find_map(|p| {
+        scenario
+            .victory_conditions
+            .iter()
+            .find(|c| c.is_fulfilled(game, scenario, p))
+            .map(|c| (p, c.description()))
+    });
+    if let Some((player, reason)) = winner {
+        game.end_game_with_winner(player, &reason);
+    }
+}
@@ -0,0 +1,68 @@
+use crate::consts::AGES;
+use crate::events::{EventOrigin, EventPlayer};
+use crate::game::Game;
+use crate::localization::Message;
+use crate::victory_points::victory_points_target_reached;
+
+// the score a solo player must reach at the end of each age
+pub const SOLO_SCORE_TARGETS: [f32; AGES as usize] = [4., 8., 12., 17., 22., 28.];
+
+// incidents are triggered every 2 advances (instead of 3)
+pub(crate) const SOLO_INCIDENT_TOKENS: u8 = 2;
+
+pub(crate) const INCIDENT_TOKENS: u8 = 3;
+
+///
+/// Returns the score the solo player must reach at the end of the given age.
+///
+/// # Panics
+///
+/// Panics if the age is not between 1 and [`AGES`].
+#[must_use]
+pub fn solo_score_target(age: u32) -> f32 {
+    assert!(
+        (1..=AGES).contains(&age),
+        "age {age} is not between 1 and {AGES}"
+    );
+    SOLO_SCORE_TARGETS[age as usize - 1]
+}
+
+#[must_use]
+pub(crate) fn incident_tokens(game: &Game) -> u8 {
+    if game.is_solo() {
+        SOLO_INCIDENT_TOKENS
+    } else {
+        INCIDENT_TOKENS
+    }
+}
+
+#[must_use]
+pub(crate) fn missed_solo_score_target(game: &Game) -> bool {
+    game.is_solo()
+        && game.age > 0
+        && game
+            .human_player_ids()
+            .iter()
+            .any(|&p| game.player(p).victory_points(game) < solo_score_target(game.age))
+}
+
+pub(crate) fn end_solo_game(game: &mut Game) {
+    let target = solo_score_target(game.age);
+    let won = !missed_solo_score_target(game)
+        && (game.age >= game.options.ages() || victory_points_target_reached(game));
+    for p in game.human_player_ids() {
+        let score = game.player(p).victory_points(game);
+        let message = if won {
+            Message::new("log.solo.won", "wins the solo game with {score} points")
+        } else {
+            Message::new(
+                "log.solo.lost",
+                "loses the solo game with {score} points (needed {target} in age {age})",
+            )
+            .param("target", target)
+            .param("age", game.age)
+        };
+        EventPlayer::new(p, EventOrigin::Ability("Solo game".to_string()))
+            .log_message(game, message.param("score", score));
+    }
+}
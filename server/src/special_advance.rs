@@ -35,6 +35,28 @@ pub enum SpecialAdvance {
     Longships,
     Raiding,
     RuneStones,
+
+    // Egypt
+    Flood,
+    MasterBuilders,
+    Hieroglyphs,
+    Pharaoh,
+
+    // Persia
+    RoyalRoad,
+    Satrapies,
+    HorseBreeding,
+    Immortals,
+
+    // reserved for content packs
+    Custom1,
+    Custom2,
+    Custom3,
+    Custom4,
+    Custom5,
+    Custom6,
+    Custom7,
+    Custom8,
 }
 
 #[allow(clippy::trivially_copy_pass_by_ref)]
@@ -132,6 +154,7 @@ impl SpecialAdvanceBuilder {
         }
     }
 
+    #[must_use]
     pub fn build(self) -> SpecialAdvanceInfo {
         SpecialAdvanceInfo::new(
             self.advance,
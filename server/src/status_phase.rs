@@ -2,7 +2,6 @@ use crate::ability_initializer::AbilityInitializerSetup;
 use crate::action_card::gain_action_card_from_pile;
 use crate::advance::{Advance, do_advance, gain_advance_without_payment, remove_advance};
 use crate::city::raze_city;
-use crate::consts::AGES;
 use crate::content::ability::Ability;
 use crate::content::persistent_events::{
     AdvanceRequest, EventResponse, PaymentRequest, PersistentEventRequest, PersistentEventType,
@@ -10,12 +9,14 @@ use crate::content::persistent_events::{
     trigger_persistent_event_with_listener,
 };
 use crate::events::{EventOrigin, EventPlayer};
+use crate::localization::Message;
 use crate::log::{TurnType, add_start_turn_action_if_needed, add_turn_log};
 use crate::objective_card::{
     gain_objective_card_from_pile, present_objective_cards, status_phase_completable,
 };
 use crate::payment::PaymentOptions;
 use crate::player_events::{PersistentEvent, PersistentEvents};
+use crate::solo::missed_solo_score_target;
 use crate::wonder::Wonder;
 use crate::{game::Game, player::Player, resource_pile::ResourcePile};
 use itertools::Itertools;
@@ -117,12 +118,13 @@ fn play_status_phase(game: &mut Game, mut phase: StatusPhaseState, response: boo
 
         phase = match phase {
             CompleteObjectives => {
-                if game.age == AGES
+                if game.age >= game.options.ages()
                     || game
                         .players
                         .iter()
                         .filter(|player| player.is_human())
                         .any(|player| player.cities.is_empty())
+                    || missed_solo_score_target(game)
                 {
                     game.end_game();
                     return;
@@ -234,7 +236,13 @@ pub(crate) fn use_raze_city() -> Ability {
             },
             |game, s, _| {
                 if s.choice.is_empty() {
-                    s.log(game, "Did not raze a city");
+                    s.log_message(
+                        game,
+                        Message::new(
+                            "log.status_phase.did_not_raze_a_city",
+                            "Did not raze a city",
+                        ),
+                    );
                     return;
                 }
                 let player = &s.player();
@@ -323,7 +331,13 @@ where
         move |game, s, v| {
             let cost = &s.choice[0];
             if cost.is_empty() {
-                s.log(game, "Keep current government");
+                s.log_message(
+                    game,
+                    Message::new(
+                        "log.status_phase.keep_current_government",
+                        "Keep current government",
+                    ),
+                );
             } else {
                 set_paid2(v, true);
             }
@@ -342,30 +356,35 @@ where
             if let PersistentEventRequest::ChangeGovernment = &request
                 && let EventResponse::ChangeGovernmentType(c) = action
             {
-                p.log(
+                p.log_message(
                     game,
-                    &format!(
-                        "{} changed their government from {} to {}",
+                    Message::new(
+                        "log.change_government",
+                        "{player} changed their government from {old} to {new}",
+                    )
+                    .param("player", p.name(game))
+                    .param(
+                        "old",
                         p.get(game)
                             .government(game)
                             .expect("player should have a government before changing it"),
-                        p.name(game),
-                        c.new_government
-                    ),
+                    )
+                    .param("new", &c.new_government),
                 );
-                p.log(
+                p.log_message(
                     game,
-                    &format!(
-                        "Additional advances: {}",
-                        if c.additional_advances.is_empty() {
-                            "none".to_string()
-                        } else {
-                            c.additional_advances
-                                .iter()
-                                .map(|a| a.name(game))
-                                .join(", ")
-                        }
-                    ),
+                    if c.additional_advances.is_empty() {
+                        Message::new("log.additional_advances.none", "Additional advances: none")
+                    } else {
+                        Message::new("log.additional_advances", "Additional advances: {advances}")
+                            .param(
+                                "advances",
+                                c.additional_advances
+                                    .iter()
+                                    .map(|a| a.name(game))
+                                    .join(", "),
+                            )
+                    },
                 );
                 change_government_type(game, p, &c);
                 return;
@@ -474,31 +493,9 @@ pub(crate) fn determine_first_player() -> Ability {
                 }
             },
             |game, s, _| {
-                s.log(
+                s.log_message(
                     game,
-                    &format!(
-                        "{} choose {}",
-                        game.player_name(s.player_index),
-                        if s.choice == game.starting_player_index {
-                            format!(
-                                "{} to remain the staring player",
-                                if s.choice == game.active_player() {
-                                    String::new()
-                                } else {
-                                    game.player_name(s.choice)
-                                }
-                            )
-                        } else {
-                            format!(
-                                "{} as the new starting player",
-                                if s.choice == game.active_player() {
-                                    String::from("themselves")
-                                } else {
-                                    game.player_name(s.choice)
-                                }
-                            )
-                        }
-                    ),
+                    starting_player_message(game, s.player_index, s.choice),
                 );
                 game.starting_player_index = s.choice;
             },
@@ -506,6 +503,31 @@ pub(crate) fn determine_first_player() -> Ability {
         .build()
 }
 
+fn starting_player_message(game: &Game, player: usize, choice: usize) -> Message {
+    let remain = choice == game.starting_player_index;
+    let message = match (remain, choice == game.active_player()) {
+        (true, true) => Message::new(
+            "log.starting_player.remain",
+            "{player} choose to remain the starting player",
+        ),
+        (true, false) => Message::new(
+            "log.starting_player.other_remains",
+            "{player} choose {other} to remain the starting player",
+        ),
+        (false, true) => Message::new(
+            "log.starting_player.self",
+            "{player} choose themselves as the new starting player",
+        ),
+        (false, false) => Message::new(
+            "log.starting_player.other",
+            "{player} choose {other} as the new starting player",
+        ),
+    };
+    message
+        .param("player", game.player_name(player))
+        .param("other", game.player_name(choice))
+}
+
 fn player_that_chooses_next_first_player(players: &[&Player]) -> usize {
     players
         .iter()
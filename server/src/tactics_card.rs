@@ -10,6 +10,7 @@ use crate::combat_listeners::{CombatRoundEnd, CombatRoundStart, CombatStrength};
 use crate::content::persistent_events::HandCardsRequest;
 use crate::events::{EventOrigin, EventPlayer};
 use crate::game::Game;
+use crate::localization::Message;
 use crate::player_events::{PersistentEvent, PersistentEvents};
 use action_card::discard_action_card;
 use std::fmt::Display;
@@ -304,8 +305,10 @@ pub(crate) fn play_tactics_card(b: AdvanceBuilder) -> AdvanceBuilder {
                 && c.modifiers.contains(&CombatModifier::TrojanHorse)
             {
                 update_combat_strength(game, p, s, |_game, _c, s, _role| {
-                    s.roll_log
-                        .push("Trojan Horse denied playing Tactics Cards".to_string());
+                    s.roll_log.push(Message::new(
+                        "log.combat_value.trojan_horse_denied_playing_tactics_cards",
+                        "Trojan Horse denied playing Tactics Cards",
+                    ));
                 });
 
                 return None;
@@ -315,7 +318,13 @@ pub(crate) fn play_tactics_card(b: AdvanceBuilder) -> AdvanceBuilder {
         },
         |game, s, r| {
             if s.choice.is_empty() {
-                s.log(game, "Did not play a Tactics Card");
+                s.log_message(
+                    game,
+                    Message::new(
+                        "log.tactics_card.did_not_play_a_tactics_card",
+                        "Did not play a Tactics Card",
+                    ),
+                );
             } else {
                 let player = s.player_index;
                 let HandCard::ActionCard(card) = s.choice[0] else {
@@ -0,0 +1,57 @@
+use crate::ability_initializer::AbilityInitializerSetup;
+use crate::content::ability::{Ability, AbilityBuilder};
+use crate::content::custom_actions::CustomActionType;
+use crate::content::persistent_events::PaymentRequest;
+use crate::events::EventPlayer;
+use crate::resource_pile::ResourcePile;
+use crate::trade::{give_options, tradeable_resources};
+use itertools::Itertools;
+
+pub(crate) fn team_trade() -> Ability {
+    Ability::builder(
+        "Team Trade",
+        "As a free action, give any number of resources to your teammate.",
+    )
+    .add_custom_action(
+        CustomActionType::TeamTrade,
+        |c| c.any_times().free_action().no_resources(),
+        use_team_trade,
+        |game, p| {
+            game.teammate(p.index)
+                .is_some_and(|t| game.player(t).is_human())
+                && !tradeable_resources(p).is_empty()
+        },
+    )
+    .build()
+}
+
+fn use_team_trade(b: AbilityBuilder) -> AbilityBuilder {
+    b.add_payment_request_listener(
+        |e| &mut e.custom_action,
+        0,
+        |game, p, _| {
+            let player = p.get(game);
+            Some(
+                tradeable_resources(player)
+                    .into_iter()
+                    .map(|(t, amount)| {
+                        PaymentRequest::optional(
+                            give_options(t, amount, p.origin.clone()),
+                            &format!("Select {t} to give to your teammate"),
+                        )
+                    })
+                    .collect_vec(),
+            )
+        },
+        |game, s, _| {
+            let teammate = game
+                .teammate(s.player_index)
+                .expect("team trade needs a teammate");
+            let given = s.choice.iter().cloned().sum::<ResourcePile>();
+            if given.is_empty() {
+                return;
+            }
+            EventPlayer::new(teammate, s.origin.clone()).gain_resources(game, given);
+        },
+    )
+}
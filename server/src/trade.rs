@@ -0,0 +1,400 @@
+use crate::ability_initializer::AbilityInitializerSetup;
+use crate::card::{HandCard, HandCardLocation, all_action_hand_cards, log_card_transfer};
+use crate::content::ability::{Ability, AbilityBuilder};
+use crate::content::custom_actions::CustomActionType;
+use crate::content::persistent_events::{
+    EventResponse, HandCardsRequest, PaymentRequest, PersistentEventRequest, PersistentEventType,
+    PlayerRequest, TriggerPersistentEventParams, trigger_persistent_event_ext,
+};
+use crate::events::{EventOrigin, EventPlayer};
+use crate::game::Game;
+use crate::localization::Message;
+use crate::log::ActionLogBalance;
+use crate::payment::{PaymentConversion, PaymentConversionType, PaymentOptions};
+use crate::player::Player;
+use crate::resource::ResourceType;
+use crate::resource_pile::ResourcePile;
+use crate::utils::remove_element;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug, Default)]
+pub struct TradeGoods {
+    #[serde(default)]
+    #[serde(skip_serializing_if = "ResourcePile::is_empty")]
+    pub resources: ResourcePile,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub action_cards: Vec<u8>,
+}
+
+impl TradeGoods {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.resources.is_empty() && self.action_cards.is_empty()
+    }
+
+    fn describe(&self, game: &Game) -> String {
+        if self.is_empty() {
+            return "nothing".to_string();
+        }
+        let mut parts = Vec::new();
+        if !self.resources.is_empty() {
+            parts.push(self.resources.to_string());
+        }
+        parts.extend(
+            self.action_cards
+                .iter()
+                .map(|id| game.cache.get_action_card(*id).civil_card.name.clone()),
+        );
+        parts.join(", ")
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum TradeStep {
+    Offer,
+    // the partner accepts the offer, makes a counter-offer or declines
+    Response,
+    // the proposer accepts or declines the counter-offer
+    Accept,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Eq, Debug)]
+pub struct TradeInfo {
+    pub step: TradeStep,
+    pub proposer: usize,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub partner: Option<usize>,
+    // what the proposer gives
+    pub offer: TradeGoods,
+    // what the partner gives
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub counter_offer: Option<TradeGoods>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub accepted: bool,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub declined: bool,
+}
+
+impl TradeInfo {
+    #[must_use]
+    pub fn new(proposer: usize) -> Self {
+        Self {
+            step: TradeStep::Offer,
+            proposer,
+            partner: None,
+            offer: TradeGoods::default(),
+            counter_offer: None,
+            accepted: false,
+            declined: false,
+        }
+    }
+
+    fn partner(&self) -> usize {
+        self.partner.expect("trade partner should be selected")
+    }
+
+    fn current_player(&self) -> usize {
+        match self.step {
+            TradeStep::Offer | TradeStep::Accept => self.proposer,
+            TradeStep::Response => self.partner(),
+        }
+    }
+
+    fn selects_goods(&self) -> bool {
+        match self.step {
+            TradeStep::Offer => true,
+            TradeStep::Response => !self.accepted && !self.declined,
+            TradeStep::Accept => false,
+        }
+    }
+
+    fn goods_mut(&mut self) -> &mut TradeGoods {
+        if self.step == TradeStep::Offer {
+            &mut self.offer
+        } else {
+            self.counter_offer.get_or_insert_default()
+        }
+    }
+
+    pub fn strip_secret(&mut self, player_index: Option<usize>) {
+        if player_index != Some(self.proposer) && player_index != self.partner {
+            // only the trading players see the offered action cards
+            for goods in [Some(&mut self.offer), self.counter_offer.as_mut()]
+                .into_iter()
+                .flatten()
+            {
+                goods.action_cards = goods.action_cards.iter().map(|_| 0).collect();
+            }
+        }
+    }
+}
+
+pub(crate) fn use_trade() -> Ability {
+    let b = Ability::builder(
+        "Trade",
+        "Once per turn, as a free action: Offer resources and action cards to another player, \
+        who may accept the offer, decline it or make a counter-offer \
+        that you can accept or decline.",
+    )
+    .add_custom_action(
+        CustomActionType::Trade,
+        |c| c.once_per_turn().free_action().no_resources(),
+        |b| {
+            b.add_simple_persistent_event_listener(
+                |event| &mut event.custom_action,
+                0,
+                |game, p, _| play_trade(game, TradeInfo::new(p.index)),
+            )
+        },
+        |game, p| game.options.trading && !trade_partners(game, p.index).is_empty(),
+    )
+    .add_player_request(
+        |event| &mut event.trade,
+        5,
+        |game, p, info| {
+            (info.step == TradeStep::Offer).then(|| {
+                PlayerRequest::new(
+                    trade_partners(game, p.index),
+                    "Select a player to trade with",
+                )
+            })
+        },
+        |_game, s, info| {
+            info.partner = Some(s.choice);
+        },
+    )
+    .add_bool_request(
+        |event| &mut event.trade,
+        4,
+        |game, _p, info| match info.step {
+            TradeStep::Offer => None,
+            // keyed by the templates in `localization::PROMPTS`
+            TradeStep::Response => Some(format!(
+                "{} offers {}. Do you want to accept the offer?",
+                game.player_name(info.proposer),
+                info.offer.describe(game),
+            )),
+            TradeStep::Accept => Some(format!(
+                "Do you want to give {} to {} for {}?",
+                info.offer.describe(game),
+                game.player_name(info.partner()),
+                info.counter_offer
+                    .as_ref()
+                    .expect("counter-offer should exist")
+                    .describe(game),
+            )),
+        },
+        |game, s, info| {
+            if s.choice {
+                info.accepted = true;
+                s.log_message(
+                    game,
+                    Message::new("log.trade.accepted_the_trade", "Accepted the trade"),
+                );
+            } else if info.step == TradeStep::Accept {
+                info.declined = true;
+                s.log_message(
+                    game,
+                    Message::new("log.trade.declined_the_trade", "Declined the trade"),
+                );
+            }
+        },
+    )
+    .add_bool_request(
+        |event| &mut event.trade,
+        3,
+        |_game, _p, info| {
+            (info.step == TradeStep::Response && !info.accepted).then(|| {
+                "Do you want to make a counter-offer? Otherwise the trade is declined.".to_string()
+            })
+        },
+        |game, s, info| {
+            if !s.choice {
+                info.declined = true;
+                s.log_message(
+                    game,
+                    Message::new("log.trade.declined_the_trade", "Declined the trade"),
+                );
+            }
+        },
+    );
+    select_goods(b).build()
+}
+
+fn select_goods(b: AbilityBuilder) -> AbilityBuilder {
+    b.add_persistent_event_listener(
+        |event| &mut event.trade,
+        2,
+        |game, p, info| {
+            if !info.selects_goods() {
+                return None;
+            }
+            let requests = tradeable_resources(p.get(game))
+                .into_iter()
+                .map(|(t, amount)| {
+                    PaymentRequest::optional(
+                        give_options(t, amount, p.origin.clone()),
+                        &format!("Select {t} to offer"),
+                    )
+                })
+                .collect_vec();
+            (!requests.is_empty()).then_some(PersistentEventRequest::Payment(requests))
+        },
+        |_game, _p, action, request, info| {
+            let (PersistentEventRequest::Payment(requests), EventResponse::Payment(payments)) =
+                (request, action)
+            else {
+                panic!("Expected payment response");
+            };
+            assert_eq!(requests.len(), payments.len());
+            for (request, payment) in requests.iter().zip(&payments) {
+                assert!(
+                    payment.is_empty() || request.cost.is_valid_payment(payment),
+                    "invalid offer {payment}"
+                );
+            }
+            info.goods_mut().resources = payments.into_iter().sum();
+        },
+    )
+    .add_hand_card_request(
+        |event| &mut event.trade,
+        1,
+        |game, p, info| {
+            let cards = all_action_hand_cards(p.get(game));
+            (info.selects_goods() && !cards.is_empty()).then(|| {
+                let max = cards.len() as u8;
+                HandCardsRequest::new(cards, 0..=max, "Select action cards to offer")
+            })
+        },
+        |_game, s, info| {
+            info.goods_mut().action_cards = s
+                .choice
+                .iter()
+                .map(|c| match c {
+                    HandCard::ActionCard(id) => *id,
+                    _ => panic!("only action cards can be traded"),
+                })
+                .collect();
+        },
+    )
+}
+
+fn trade_partners(game: &Game, player_index: usize) -> Vec<usize> {
+    game.human_player_ids()
+        .into_iter()
+        .filter(|p| *p != player_index)
+        .collect()
+}
+
+pub(crate) fn trade_response(game: &mut Game, info: TradeInfo) {
+    play_trade(game, info);
+}
+
+fn play_trade(game: &mut Game, mut info: TradeInfo) {
+    loop {
+        info = match trigger_persistent_event_ext(
+            game,
+            &[info.current_player()],
+            |events| &mut events.trade,
+            info.clone(),
+            PersistentEventType::Trade,
+            TriggerPersistentEventParams::default(),
+        ) {
+            Some(i) => i,
+            None => return,
+        };
+
+        match info.step {
+            TradeStep::Offer => info.step = TradeStep::Response,
+            TradeStep::Response => {
+                if info.accepted {
+                    execute_trade(game, &info);
+                    return;
+                }
+                if info.declined {
+                    return;
+                }
+                info.counter_offer.get_or_insert_default();
+                info.step = TradeStep::Accept;
+            }
+            TradeStep::Accept => {
+                if info.accepted {
+                    execute_trade(game, &info);
+                }
+                return;
+            }
+        }
+    }
+}
+
+fn execute_trade(game: &mut Game, info: &TradeInfo) {
+    let partner = info.partner();
+    // the partner gives nothing if the offer was accepted as made
+    let counter_offer = info.counter_offer.clone().unwrap_or_default();
+    // first lose all goods, so that nothing is wasted that could be traded away
+    lose_goods(game, info.proposer, &info.offer);
+    lose_goods(game, partner, &counter_offer);
+    gain_goods(game, info.proposer, partner, &info.offer);
+    gain_goods(game, partner, info.proposer, &counter_offer);
+}
+
+fn lose_goods(game: &mut Game, player: usize, goods: &TradeGoods) {
+    if !goods.resources.is_empty() {
+        EventPlayer::new(player, trade_origin()).lose_resources(
+            game,
+            goods.resources.clone(),
+            ActionLogBalance::Pay,
+        );
+    }
+    for card in &goods.action_cards {
+        remove_element(&mut game.player_mut(player).action_cards, card);
+    }
+}
+
+fn gain_goods(game: &mut Game, from: usize, to: usize, goods: &TradeGoods) {
+    if !goods.resources.is_empty() {
+        EventPlayer::new(to, trade_origin()).gain_resources(game, goods.resources.clone());
+    }
+    for card in &goods.action_cards {
+        game.player_mut(to).action_cards.push(*card);
+        log_card_transfer(
+            game,
+            &HandCard::ActionCard(*card),
+            HandCardLocation::Hand(from),
+            HandCardLocation::Hand(to),
+            &trade_origin(),
+        );
+    }
+}
+
+fn trade_origin() -> EventOrigin {
+    EventOrigin::Ability("Trade".to_string())
+}
+
+pub(crate) fn tradeable_resources(player: &Player) -> Vec<(ResourceType, u8)> {
+    ResourceType::resources()
+        .into_iter()
+        .map(|t| (t, player.resources.get(&t)))
+        .filter(|(_, amount)| *amount > 0)
+        .collect()
+}
+
+// 1 to amount resources of the given type
+pub(crate) fn give_options(t: ResourceType, amount: u8, origin: EventOrigin) -> PaymentOptions {
+    PaymentOptions::new(
+        ResourcePile::of(t, amount),
+        vec![PaymentConversion::resource_options(
+            vec![ResourcePile::of(t, 1)],
+            ResourcePile::empty(),
+            PaymentConversionType::MayOverpay(amount - 1),
+        )],
+        vec![],
+        origin,
+    )
+}
@@ -1,5 +1,6 @@
 use crate::action::{Action, after_action};
 use crate::game::Game;
+use crate::game_data::{GameData, undo_data};
 use crate::log::{current_turn_log, current_turn_log_mut};
 use crate::movement::execute_movement_action;
 use json_patch::{PatchOperation, patch};
@@ -33,20 +34,23 @@ pub(crate) fn undo(mut game: Game) -> Result<Game, String> {
         _ => {}
     }
 
-    let mut v = to_serde_value(&game);
+    let log = std::mem::take(&mut game.log);
+    let mut v = undo_snapshot(&game);
 
     patch(&mut v, &p).map_err(|e| format!("Failed to apply patch: {e}"))?;
 
-    Ok(Game::from_data(
-        serde_json::from_value(v).map_err(|e| format!("Failed to deserialize game: {e}"))?,
-        game.cache,
-        game.context,
-    ))
+    let mut data: GameData =
+        serde_json::from_value(v).map_err(|e| format!("Failed to deserialize game: {e}"))?;
+    data.log = log;
+    Ok(Game::from_data(data, game.cache, game.context))
 }
 
-pub(crate) fn to_serde_value(game: &Game) -> Value {
-    let s = serde_json::to_string(&game.cloned_data()).expect("game should be serializable");
-    serde_json::from_str(&s).expect("game should be serializable")
+///
+/// Returns the part of the game that can be restored by an undo patch.
+///
+/// The log is left out, because it can be large in late game states and is kept as is on undo.
+pub(crate) fn undo_snapshot(game: &Game) -> Value {
+    serde_json::to_value(undo_data(game)).expect("game should be serializable")
 }
 
 pub fn redo(game: &mut Game, player_index: usize) -> Result<(), String> {
@@ -63,3 +67,38 @@ pub fn redo(game: &mut Game, player_index: usize) -> Result<(), String> {
     after_action(game, player_index);
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::undo_snapshot;
+    use crate::action::{Action, execute_action};
+    use crate::advance::{Advance, AdvanceAction};
+    use crate::game_setup::{GameSetupBuilder, setup_game};
+    use crate::playing_actions::PlayingAction;
+    use crate::resource_pile::ResourcePile;
+
+    #[test]
+    fn snapshot_does_not_grow_with_the_log() {
+        let mut game = setup_game(&GameSetupBuilder::new(2).skip_random_map().build());
+        let size = undo_snapshot(&game).to_string().len();
+
+        // late game states have a long log
+        let age = game.log[0].clone();
+        game.log.splice(0..0, std::iter::repeat_n(age, 1000));
+        let ages = game.log.len();
+        assert_eq!(undo_snapshot(&game).to_string().len(), size);
+        let full = serde_json::to_string(&game.cloned_data()).expect("game should serialize");
+        assert!(full.len() > 10 * size);
+
+        let player = game.active_player();
+        let advance = Action::Playing(PlayingAction::Advance(AdvanceAction::new(
+            Advance::Math,
+            ResourcePile::food(2),
+        )));
+        let game = execute_action(game, advance, player);
+        assert!(game.player(player).has_advance(Advance::Math));
+        let game = execute_action(game, Action::Undo, player);
+        assert!(!game.player(player).has_advance(Advance::Math));
+        assert_eq!(game.log.len(), ages);
+    }
+}
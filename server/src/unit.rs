@@ -14,6 +14,7 @@ use crate::content::persistent_events::{KilledUnits, PersistentEventType, UnitsR
 use crate::events::EventOrigin;
 use crate::explore::is_any_ship;
 use crate::game::GameState;
+use crate::localization::Message;
 use crate::log::{ActionLogBalance, ActionLogEntry, add_action_log_item};
 use crate::movement::{CurrentMove, MovementRestriction};
 use crate::player::{Player, remove_unit};
@@ -788,11 +789,11 @@ pub(crate) fn choose_carried_units_to_remove() -> Ability {
                     }
                 }
 
-                s.log(
+                s.log_message(
                     game,
-                    &format!(
-                        "Killed carried units: {}",
-                        units.into_iter().collect::<Units>().to_string(Some(game))
+                    Message::new("log.kill_carried_units", "Killed carried units: {units}").param(
+                        "units",
+                        units.into_iter().collect::<Units>().to_string(Some(game)),
                     ),
                 );
             }
@@ -1,5 +1,6 @@
 use std::collections::{BTreeMap, HashMap};
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::time::Duration;
+use web_time::{SystemTime, UNIX_EPOCH};
 
 use itertools::Itertools;
 use serde::{Serialize, Serializer};
@@ -214,7 +215,6 @@ impl<T> Shuffle<T> for Vec<T> {
 /// # Panics
 ///
 /// Panics if the probability distribution is empty or if all probabilities are zero.
-#[cfg(not(target_arch = "wasm32"))] // only for AI
 pub(crate) fn weighted_random_selection(probability_distribution: &[f64], rng: &mut Rng) -> usize {
     if probability_distribution.len() == 1 {
         return 0;
@@ -232,7 +232,11 @@ pub(crate) fn weighted_random_selection(probability_distribution: &[f64], rng: &
         }
         sum -= p;
     }
-    unreachable!();
+    // rounding errors can leave the probability of the last entry slightly below 1
+    probability_distribution
+        .iter()
+        .rposition(|p| *p > 0.0)
+        .expect("there is a positive probability")
 }
 
 pub(crate) fn sorted_map<S: Serializer, K: Serialize + Ord, V: Serialize>(
@@ -3,7 +3,8 @@ use crate::consts::{
     OBJECTIVE_VICTORY_POINTS,
 };
 use crate::events::EventOrigin;
-use crate::game::Game;
+use crate::game::{Game, GameState};
+use crate::localization::Message;
 use crate::player::Player;
 use crate::player_events::trigger_event_with_game_info;
 use crate::wonder::{wonders_built_points, wonders_owned_points};
@@ -39,6 +40,16 @@ impl SpecialVictoryPoints {
     }
 }
 
+#[allow(clippy::float_cmp)]
+pub(crate) fn gain_victory_points_message(points: f32) -> Message {
+    if points == 1.0 {
+        Message::new("log.gain_victory_point", "Gain 1 victory point")
+    } else {
+        Message::new("log.gain_victory_points", "Gain {points} victory points")
+            .param("points", points)
+    }
+}
+
 pub(crate) fn add_special_victory_points(
     player: &mut Player,
     points: f32,
@@ -132,12 +143,55 @@ pub fn victory_points_parts(player: &Player, game: &Game) -> [(&'static str, f32
     ]
 }
 
+///
+/// In team play, the score of a player includes the score of the teammate.
+#[must_use]
+pub fn team_victory_points_parts(player: &Player, game: &Game) -> [(&'static str, f32); 6] {
+    let mut parts = victory_points_parts(player, game);
+    if let Some(teammate) = game.teammate(player.index) {
+        for (part, (_, points)) in parts
+            .iter_mut()
+            .zip(victory_points_parts(game.player(teammate), game))
+        {
+            part.1 += points;
+        }
+    }
+    parts
+}
+
+#[must_use]
+pub fn team_victory_points(player: &Player, game: &Game) -> f32 {
+    team_victory_points_parts(player, game)
+        .iter()
+        .map(|(_, v)| v)
+        .sum()
+}
+
+#[must_use]
+pub(crate) fn victory_points_target_reached(game: &Game) -> bool {
+    game.options.victory_points_target.is_some_and(|target| {
+        game.human_player_ids()
+            .iter()
+            .any(|&p| team_victory_points(game.player(p), game) >= target as f32)
+    })
+}
+
+pub(crate) fn check_victory_points_target(game: &mut Game) {
+    // don't end the game in the middle of an event
+    if game.events.is_empty()
+        && !matches!(game.state, GameState::Finished)
+        && victory_points_target_reached(game)
+    {
+        game.end_game();
+    }
+}
+
 #[must_use]
 pub(crate) fn compare_score(player: &Player, other: &Player, game: &Game) -> Ordering {
     use std::cmp::Ordering::{Equal, Greater, Less};
 
-    let parts = victory_points_parts(player, game);
-    let other_parts = victory_points_parts(other, game);
+    let parts = team_victory_points_parts(player, game);
+    let other_parts = team_victory_points_parts(other, game);
     let sum = parts.iter().map(|(_, v)| v).sum::<f32>();
     let other_sum = other_parts.iter().map(|(_, v)| v).sum::<f32>();
 
@@ -8,7 +8,9 @@ use crate::consts::WONDER_VICTORY_POINTS;
 use crate::content::ability::Ability;
 use crate::content::effects::PermanentEffect;
 use crate::content::persistent_events::{PaymentRequest, PersistentEventType, PositionRequest};
+use crate::content_pack::custom_wonder_name;
 use crate::events::{EventOrigin, EventPlayer};
+use crate::localization::Message;
 use crate::log::{ActionLogBalance, add_start_turn_action_if_needed};
 use crate::payment::PaymentOptions;
 use crate::player::{CostTrigger, Player};
@@ -38,6 +40,12 @@ pub enum Wonder {
     GreatStatue,
     GreatWall,
     Hidden,
+
+    // reserved for content packs
+    Custom1,
+    Custom2,
+    Custom3,
+    Custom4,
 }
 
 impl Wonder {
@@ -55,6 +63,14 @@ impl Wonder {
     pub fn name(&self) -> String {
         self.to_string()
     }
+
+    #[must_use]
+    pub fn is_custom(&self) -> bool {
+        matches!(
+            self,
+            Wonder::Custom1 | Wonder::Custom2 | Wonder::Custom3 | Wonder::Custom4
+        )
+    }
 }
 
 impl Display for Wonder {
@@ -69,6 +85,12 @@ impl Display for Wonder {
             Wonder::GreatStatue => write!(f, "Great Statue"),
             Wonder::GreatWall => write!(f, "Great Wall"),
             Wonder::Hidden => write!(f, "Hidden"),
+            Wonder::Custom1 | Wonder::Custom2 | Wonder::Custom3 | Wonder::Custom4 => {
+                match custom_wonder_name(*self) {
+                    Some(name) => write!(f, "{name}"),
+                    None => write!(f, "{self:?}"),
+                }
+            }
         }
     }
 }
@@ -350,7 +372,7 @@ impl WonderCardInfo {
     }
 }
 
-pub(crate) struct WonderBuildInfo {
+pub struct WonderBuildInfo {
     pub wonder: Wonder,
     pub city_position: Position,
     pub player: usize,
@@ -375,11 +397,36 @@ pub(crate) fn can_construct_wonder(
     cost: CostInfo,
     trigger: CostTrigger,
 ) -> Result<CostInfo, String> {
-    can_construct_anything(city, player, true)?;
-
     if !player.wonder_cards.contains(&wonder) {
         return Err("Wonder card not owned".to_string());
     }
+
+    let cost = can_place_wonder(city, wonder, player, game, cost, trigger)?;
+
+    if !player.can_afford(&cost.cost) {
+        return Err("Not enough resources".to_string());
+    }
+
+    if game.actions_left == 0 && !cost.ignore_action_cost {
+        return Err("Not enough actions left".to_string());
+    }
+
+    Ok(cost)
+}
+
+///
+/// Checks if the wonder could be built in the city - without checking
+/// if the player owns the wonder card and can pay for it.
+pub(crate) fn can_place_wonder(
+    city: &City,
+    wonder: Wonder,
+    player: &Player,
+    game: &Game,
+    cost: CostInfo,
+    trigger: CostTrigger,
+) -> Result<CostInfo, String> {
+    can_construct_anything(city, player, true)?;
+
     let info = wonder.info(game);
 
     if city.mood_state != MoodState::Happy {
@@ -408,14 +455,6 @@ pub(crate) fn can_construct_wonder(
         }
     }
 
-    if !player.can_afford(&cost.cost) {
-        return Err("Not enough resources".to_string());
-    }
-
-    if game.actions_left == 0 && !cost.ignore_action_cost {
-        return Err("Not enough actions left".to_string());
-    }
-
     Ok(cost)
 }
 
@@ -619,14 +658,24 @@ fn remove_public_wonder(game: &mut Game) {
 
 pub(crate) fn draw_public_wonder(game: &mut Game, player: &EventPlayer) {
     if let Some(wonder) = draw_wonder_from_pile(game, player) {
-        player.log(
+        player.log_message(
             game,
-            &format!("{} is now available to be taken by anyone", wonder.name()),
+            Message::new(
+                "log.wonder.public",
+                "{wonder} is now available to be taken by anyone",
+            )
+            .param("wonder", wonder.name()),
         );
         game.permanent_effects
             .push(PermanentEffect::PublicWonderCard(wonder));
     } else {
-        player.log(game, "No wonders left to draw as public wonder card");
+        player.log_message(
+            game,
+            Message::new(
+                "log.wonder.no_wonders_left_to_draw_as",
+                "No wonders left to draw as public wonder card",
+            ),
+        );
     }
 }
 
@@ -650,7 +699,13 @@ pub(crate) fn use_draw_replacement_wonder() -> Ability {
             let player = p.get_mut(game);
             if player.event_info.remove(DRAW_REPLACEMENT_WONDER).is_some() {
                 add_start_turn_action_if_needed(game, game.active_player());
-                p.log(game, "Draw a replacement wonder card");
+                p.log_message(
+                    game,
+                    Message::new(
+                        "log.wonder.draw_a_replacement_wonder_card",
+                        "Draw a replacement wonder card",
+                    ),
+                );
                 draw_wonder_card(game, p);
             }
         },
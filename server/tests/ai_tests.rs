@@ -1,15 +1,14 @@
-#[cfg(not(target_arch = "wasm32"))]
 use crate::common::JsonTest;
-use server::collect::Collect;
+use server::collect::{Collect, PositionCollection};
 use server::happiness::IncreaseHappiness;
+use server::playing_actions::PlayingActionType;
+use server::position::Position;
 use server::recruit::Recruit;
 
 mod common;
 
-#[cfg(not(target_arch = "wasm32"))]
 const JSON: JsonTest = JsonTest::new("ai");
 
-#[cfg(not(target_arch = "wasm32"))]
 #[test]
 fn all_actions() {
     use server::action::{Action, ActionType};
@@ -80,3 +79,341 @@ fn all_actions() {
         ]
     )
 }
+
+#[test]
+fn ai_clone() {
+    use server::action::{Action, execute_action};
+    use server::game::GameContext;
+    use server::playing_actions::PlayingAction;
+
+    let game = JSON.load_game("start");
+    let clone = game.ai_clone();
+    assert_eq!(clone.context, GameContext::AI);
+    assert_eq!(clone.players.len(), game.players.len());
+    assert_eq!(
+        clone.player(1).resources,
+        game.player(1).resources,
+        "players should be copied"
+    );
+
+    let clone = execute_action(clone, Action::Playing(PlayingAction::EndTurn), 1);
+    assert_eq!(clone.current_player_index, 0);
+    assert_eq!(game.current_player_index, 1, "original should be unchanged");
+}
+
+#[test]
+fn evaluation() {
+    use server::ai_evaluation::{
+        STANDARD_WEIGHTS, action_type_prior, evaluate, evaluation_terms, leading_player,
+        relative_evaluation,
+    };
+    use server::game::Game;
+    use server::playing_actions::PlayingActionType;
+    use server::resource_pile::ResourcePile;
+    use server::wonder::Wonder;
+
+    let mut game = JSON.load_game("start");
+    let before = evaluate(&game, 1, &STANDARD_WEIGHTS);
+    assert!(before > 0.0);
+
+    game.player_mut(1).resources += ResourcePile::gold(5);
+    assert!(evaluate(&game, 1, &STANDARD_WEIGHTS) > before);
+    assert!(relative_evaluation(&game, 1, &STANDARD_WEIGHTS) > 0.0);
+    assert_eq!(leading_player(&game, &STANDARD_WEIGHTS), 1);
+
+    assert_eq!(
+        action_type_prior(&game, 1, &PlayingActionType::EndTurn, &STANDARD_WEIGHTS),
+        1.0
+    );
+    assert!(action_type_prior(&game, 1, &PlayingActionType::Advance, &STANDARD_WEIGHTS) > 1.0);
+
+    let term = |game: &Game, name: &str| {
+        evaluation_terms(game, 1, &STANDARD_WEIGHTS)
+            .into_iter()
+            .find(|(n, _)| *n == name)
+            .expect("term should exist")
+            .1
+    };
+    assert!(term(&game, "income") > 0.0);
+    assert_eq!(term(&game, "wonder progress"), 0.0);
+    game.player_mut(1).wonder_cards.push(Wonder::Pyramids);
+    game.player_mut(1).resources += ResourcePile::new(5, 5, 5, 5, 5, 5, 5);
+    assert!(term(&game, "wonder progress") > 0.0);
+}
+
+#[test]
+fn profiles() {
+    use server::advance::Advance;
+    use server::ai_evaluation::STANDARD_WEIGHTS;
+    use server::ai_profile::AiProfile;
+
+    for profile in AiProfile::all() {
+        assert_eq!(AiProfile::from_code(profile.code()), Some(profile));
+    }
+    assert_eq!(AiProfile::from_code("unknown"), None);
+    assert_eq!(AiProfile::Balanced.weights(), STANDARD_WEIGHTS);
+    assert!(AiProfile::Militarist.weights().army_unit > STANDARD_WEIGHTS.army_unit);
+
+    let game = JSON.load_game("start");
+    let militarist = AiProfile::Militarist;
+    assert!(
+        militarist.advance_prior(&game, Advance::Tactics)
+            > militarist.advance_prior(&game, Advance::Farming)
+    );
+    assert_eq!(
+        AiProfile::Balanced.advance_prior(&game, Advance::Tactics),
+        AiProfile::Balanced.advance_prior(&game, Advance::Farming)
+    );
+}
+
+#[test]
+fn decision_trace() {
+    use server::ai::AI;
+    use server::ai_profile::AiProfile;
+    use std::time::Duration;
+
+    let game = JSON.load_game("start");
+    let mut ai = AI::new(
+        1.,
+        Duration::from_millis(100),
+        false,
+        &game,
+        1,
+        AiProfile::Militarist,
+    );
+    let (action, trace) = ai.next_action_with_trace(&game);
+    assert_eq!(trace.chosen, action);
+    assert_eq!(trace.player_index, 1);
+    assert_eq!(trace.profile, AiProfile::Militarist);
+    assert!(trace.candidates.iter().any(|c| c.action == action));
+    assert!(trace.candidates.iter().all(|c| c.score_variance >= 0.0));
+}
+
+#[test]
+fn opening_book_shortcut() {
+    use server::action::Action;
+    use server::ai::AI;
+    use server::ai_opening_book::{OpeningBook, RoundStatistics};
+    use server::ai_profile::AiProfile;
+    use server::playing_actions::PlayingAction;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    let game = JSON.load_game("start");
+    let new_ai = || {
+        AI::new(
+            1.,
+            Duration::from_millis(100),
+            false,
+            &game,
+            1,
+            AiProfile::default(),
+        )
+    };
+    let (_, trace) = new_ai().next_action_with_trace(&game);
+    assert!(!trace.opening_book);
+    let advance = trace
+        .candidates
+        .iter()
+        .find_map(|c| match &c.action {
+            Action::Playing(PlayingAction::Advance(a)) => Some(a.advance),
+            _ => None,
+        })
+        .expect("advance candidate");
+
+    let mut ai = new_ai();
+    ai.set_opening_book(Arc::new(OpeningBook {
+        games: 10,
+        rounds: vec![RoundStatistics {
+            age: game.age,
+            round: game.round,
+            actions: 10,
+            action_types: vec![(PlayingActionType::Advance, 10)],
+            advances: vec![(advance, 10)],
+            city_positions: vec![],
+            collects: vec![],
+        }],
+    }));
+    let (action, trace) = ai.next_action_with_trace(&game);
+    assert!(trace.opening_book);
+    assert_eq!(trace.chosen, action);
+    assert!(matches!(action, Action::Playing(PlayingAction::Advance(a)) if a.advance == advance));
+    assert!(trace.candidates.iter().all(|c| c.rollouts == 0));
+
+    // the statistics are relative to the home block of the player - here at D7 without rotation
+    let collect = trace
+        .candidates
+        .iter()
+        .find_map(|c| match &c.action {
+            Action::Playing(PlayingAction::Collect(c)) => Some(c.clone()),
+            _ => None,
+        })
+        .expect("collect candidate");
+    let home = Position::from_offset("D7");
+    let relative = |p: Position| Position::new(p.q - home.q, p.r - home.r);
+    let mut collections = collect
+        .collections
+        .iter()
+        .map(|c| PositionCollection {
+            position: relative(c.position),
+            ..c.clone()
+        })
+        .collect::<Vec<_>>();
+    collections.sort_by_key(|c| (c.position.q, c.position.r));
+    let relative_collect = Collect::new(
+        relative(collect.city_position),
+        collections,
+        collect.action_type.clone(),
+    );
+
+    let mut ai = new_ai();
+    ai.set_opening_book(Arc::new(OpeningBook {
+        games: 10,
+        rounds: vec![RoundStatistics {
+            age: game.age,
+            round: game.round,
+            actions: 10,
+            action_types: vec![(PlayingActionType::Collect, 10)],
+            advances: vec![],
+            city_positions: vec![],
+            collects: vec![(relative_collect, 10)],
+        }],
+    }));
+    let (action, trace) = ai.next_action_with_trace(&game);
+    assert!(trace.opening_book);
+    assert_eq!(action, Action::Playing(PlayingAction::Collect(collect)));
+
+    // the book doesn't apply to custom maps
+    let mut custom_map = game.clone();
+    custom_map.map.tiles.remove(&Position::from_offset("A3"));
+    let (_, trace) = ai.next_action_with_trace(&custom_map);
+    assert!(!trace.opening_book);
+}
+
+#[test]
+fn suggest_actions() {
+    use server::ai_suggestion::suggest_actions;
+    use std::time::Duration;
+
+    let game = JSON.load_game("start");
+    let suggestions = suggest_actions(&game, 1, Duration::from_millis(100), 3);
+    assert_eq!(suggestions.len(), 3);
+    assert!(suggestions.iter().all(|s| !s.reason.is_empty()));
+    assert!(
+        suggestions
+            .iter()
+            .all(|s| s.log.action == s.action && s.log.player == 1)
+    );
+    assert_ne!(
+        suggestions[0].action, suggestions[1].action,
+        "suggestions should be different"
+    );
+}
+
+#[test]
+fn ai_move() {
+    use server::game_api;
+    use std::time::Duration;
+
+    let game = JSON.load_game("start");
+    let action = game_api::ai_move(&game, 1, Duration::from_millis(100));
+    let game = game_api::execute(game, action, 1);
+    assert!(!game_api::ended(&game));
+}
+
+#[test]
+fn opening_book() {
+    use server::action::Action;
+    use server::ai_opening_book::OpeningBook;
+    use server::game_api;
+    use server::game_setup::{GameSetupBuilder, setup_game};
+    use server::replay::{ReplayGameData, replay};
+    use std::time::Duration;
+
+    let mut game = setup_game(&GameSetupBuilder::new(2).seed("42".to_string()).build());
+    let mut first_action = None;
+    for _ in 0..6 {
+        let player = game.active_player();
+        let action = game_api::ai_move(&game, player, Duration::from_millis(10));
+        if first_action.is_none()
+            && let Action::Playing(a) = &action
+        {
+            first_action = Some(a.playing_action_type(game.player(player)));
+        }
+        game = game_api::execute(game, action, player);
+    }
+
+    let replayed = replay(ReplayGameData::from_game(&game), None);
+    assert_eq!(replayed.current_player_index, game.current_player_index);
+    assert_eq!(replayed.actions_left, game.actions_left);
+    for p in 0..2 {
+        assert_eq!(replayed.player(p).advances, game.player(p).advances);
+        assert_eq!(replayed.player(p).resources, game.player(p).resources);
+    }
+
+    let mut book = OpeningBook::default();
+    for _ in 0..10 {
+        book.add_game(ReplayGameData::from_game(&game));
+    }
+    assert_eq!(book.games, 10);
+    let start = setup_game(&GameSetupBuilder::new(2).seed("42".to_string()).build());
+    let first_action = first_action.expect("no playing action");
+    assert!(book.action_type_prior(&start, &first_action) > 1.0);
+    assert_eq!(
+        book.action_type_prior(&start, &PlayingActionType::InfluenceCultureAttempt),
+        1.0
+    );
+}
+
+#[test]
+fn cancel_thinking() {
+    use server::ai::AI;
+    use server::ai_profile::AiProfile;
+    use std::time::{Duration, Instant};
+
+    let game = JSON.load_game("start");
+    let mut ai = AI::new(
+        1.,
+        Duration::from_secs(30),
+        false,
+        &game,
+        1,
+        AiProfile::default(),
+    );
+    let start = Instant::now();
+    ai.cancel_handle().cancel();
+    let action = ai.next_action(&game);
+    assert!(
+        start.elapsed() < Duration::from_secs(10),
+        "cancelled AI should not use the whole thinking time"
+    );
+    let _ = server::action::execute_action(game, action, 1);
+    assert!(
+        !ai.cancel_handle().is_cancelled(),
+        "should only cancel once"
+    );
+}
+
+#[test]
+fn cancel_thinking_with_weighted_selection() {
+    use server::ai::AI;
+    use server::ai_profile::AiProfile;
+    use std::time::Duration;
+
+    // a difficulty below 1 selects the actions and missions randomly weighted by their score
+    let mut game = JSON.load_game("start");
+    let mut ai = AI::new(
+        0.5,
+        Duration::from_millis(200),
+        false,
+        &game,
+        1,
+        AiProfile::default(),
+    );
+    let (_, trace) = ai.next_action_with_trace(&game);
+    // the settler reached the target of its mission - so it needs a new mission
+    game.players[1].units[0].position = trace.missions[0].target;
+    ai.cancel_handle().cancel();
+    let action = ai.next_action(&game);
+    let _ = server::action::execute_action(game, action, 1);
+}
@@ -0,0 +1,139 @@
+use crate::common::{JsonTest, TestAction, custom_action, move_action, payment_response};
+use server::action::Action;
+use server::city_pieces::Building;
+use server::collect::{Collect, PositionCollection};
+use server::construct::Construct;
+use server::consts::BUILDING_COST;
+use server::content::custom_actions::CustomActionType;
+use server::playing_actions::PlayingAction::WonderCard;
+use server::playing_actions::{PlayingAction, PlayingActionType};
+use server::position::Position;
+use server::recruit::Recruit;
+use server::resource_pile::ResourcePile;
+use server::unit::Units;
+use server::wonder::Wonder;
+
+mod common;
+
+const JSON: JsonTest = JsonTest::child("civilizations", "egypt");
+
+#[test]
+fn flood() {
+    JSON.test(
+        "flood",
+        vec![TestAction::undoable(
+            0,
+            Action::Playing(PlayingAction::Collect(Collect::new(
+                Position::from_offset("B3"),
+                vec![PositionCollection::new(
+                    Position::from_offset("B4"),
+                    ResourcePile::food(1),
+                )],
+                PlayingActionType::Collect,
+            ))),
+        )],
+    );
+}
+
+#[test]
+fn master_builders() {
+    JSON.test(
+        "master_builders",
+        vec![
+            TestAction::undoable(0, Action::Playing(WonderCard(Wonder::Colosseum))).skip_json(),
+            TestAction::undoable(0, payment_response(ResourcePile::new(3, 4, 5, 0, 0, 0, 4))),
+        ],
+    );
+}
+
+#[test]
+fn hieroglyphs() {
+    JSON.test(
+        "hieroglyphs",
+        vec![TestAction::undoable(
+            0,
+            Action::Playing(PlayingAction::Construct(Construct::new(
+                Position::from_offset("A1"),
+                Building::Academy,
+                BUILDING_COST,
+            ))),
+        )],
+    );
+}
+
+#[test]
+fn pharaoh() {
+    JSON.test(
+        "pharaoh",
+        vec![TestAction::undoable(
+            0,
+            Action::Playing(PlayingAction::FoundCity { settler: 4 }),
+        )],
+    );
+}
+
+#[test]
+fn queen_of_the_nile() {
+    JSON.test(
+        "queen_of_the_nile",
+        vec![TestAction::undoable(
+            0,
+            Action::Playing(PlayingAction::Recruit(Recruit::new(
+                &Units::new(0, 1, 0, 0, 0, None),
+                Position::from_offset("A1"),
+                ResourcePile::food(1) + ResourcePile::ore(1),
+            ))),
+        )],
+    );
+}
+
+#[test]
+fn battle_of_kadesh() {
+    JSON.test(
+        "battle_of_kadesh",
+        vec![TestAction::not_undoable(
+            1,
+            move_action(vec![0, 1], Position::from_offset("D8")),
+        )],
+    );
+}
+
+#[test]
+fn charisma() {
+    JSON.test(
+        "charisma",
+        vec![TestAction::not_undoable(
+            0,
+            move_action(vec![0], Position::from_offset("D8")),
+        )],
+    );
+}
+
+#[test]
+fn prosperity() {
+    JSON.test(
+        "prosperity",
+        vec![TestAction::undoable(
+            0,
+            Action::Playing(PlayingAction::Collect(Collect::new(
+                Position::from_offset("A1"),
+                vec![PositionCollection::new(
+                    Position::from_offset("A1"),
+                    ResourcePile::food(1),
+                )],
+                PlayingActionType::Collect,
+            ))),
+        )],
+    );
+}
+
+#[test]
+fn expedition_to_punt() {
+    JSON.test(
+        "expedition_to_punt",
+        vec![
+            TestAction::undoable(0, custom_action(CustomActionType::ExpeditionToPunt)).skip_json(),
+            TestAction::undoable(0, payment_response(ResourcePile::mood_tokens(1))),
+        ],
+    );
+}
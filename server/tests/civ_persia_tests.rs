@@ -0,0 +1,145 @@
+use crate::common::{JsonTest, TestAction, custom_action, move_action, payment_response};
+use server::action::Action;
+use server::advance::Advance;
+use server::collect::{Collect, PositionCollection};
+use server::content::custom_actions::CustomActionType;
+use server::content::persistent_events::EventResponse;
+use server::playing_actions::{PlayingAction, PlayingActionType};
+use server::position::Position;
+use server::recruit::Recruit;
+use server::resource_pile::ResourcePile;
+use server::unit::Units;
+
+mod common;
+
+const JSON: JsonTest = JsonTest::child("civilizations", "persia");
+
+#[test]
+fn royal_road() {
+    JSON.test(
+        "royal_road",
+        vec![TestAction::undoable(
+            0,
+            move_action(vec![0], Position::from_offset("B1")),
+        )],
+    );
+}
+
+#[test]
+fn satrapies() {
+    JSON.test(
+        "satrapies",
+        vec![TestAction::undoable(
+            0,
+            Action::Playing(PlayingAction::Collect(Collect::new(
+                Position::from_offset("A1"),
+                vec![PositionCollection::new(
+                    Position::from_offset("A1"),
+                    ResourcePile::gold(1),
+                )],
+                PlayingActionType::Collect,
+            ))),
+        )],
+    );
+}
+
+#[test]
+fn horse_breeding() {
+    JSON.test(
+        "horse_breeding",
+        vec![TestAction::undoable(
+            0,
+            Action::Playing(PlayingAction::Recruit(Recruit::new(
+                &Units::new(0, 0, 0, 1, 0, None),
+                Position::from_offset("A1"),
+                ResourcePile::wood(1),
+            ))),
+        )],
+    );
+}
+
+#[test]
+fn immortals() {
+    JSON.test(
+        "immortals",
+        vec![TestAction::not_undoable(
+            0,
+            move_action(vec![0, 1, 2], Position::from_offset("D8")),
+        )],
+    );
+}
+
+#[test]
+fn liberator() {
+    JSON.test(
+        "liberator",
+        vec![TestAction::not_undoable(
+            0,
+            move_action(vec![0], Position::from_offset("D8")),
+        )],
+    );
+}
+
+#[test]
+fn king_of_kings() {
+    JSON.test(
+        "king_of_kings",
+        vec![TestAction::not_undoable(
+            0,
+            move_action(vec![0, 1], Position::from_offset("D8")),
+        )],
+    );
+}
+
+#[test]
+fn administrator() {
+    JSON.test(
+        "administrator",
+        vec![
+            TestAction::undoable(0, custom_action(CustomActionType::Administrator)).skip_json(),
+            TestAction::undoable(0, payment_response(ResourcePile::mood_tokens(1))).skip_json(),
+            TestAction::not_undoable(
+                0,
+                Action::Response(EventResponse::SelectAdvance(Advance::Engineering)),
+            ),
+        ],
+    );
+}
+
+#[test]
+fn cavalry_charge() {
+    JSON.test(
+        "cavalry_charge",
+        vec![TestAction::not_undoable(
+            0,
+            move_action(vec![0, 1, 2], Position::from_offset("D8")),
+        )],
+    );
+}
+
+#[test]
+fn immortal_guard() {
+    JSON.test(
+        "immortal_guard",
+        vec![
+            TestAction::not_undoable(0, move_action(vec![0, 10], Position::from_offset("C1")))
+                .skip_json(),
+            TestAction::undoable(0, payment_response(ResourcePile::gold(2))),
+        ],
+    );
+}
+
+#[test]
+fn great_army() {
+    JSON.test(
+        "great_army",
+        vec![TestAction::undoable(
+            0,
+            Action::Playing(PlayingAction::Recruit(Recruit::new(
+                &Units::new(0, 2, 0, 0, 0, None),
+                Position::from_offset("A1"),
+                ResourcePile::food(2) + ResourcePile::ore(2),
+            ))),
+        )],
+    );
+}
@@ -4,10 +4,14 @@ use crate::common::{TestAction, move_action, payment_response};
 use common::JsonTest;
 use server::action::Action;
 use server::card::HandCard;
+use server::combat_report::CombatReportSide;
 use server::content::persistent_events::EventResponse;
+use server::game_api::combat_report;
+use server::log::ActionLogEntry;
 use server::playing_actions::PlayingAction::Recruit;
 use server::position::Position;
 use server::resource_pile::ResourcePile;
+use server::tactics_card::CombatRole;
 use server::unit::Units;
 
 mod common;
@@ -224,3 +228,82 @@ fn test_recruit_combat() {
         ],
     );
 }
+
+#[test]
+fn test_combat_report_retreat() {
+    let game = JSON.load_game("retreat_no.outcome1");
+    let reports = combat_report(&game);
+    assert_eq!(reports.len(), 1);
+    let report = &reports[0];
+    assert_eq!(report.rounds.len(), 2);
+    assert_eq!(report.rounds[0].retreat, Some(false));
+    assert_eq!(report.rounds[1].retreat, None);
+
+    let attacker = report.side(1, CombatRole::Attacker);
+    assert_eq!(attacker.combat_value, 7);
+    assert_eq!(attacker.hits, 1);
+    assert_eq!(attacker.casualties, Units::new(0, 1, 0, 0, 0, None));
+    let defender = report.side(1, CombatRole::Defender);
+    assert_eq!(defender.rolls.len(), 2);
+    assert_eq!(defender.casualties, Units::new(0, 1, 0, 0, 0, None));
+    assert!(report.stats.as_ref().is_some_and(|s| s.is_winner(1)));
+
+    let game = JSON.load_game("retreat.outcome1");
+    let reports = combat_report(&game);
+    assert_eq!(reports[0].rounds[0].retreat, Some(true));
+    assert!(reports[0].stats.is_none());
+
+    // archived games logged the decision as text
+    let mut game = JSON.load_game("retreat_no.outcome1");
+    for item in game
+        .log
+        .iter_mut()
+        .flat_map(|age| age.rounds.iter_mut())
+        .flat_map(|round| round.turns.iter_mut())
+        .flat_map(|turn| turn.actions.iter_mut())
+        .flat_map(|action| action.items.iter_mut())
+    {
+        if matches!(item.entry, ActionLogEntry::CombatRetreat { .. }) {
+            item.entry = serde_json::from_str(r#"{"Text": "Do not retreat"}"#).unwrap();
+        }
+    }
+    let reports = combat_report(&game);
+    assert_eq!(reports[0].rounds[0].retreat, Some(false));
+    let round = &reports[0].rounds[0];
+    assert!(
+        round
+            .attacker
+            .notes
+            .iter()
+            .chain(&round.defender.notes)
+            .all(|n| n.message.key != "Do not retreat")
+    );
+}
+
+#[test]
+fn test_combat_report_modifiers() {
+    let game = JSON.load_game("combat_all_modifiers.outcome5");
+    let report = &combat_report(&game)[0];
+    let attacker = report.side(0, CombatRole::Attacker);
+    assert_eq!(attacker.tactics_card, Some(1));
+    let modifiers = |side: &CombatReportSide| {
+        side.modifiers
+            .iter()
+            .map(|m| m.key.clone())
+            .collect::<Vec<_>>()
+    };
+    assert!(modifiers(attacker).contains(&"combat_modifier.steel_weapons".to_string()));
+    assert!(
+        modifiers(attacker).contains(&"combat_modifier.cancel_fortress_ignore_hit".to_string())
+    );
+    let defender = report.side(0, CombatRole::Defender);
+    assert_eq!(defender.tactics_card, Some(2));
+    assert!(modifiers(defender).contains(&"combat_modifier.steel_weapons".to_string()));
+    assert!(
+        defender
+            .notes
+            .iter()
+            .any(|n| format!("{}: {}", n.origin.name(&game), n.message)
+                == "Tactics: Reveal Tactics Card Encircled")
+    );
+}
@@ -0,0 +1,191 @@
+use server::ability_initializer::AbilityInitializerSetup;
+use server::action_card::ActionCard;
+use server::advance::Advance;
+use server::cache::Cache;
+use server::civilization::Civilization;
+use server::content_pack::{ContentPack, register_content_pack};
+use server::game::{Game, GameContext, GameOptions};
+use server::game_data::GameData;
+use server::game_setup::{GameSetupBuilder, setup_game};
+use server::leader::{Leader, LeaderInfo};
+use server::leader_ability::LeaderAbility;
+use server::resource_pile::ResourcePile;
+use server::special_advance::{SpecialAdvance, SpecialAdvanceInfo, SpecialAdvanceRequirement};
+use server::wonder::{Wonder, WonderInfo};
+
+fn windfall(id: u8) -> ActionCard {
+    ActionCard::builder(
+        id,
+        "Windfall",
+        "Gain 2 gold.",
+        |c| c.action().no_resources(),
+        |_, _, _| true,
+    )
+    .add_simple_persistent_event_listener(
+        |e| &mut e.play_action_card,
+        0,
+        |game, p, _| p.gain_resources(game, ResourcePile::gold(2)),
+    )
+    .build()
+}
+
+fn options(packs: &[&str]) -> GameOptions {
+    GameOptions {
+        content_packs: packs.iter().map(ToString::to_string).collect(),
+        ..GameOptions::default()
+    }
+}
+
+#[test]
+fn register_action_card() {
+    register_content_pack(ContentPack::new("Windfall").add_action_card(windfall(200)));
+    let options = options(&["Windfall"]);
+    let cache = Cache::try_new(&options).expect("pack should be valid");
+    assert_eq!(cache.get_action_card(200).civil_card.name, "Windfall");
+
+    let game = setup_game(
+        &GameSetupBuilder::new(2)
+            .skip_random_map()
+            .options(options)
+            .build(),
+    );
+    assert!(game.action_cards_left.contains(&200));
+}
+
+#[test]
+fn reload_game_with_pack_card() {
+    register_content_pack(ContentPack::new("Reload").add_action_card(windfall(201)));
+    let mut game = setup_game(
+        &GameSetupBuilder::new(2)
+            .skip_random_map()
+            .options(options(&["Reload"]))
+            .build(),
+    );
+    game.players[0].action_cards.push(201);
+
+    let json = serde_json::to_string(&game.data()).expect("game should be serializable");
+    let data: GameData = serde_json::from_str(&json).expect("game should be deserializable");
+    let cache = Cache::new(&data.options);
+    let game = Game::from_data(data, cache, GameContext::Play);
+    assert!(game.players[0].action_cards.contains(&201));
+    assert_eq!(game.cache.get_action_card(201).civil_card.name, "Windfall");
+}
+
+#[test]
+fn register_new_wonder_and_civilization() {
+    register_content_pack(
+        ContentPack::new("Carthage")
+            .add_new_wonder(
+                "Cothon",
+                WonderInfo::builder(
+                    Wonder::Custom1,
+                    "A harbor for the fleet.",
+                    ResourcePile::new(3, 3, 3, 0, 0, 0, 4),
+                    Advance::Fishing,
+                )
+                .build(),
+            )
+            .add_civilization(Civilization::new(
+                "Carthage",
+                vec![
+                    SpecialAdvanceInfo::builder(
+                        SpecialAdvance::Custom1,
+                        SpecialAdvanceRequirement::Advance(Advance::Fishing),
+                        "Punic Fleet",
+                        "-",
+                    )
+                    .build(),
+                ],
+                vec![LeaderInfo::new(
+                    Leader::Custom1,
+                    "Hannibal",
+                    LeaderAbility::builder("Elephants", "-").build(),
+                    LeaderAbility::builder("Alps", "-").build(),
+                )],
+                None,
+            )),
+    );
+    let game = setup_game(
+        &GameSetupBuilder::new(2)
+            .skip_random_map()
+            .options(options(&["Carthage"]))
+            .build(),
+    );
+    assert_eq!(Wonder::Custom1.name(), "Cothon");
+    assert!(game.wonders_left.contains(&Wonder::Custom1));
+    let carthage = game.cache.get_civilization("Carthage");
+    assert_eq!(
+        carthage.special_advances[0].advance,
+        SpecialAdvance::Custom1
+    );
+    assert_eq!(Leader::Custom1.name(&game), "Hannibal");
+}
+
+#[test]
+fn reject_collisions() {
+    let err = |packs: &[&str]| {
+        Cache::try_new(&options(packs))
+            .err()
+            .expect("pack should be rejected")
+    };
+
+    assert_eq!(err(&["Unknown"]), "content pack Unknown is not registered");
+
+    register_content_pack(ContentPack::new("Action Card Collision").add_action_card(windfall(1)));
+    assert_eq!(
+        err(&["Action Card Collision"]),
+        "content pack Action Card Collision: action card id 1 is already used"
+    );
+
+    register_content_pack(
+        ContentPack::new("Civilization Collision").add_civilization(Civilization::new(
+            "Rome",
+            vec![],
+            vec![],
+            None,
+        )),
+    );
+    assert_eq!(
+        err(&["Civilization Collision"]),
+        "content pack Civilization Collision: civilization Rome already exists"
+    );
+
+    register_content_pack(
+        ContentPack::new("Leader Collision").add_civilization(Civilization::new(
+            "Carthage",
+            vec![],
+            Cache::new(&GameOptions::default())
+                .get_civilization("Rome")
+                .leaders,
+            None,
+        )),
+    );
+    assert_eq!(
+        err(&["Leader Collision"]),
+        "content pack Leader Collision: leader Augustus is already used"
+    );
+
+    let colosseum =
+        |w| WonderInfo::builder(w, "-", ResourcePile::empty(), Advance::Engineering).build();
+    register_content_pack(
+        ContentPack::new("Wonder Without Reserved Id")
+            .add_new_wonder("Arena", colosseum(Wonder::Colosseum)),
+    );
+    assert_eq!(
+        err(&["Wonder Without Reserved Id"]),
+        "content pack Wonder Without Reserved Id: new wonder Colosseum must use a reserved id"
+    );
+    register_content_pack(
+        ContentPack::new("Unnamed Wonder").add_wonder(colosseum(Wonder::Custom4)),
+    );
+    assert_eq!(
+        err(&["Unnamed Wonder"]),
+        "content pack Unnamed Wonder: wonder Custom4 must be added as a new wonder"
+    );
+
+    register_content_pack(ContentPack::new("Twice").add_action_card(windfall(202)));
+    assert_eq!(
+        err(&["Twice", "Twice"]),
+        "content pack Twice: action card id 202 is already used"
+    );
+}
@@ -3,12 +3,24 @@ use itertools::Itertools;
 use playing_actions::PlayingActionType;
 use server::card::HandCard;
 use server::collect::PositionCollection;
-use server::content::persistent_events::{EventResponse, SelectedStructure};
-use server::game::{CivSetupOption, GameOptions, PatchOption, UndoOption};
+use server::content::catalog::{CatalogChangeType, ContentCategory, diff_catalogs};
+use server::content::custom_actions::{CustomAction, CustomActionType, SpecialAction};
+use server::content::persistent_events::{
+    EventResponse, PersistentEventRequest, PersistentEventType, SelectedStructure,
+};
+use server::content::scenarios::FALL_OF_ROME;
+use server::game::{
+    CardSetupOption, CivSetupOption, Game, GameOptions, GameState, PatchOption, TeamOption,
+    UndoOption,
+};
 use server::game_setup::{GameSetupBuilder, setup_game};
 use server::leader::Leader;
+use server::movement::{MoveDestination, possible_move_destinations};
+use server::player::CostTrigger;
+use server::recruit::recruit_cost_without_replaced;
+use server::special_advance::SpecialAdvance;
 use server::structure::Structure;
-use server::unit::Units;
+use server::unit::{Unit, UnitType, Units};
 use server::wonder::Wonder;
 use server::{
     action::Action,
@@ -37,6 +49,7 @@ fn new_game() {
                 civilization: CivSetupOption::ChooseCivilization,
                 undo: UndoOption::SamePlayer,
                 patch: PatchOption::Standard,
+                ..GameOptions::default()
             })
             .build(),
     );
@@ -50,6 +63,337 @@ fn new_game() {
     JSON.compare_game("new_game", &game);
 }
 
+#[test]
+fn test_card_draft() {
+    let mut game = setup_game(
+        &GameSetupBuilder::new(2)
+            .skip_random_map()
+            .options(GameOptions {
+                cards: CardSetupOption::Draft,
+                ..GameOptions::default()
+            })
+            .build(),
+    );
+    assert_eq!(game.state, GameState::Draft);
+    assert!(game.players.iter().all(|p| p.action_cards.is_empty()));
+    let objective_cards_left = game.objective_cards_left.len();
+
+    for i in 0..4 {
+        let player_index = game.active_player();
+        let PersistentEventRequest::SelectHandCards(r) =
+            &game.current_event_handler().expect("draft request").request
+        else {
+            panic!("hand card request expected");
+        };
+        // first an action card is kept, then an objective card from the passed packet
+        let objective_round = i >= 2;
+        assert_eq!(r.choices.len(), 3);
+        assert!(
+            r.choices
+                .iter()
+                .all(|c| matches!(c, HandCard::ObjectiveCard(_)) == objective_round)
+        );
+        let picks = vec![r.choices[0].clone()];
+
+        let other = game_api::strip_secret(game.clone(), Some(1 - player_index));
+        let PersistentEventType::Draft(d) = &other.current_event().event_type else {
+            panic!("draft event expected");
+        };
+        assert!(
+            d.packets[player_index]
+                .iter()
+                .all(|c| matches!(c, HandCard::ActionCard(0) | HandCard::ObjectiveCard(0)))
+        );
+
+        game = game_api::execute(
+            game,
+            Action::Response(EventResponse::SelectHandCards(picks)),
+            player_index,
+        );
+    }
+
+    assert_eq!(game.state, GameState::Playing);
+    assert_eq!(game.age, 1);
+    for p in game.players.iter().filter(|p| p.is_human()) {
+        assert_eq!(p.action_cards.len(), 1);
+        assert_eq!(p.objective_cards.len(), 1);
+    }
+    assert_eq!(game.action_cards_discarded.len(), 4);
+    assert_eq!(game.objective_cards_left.len(), objective_cards_left + 4);
+}
+
+#[test]
+fn test_team_play() {
+    let mut game = setup_game(
+        &GameSetupBuilder::new(4)
+            .skip_random_map()
+            .options(GameOptions {
+                teams: TeamOption::TwoVersusTwo,
+                ..GameOptions::default()
+            })
+            .build(),
+    );
+    assert_eq!(game.teammate(0), Some(2));
+    assert_eq!(game.teammate(3), Some(1));
+
+    // teammates share hexes without combat
+    let position = Position::from_offset("A1");
+    game.players[2]
+        .units
+        .push(Unit::new(2, position, UnitType::Infantry, 100));
+    assert_eq!(game.enemy_player(0, position), None);
+    assert_eq!(game.enemy_player(1, position), Some(2));
+    // the teammate with military units defends a shared tile
+    game.players[0]
+        .units
+        .push(Unit::new(0, position, UnitType::Settler, 100));
+    assert_eq!(game.enemy_player(1, position), Some(2));
+    game.players[0].units.clear();
+    game.players[2].units.clear();
+
+    // scores are aggregated per team
+    game.players[2].advances.insert(advance::Advance::Writing);
+    assert_eq!(game_api::rankings(&game), vec![1, 2, 1, 2]);
+
+    // hand cards are revealed to the teammate
+    let view = game_api::strip_secret(game.clone(), Some(0));
+    assert_eq!(view.players[2].action_cards, game.players[2].action_cards);
+    assert_eq!(view.players[1].action_cards, vec![0]);
+
+    // teammates can give resources as a free action
+    let player_index = game.current_player_index;
+    let teammate = game.teammate(player_index).expect("teammate");
+    // the food limit is 2
+    game.players[teammate].resources.food = 0;
+    game = game_api::execute(
+        game,
+        Action::Playing(Custom(CustomAction::new(CustomActionType::TeamTrade, None))),
+        player_index,
+    );
+    game = game_api::execute(
+        game,
+        Action::Response(EventResponse::Payment(vec![ResourcePile::food(1)])),
+        player_index,
+    );
+    assert_eq!(game.players[player_index].resources.food, 1);
+    assert_eq!(game.players[teammate].resources.food, 1);
+    assert_eq!(game.actions_left, 3);
+
+    let start = Position::from_offset("B2");
+    let shared = Position::from_offset("B3");
+    let enemy_tile = Position::from_offset("C2");
+    for p in [start, shared, enemy_tile] {
+        game.map.tiles.insert(p, Fertile);
+    }
+    game.players[player_index]
+        .advances
+        .insert(advance::Advance::Tactics);
+    for id in 100..103 {
+        game.players[player_index].units.push(Unit::new(
+            player_index,
+            start,
+            UnitType::Infantry,
+            id,
+        ));
+        game.players[teammate]
+            .units
+            .push(Unit::new(teammate, shared, UnitType::Infantry, id));
+    }
+
+    // both enemy teammates lose their settlers on a captured tile
+    for p in &mut game.players {
+        p.objective_cards.clear();
+    }
+    let enemy = (player_index + 1) % 4;
+    let enemy_teammate = game.teammate(enemy).expect("teammate");
+    for p in [enemy, enemy_teammate] {
+        game.players[p]
+            .units
+            .push(Unit::new(p, enemy_tile, UnitType::Settler, 100));
+    }
+    game = game_api::execute(game, move_action(vec![100], enemy_tile), player_index);
+    assert!(game.players[enemy].get_units(enemy_tile).is_empty());
+    assert!(
+        game.players[enemy_teammate]
+            .get_units(enemy_tile)
+            .is_empty()
+    );
+
+    // moving onto a teammate's tile doesn't start a combat
+    game = game_api::execute(game, move_action(vec![101], shared), player_index);
+    assert_eq!(game.players[player_index].get_unit(101).position, shared);
+    assert_eq!(game.players[teammate].get_units(shared).len(), 3);
+
+    // teammates share the stack limit
+    let destinations = possible_move_destinations(&game, player_index, &[102], start)
+        .list
+        .into_iter()
+        .filter_map(|d| match d {
+            MoveDestination::Tile(p, _) => Some(p),
+            MoveDestination::Carrier(_) => None,
+        })
+        .collect_vec();
+    assert!(destinations.contains(&enemy_tile));
+    assert!(!destinations.contains(&shared));
+}
+
+fn start_trade() -> (Game, usize, usize, u8) {
+    let mut game = setup_game(
+        &GameSetupBuilder::new(2)
+            .skip_random_map()
+            .options(GameOptions {
+                trading: true,
+                ..GameOptions::default()
+            })
+            .build(),
+    );
+    let proposer = game.current_player_index;
+    let partner = 1 - proposer;
+    game.players[proposer].resources.wood = 2;
+    game.players[partner].resources.ore = 1;
+    let card = game.players[proposer].action_cards[0];
+
+    game = game_api::execute(
+        game,
+        Action::Playing(Custom(CustomAction::new(CustomActionType::Trade, None))),
+        proposer,
+    );
+    // offer - the only other player is selected automatically
+    game = trade_response(
+        game,
+        proposer,
+        EventResponse::Payment(vec![ResourcePile::empty(), ResourcePile::wood(1)]),
+    );
+    game = trade_response(
+        game,
+        proposer,
+        EventResponse::SelectHandCards(vec![HandCard::ActionCard(card)]),
+    );
+    assert_eq!(game.active_player(), partner);
+    (game, proposer, partner, card)
+}
+
+fn trade_response(game: Game, player: usize, response: EventResponse) -> Game {
+    game_api::execute(game, Action::Response(response), player)
+}
+
+#[test]
+fn test_trade() {
+    let (mut game, proposer, partner, card) = start_trade();
+    // don't accept the offer as made, but make a counter-offer
+    game = trade_response(game, partner, EventResponse::Bool(false));
+    game = trade_response(game, partner, EventResponse::Bool(true));
+    game = trade_response(
+        game,
+        partner,
+        EventResponse::Payment(vec![ResourcePile::empty(), ResourcePile::ore(1)]),
+    );
+    game = trade_response(game, partner, EventResponse::SelectHandCards(vec![]));
+    // accept
+    assert_eq!(game.active_player(), proposer);
+    game = trade_response(game, proposer, EventResponse::Bool(true));
+
+    assert!(game.events.is_empty());
+    let p = &game.players[proposer];
+    assert_eq!(p.resources.wood, 1);
+    assert_eq!(p.resources.ore, 1);
+    assert!(!p.action_cards.contains(&card));
+    let q = &game.players[partner];
+    assert_eq!(q.resources.wood, 1);
+    assert_eq!(q.resources.ore, 0);
+    assert!(q.action_cards.contains(&card));
+    assert_eq!(game.actions_left, 3);
+    // only once per turn
+    assert!(
+        PlayingActionType::Special(SpecialAction::Custom(CustomActionType::Trade))
+            .is_available(&game, proposer)
+            .is_err()
+    );
+}
+
+#[test]
+fn test_trade_accept_offer() {
+    let (mut game, proposer, partner, card) = start_trade();
+    game = trade_response(game, partner, EventResponse::Bool(true));
+
+    assert!(game.events.is_empty());
+    let p = &game.players[proposer];
+    assert_eq!(p.resources.wood, 1);
+    assert!(!p.action_cards.contains(&card));
+    let q = &game.players[partner];
+    assert_eq!(q.resources.wood, 1);
+    assert_eq!(q.resources.ore, 1);
+    assert!(q.action_cards.contains(&card));
+}
+
+#[test]
+fn test_trade_decline_offer() {
+    let (mut game, proposer, partner, card) = start_trade();
+    game = trade_response(game, partner, EventResponse::Bool(false));
+    game = trade_response(game, partner, EventResponse::Bool(false));
+
+    assert!(game.events.is_empty());
+    let p = &game.players[proposer];
+    assert_eq!(p.resources.wood, 2);
+    assert!(p.action_cards.contains(&card));
+    assert_eq!(game.players[partner].resources.ore, 1);
+}
+
+#[test]
+fn test_scenario() {
+    for s in server::content::scenarios::get_all_uncached() {
+        let game = setup_game(
+            &GameSetupBuilder::new(s.players.len())
+                .scenario(&s.name)
+                .build(),
+        );
+        assert_eq!(game.players[0].civilization.name, s.players[0].civilization);
+    }
+
+    let mut game = setup_game(&GameSetupBuilder::new(2).scenario(FALL_OF_ROME).build());
+    assert_eq!(game.options.scenario.as_deref(), Some(FALL_OF_ROME));
+    assert_eq!(game.options.ages(), 3);
+    assert!(!game.wonders_left.contains(&Wonder::GreatWall));
+    let rome = &game.players[0];
+    assert_eq!(rome.civilization.name, "Rome");
+    assert_eq!(rome.cities.len(), 3);
+    assert!(rome.has_advance(advance::Advance::Bartering));
+    let capital = Position::from_offset("B2");
+    assert_eq!(rome.get_city(capital).mood_state, Happy);
+    assert_eq!(rome.get_city(capital).pieces.amount(), 2);
+
+    // the first scenario city is the capital for Provinces
+    let mut rome = rome.clone();
+    rome.special_advances.insert(SpecialAdvance::Provinces);
+    let cavalry = |position: &str| {
+        recruit_cost_without_replaced(
+            &game,
+            &rome,
+            &Units::new(0, 0, 0, 1, 0, None),
+            Position::from_offset(position),
+            CostTrigger::NoModifiers,
+        )
+        .map(|_| ())
+    };
+    assert_eq!(cavalry("F2"), Ok(()));
+    assert_eq!(cavalry("D3"), Err("Missing building: market".to_string()));
+    assert_eq!(game.players[1].civilization.name, "Vikings");
+    assert_eq!(game.players[1].units.len(), 4);
+
+    // the vikings capture the roman capital
+    let mut city = game.players[0].cities.remove(0);
+    city.player_index = 1;
+    game.players[1].cities.push(city);
+
+    let player = game.current_player_index;
+    let game = game_api::execute(game, Action::Playing(EndTurn), player);
+    assert_eq!(game.state, GameState::Finished);
+    assert_eq!(
+        game.messages.last().expect("message").to_string(),
+        "The game has ended. Player2 has won"
+    );
+}
+
 #[test]
 fn basic_actions() {
     let mut game = setup_game(
@@ -356,6 +700,24 @@ fn test_construct() {
     );
 }
 
+#[test]
+fn test_victory_points_target() {
+    JSON.test(
+        "victory_points_target",
+        vec![
+            TestAction::not_undoable(
+                0,
+                Action::Playing(Construct(construct::Construct::new(
+                    Position::from_offset("C2"),
+                    Observatory,
+                    ResourcePile::new(1, 1, 1, 0, 0, 0, 0),
+                ))),
+            )
+            .with_post_assert(|game| assert!(game_api::ended(&game))),
+        ],
+    );
+}
+
 #[test]
 fn test_same_player_undo() {
     JSON.test(
@@ -388,3 +750,48 @@ fn test_construct_port() {
         )],
     );
 }
+
+#[test]
+fn test_content_catalog() {
+    let catalog = game_api::content(PatchOption::Standard);
+    let advances = &catalog.content[&ContentCategory::Advances];
+    let storage = advances.iter().find(|a| a.id == "Storage").unwrap();
+    assert_eq!(storage.name, "Storage");
+    assert_eq!(storage.details["required"], "Farming");
+    assert_eq!(
+        storage.details["cost"],
+        "2 ideas (replaceable by Food) (replaceable by Gold)"
+    );
+    let card = &catalog.content[&ContentCategory::ObjectiveCards][0];
+    assert_eq!(card.description.lines().count(), 2);
+    let rome = catalog.content[&ContentCategory::Civilizations]
+        .iter()
+        .find(|c| c.id == "Rome")
+        .unwrap();
+    assert!(rome.details["leaders"].contains("Augustus"));
+    assert!(rome.details["special_advances"].contains("Provinces"));
+    assert!(catalog.content.values().all(|entries| !entries.is_empty()));
+    assert!(diff_catalogs(&catalog, &catalog).is_empty());
+
+    let mut changed = catalog.clone();
+    changed
+        .content
+        .get_mut(&ContentCategory::Civilizations)
+        .unwrap()
+        .retain(|c| c.id != "Rome");
+    let diff = diff_catalogs(&catalog, &changed);
+    assert_eq!(diff.len(), 1);
+    assert_eq!(diff[0].category, ContentCategory::Civilizations);
+    assert_eq!(diff[0].change, CatalogChangeType::Removed);
+
+    let diff = game_api::content_diff();
+    assert!(diff.iter().any(|c| c.category == ContentCategory::Advances
+        && c.id == "CityPlanning"
+        && c.change == CatalogChangeType::Added));
+    assert!(diff.iter().any(|c| c.category == ContentCategory::Advances
+        && c.id == "Sanitation"
+        && c.change == CatalogChangeType::Removed));
+    let philosophy = diff.iter().find(|c| c.id == "Philosophy").unwrap();
+    assert_eq!(philosophy.change, CatalogChangeType::Changed);
+    assert_eq!(philosophy.fields[0].field, "description");
+}
@@ -4,6 +4,9 @@ use server::action::Action;
 use server::card::HandCard;
 use server::city_pieces::Building::Fortress;
 use server::content::persistent_events::{EventResponse, SelectedStructure};
+use server::game::{GameOptions, IncidentOptions};
+use server::game_setup::{GameSetupBuilder, setup_game};
+use server::incident::IncidentCategory;
 use server::leader::Leader;
 use server::playing_actions::PlayingAction::Construct;
 use server::position::Position;
@@ -13,6 +16,8 @@ use server::structure::Structure;
 use server::unit::UnitType;
 use server::wonder::Wonder;
 use server::{advance, construct};
+use std::collections::HashMap;
+use std::panic::catch_unwind;
 use std::vec;
 
 mod common;
@@ -37,6 +42,96 @@ fn test_barbarians_spawn() {
     );
 }
 
+#[test]
+fn test_barbarians_disabled() {
+    JSON.test(
+        "barbarians_disabled",
+        vec![TestAction::not_undoable(
+            0,
+            Action::Response(EventResponse::SelectAdvance(Advance::Storage)),
+        )],
+    );
+}
+
+#[test]
+fn test_incident_tokens_disabled() {
+    JSON.test(
+        "incident_tokens_disabled",
+        vec![TestAction::not_undoable(
+            0,
+            Action::Response(EventResponse::SelectAdvance(Advance::Storage)),
+        )],
+    );
+}
+
+#[test]
+fn test_incident_category_weights() {
+    let game = setup_game(
+        &GameSetupBuilder::new(2)
+            .skip_random_map()
+            .options(GameOptions {
+                incidents: IncidentOptions {
+                    category_weights: HashMap::from([
+                        (IncidentCategory::GreatPerson, 0),
+                        (IncidentCategory::Trade, 2),
+                    ]),
+                    ..IncidentOptions::default()
+                },
+                ..GameOptions::default()
+            })
+            .build(),
+    );
+    let count = |category: IncidentCategory| {
+        game.incidents_left
+            .iter()
+            .filter(|id| game.cache.get_incident(**id).category == category)
+            .count()
+    };
+    let cards = |category: IncidentCategory| {
+        game.cache
+            .get_incidents()
+            .iter()
+            .filter(|i| i.category == category)
+            .count()
+    };
+    assert_eq!(count(IncidentCategory::GreatPerson), 0);
+    assert_eq!(
+        count(IncidentCategory::Trade),
+        2 * cards(IncidentCategory::Trade)
+    );
+    assert_eq!(
+        count(IncidentCategory::Disaster),
+        cards(IncidentCategory::Disaster)
+    );
+}
+
+#[test]
+fn test_incident_category_weights_empty_deck() {
+    let options = GameOptions {
+        incidents: IncidentOptions {
+            category_weights: HashMap::from([
+                (IncidentCategory::Disaster, 0),
+                (IncidentCategory::GreatPerson, 0),
+                (IncidentCategory::Trade, 0),
+                (IncidentCategory::Other, 0),
+            ]),
+            ..IncidentOptions::default()
+        },
+        ..GameOptions::default()
+    };
+    let setup = GameSetupBuilder::new(2)
+        .skip_random_map()
+        .options(options)
+        .build();
+    let Err(err) = catch_unwind(|| setup_game(&setup)) else {
+        panic!("empty event deck should be rejected");
+    };
+    assert_eq!(
+        err.downcast_ref::<&str>().copied(),
+        Some("the category weights must keep at least one event")
+    );
+}
+
 #[test]
 fn test_barbarians_move() {
     JSON.test(
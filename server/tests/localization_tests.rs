@@ -0,0 +1,133 @@
+use server::advance::Advance;
+use server::content::effects::event_help;
+use server::events::EventOrigin;
+use server::game_setup::{GameSetupBuilder, setup_game};
+use server::localization::{Catalog, Language, Message};
+use server::log::ActionLogEntry;
+
+#[test]
+fn english_is_the_fallback() {
+    let message = Message::new("test.missing_key", "{player} wins").param("player", "Alice");
+    assert_eq!(message.to_string(), "Alice wins");
+    assert_eq!(
+        Catalog::new(Language::English).translate(&message),
+        "Alice wins"
+    );
+    assert_eq!(
+        Catalog::new(Language::German).translate(&message),
+        "Alice wins"
+    );
+}
+
+#[test]
+fn german_catalog() {
+    let catalog = Catalog::new(Language::German);
+    let message = Message::new("log.age_round", "Age {age}, Round {round}")
+        .param("age", 2)
+        .param("round", 3);
+    assert_eq!(catalog.translate(&message), "Zeitalter 2, Runde 3");
+    assert_eq!(
+        catalog.translate_text("Not enough resources"),
+        "Nicht genügend Ressourcen"
+    );
+    assert_eq!(Language::from_code("de-AT"), Some(Language::German));
+}
+
+#[test]
+fn errors_are_keyed_by_template() {
+    let catalog = Catalog::new(Language::German);
+    assert_eq!(
+        catalog.translate_text("Leader Augustus not available"),
+        "Anführer Augustus ist nicht verfügbar"
+    );
+    assert_eq!(
+        catalog.translate_text("Only have 2 Infantry - not 3"),
+        "Nur 2 Infantry verfügbar - nicht 3"
+    );
+    assert_eq!(catalog.translate_text("unknown error"), "unknown error");
+}
+
+#[test]
+fn params_are_substituted_once() {
+    let message = Message::new("test.params", "{player} offers {offer}")
+        .param("player", "{offer}")
+        .param("offer", "1 food");
+    assert_eq!(message.to_string(), "{offer} offers 1 food");
+    assert_eq!(
+        Message::new("test.missing_param", "{missing} and {").to_string(),
+        "{missing} and {"
+    );
+}
+
+#[test]
+fn content_has_stable_keys() {
+    let game = setup_game(&GameSetupBuilder::new(2).skip_random_map().build());
+    let help = event_help(&game, &EventOrigin::Advance(Advance::Farming));
+    assert_eq!(help[0].key, "advance.Farming.name");
+    assert_eq!(help[0].to_string(), "Farming");
+    assert_eq!(help[1].key, "advance.Farming.description");
+
+    let catalog = Catalog::new(Language::German);
+    assert_eq!(catalog.translate(&help[0]), "Ackerbau");
+    assert!(
+        game.cache
+            .get_advances()
+            .keys()
+            .all(|a| catalog.contains(&format!("advance.{a:?}.name"))),
+        "all advance names should be translated"
+    );
+    assert!(
+        game.cache
+            .get_advances()
+            .keys()
+            .all(|a| catalog.contains(&format!("advance.{a:?}.description"))),
+        "all advance descriptions should be translated"
+    );
+    assert!(
+        game.cache
+            .get_wonders()
+            .iter()
+            .all(|w| catalog.contains(&format!("wonder.{:?}.description", w.wonder))),
+        "all wonder descriptions should be translated"
+    );
+}
+
+#[test]
+fn prompts_are_keyed_by_template() {
+    assert_eq!(
+        Catalog::new(Language::German)
+            .translate_text("Alice offers 1 food. Do you want to accept the offer?"),
+        "Alice bietet 1 food an. Möchtest du das Angebot annehmen?"
+    );
+}
+
+#[test]
+fn legacy_text_entries_are_messages() {
+    let entry: ActionLogEntry =
+        serde_json::from_str(r#"{"Text":"Declined the trade"}"#).expect("entry should deserialize");
+    assert_eq!(entry.text().as_deref(), Some("Declined the trade"));
+}
+
+#[test]
+fn log_messages_can_be_translated() {
+    let entry = ActionLogEntry::Message(
+        Message::new(
+            "log.influence.succeeded",
+            "Cultural influence succeeded (rolls {roll})",
+        )
+        .param("roll", 6),
+    );
+    let json = serde_json::to_string(&entry).expect("entry should be serializable");
+    let entry: ActionLogEntry = serde_json::from_str(&json).expect("entry should deserialize");
+    assert_eq!(
+        entry.text().as_deref(),
+        Some("Cultural influence succeeded (rolls 6)")
+    );
+    let ActionLogEntry::Message(m) = &entry else {
+        panic!("message entry expected");
+    };
+    assert_eq!(
+        Catalog::new(Language::German).translate(m),
+        "Kultureller Einfluss erfolgreich (würfelt 6)"
+    );
+}
@@ -3,6 +3,9 @@ use server::action::Action;
 use server::card::HandCard;
 use server::content::persistent_events::EventResponse;
 use server::playing_actions::PlayingAction;
+use server::position::Position;
+use server::rules_inspection::inspect_rules;
+use server::wonder::Wonder;
 
 mod common;
 
@@ -112,3 +115,53 @@ fn test_standing_army() {
         ],
     )
 }
+
+#[test]
+fn test_inspect_rules() {
+    let game = JSON.load_game("large_civ");
+    let inspection = inspect_rules(&game);
+
+    let large_civ = inspection
+        .objectives
+        .iter()
+        .find(|o| o.name == "Large Civilization")
+        .expect("objective");
+    assert!(large_civ.players[0].completable);
+    assert!(large_civ.players[0].has_card);
+    assert!(!large_civ.players[1].completable);
+    assert_eq!(large_civ.players[1].reasons, vec!["You have 1 of 6"]);
+    assert!(
+        inspection
+            .objectives
+            .iter()
+            .filter(|o| o.instant)
+            .all(|o| o.players.iter().all(|p| !p.completable))
+    );
+
+    // the player is currently selecting the objective card
+    let selecting = inspect_rules(&JsonTest::new("tactics_cards").load_game("martyr2.outcome1"));
+    let general = selecting
+        .objectives
+        .iter()
+        .find(|o| o.name == "General")
+        .expect("objective");
+    assert!(general.instant);
+    assert!(general.players[0].completable);
+    assert!(!general.players[1].completable);
+
+    let lighthouse = inspection
+        .wonders
+        .iter()
+        .find(|w| w.wonder == Wonder::GreatLighthouse)
+        .expect("wonder");
+    let city = lighthouse
+        .cities
+        .iter()
+        .find(|c| c.city == Position::from_offset("A1"))
+        .expect("city");
+    assert!(!city.completable);
+    assert!(
+        city.reasons
+            .contains(&"Placement requirement not met".to_string())
+    );
+}
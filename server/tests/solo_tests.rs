@@ -0,0 +1,31 @@
+use crate::common::{JsonTest, TestAction};
+use server::action::Action;
+use server::game_setup::{GameSetupBuilder, setup_game};
+use server::playing_actions::PlayingAction;
+use server::solo::solo_score_target;
+
+mod common;
+
+const JSON: JsonTest = JsonTest::new("solo");
+
+#[test]
+fn test_solo_setup() {
+    let game = setup_game(&GameSetupBuilder::new(1).seed("solo".to_string()).build());
+    assert!(game.is_solo());
+    assert_eq!(game.human_player_ids(), vec![0]);
+    assert_eq!(game.players.len(), 3);
+    assert_eq!(game.map.unexplored_blocks.len(), 6);
+    assert_eq!(game.player(0).cities.len(), 1);
+    assert_eq!(solo_score_target(6), 28.);
+}
+
+#[test]
+fn test_solo_end_game_missed_score_target() {
+    JSON.test(
+        "end_game",
+        vec![TestAction::not_undoable(
+            0,
+            Action::Playing(PlayingAction::EndTurn),
+        )],
+    );
+}
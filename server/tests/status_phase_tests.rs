@@ -23,6 +23,17 @@ fn test_end_game() {
     );
 }
 
+#[test]
+fn test_end_game_short_game() {
+    JSON.test(
+        "end_game_short_game",
+        vec![TestAction::not_undoable(
+            0,
+            Action::Playing(PlayingAction::EndTurn),
+        )],
+    );
+}
+
 #[test]
 fn test_free_advance() {
     JSON.test(